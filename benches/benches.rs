@@ -16,6 +16,15 @@ use hashers::jenkins::*;
 use hashers::null::*;
 use hashers::pigeon::*;
 use hashers::oz::*;
+use hashers::xxhash::*;
+use hashers::xxhash::xxh3::*;
+use hashers::murmur::*;
+use hashers::google::*;
+use hashers::wy::*;
+use hashers::sip::*;
+use hashers::highway::*;
+use hashers::crc::*;
+use hashers::checksum::*;
 
 macro_rules! tiny_bench {
     ($name:ident, $fcn:ident, $hasher:ident) => {
@@ -40,6 +49,37 @@ tiny_bench!(tiny_oaat, oaat, OAATHasher);
 tiny_bench!(tiny_passthrough, passthrough, PassThroughHasher);
 tiny_bench!(tiny_sdbm, sdbm, SDBMHasher);
 tiny_bench!(tiny_spooky, spooky, SpookyHasher);
+tiny_bench!(tiny_xxhash32, xxhash32, XxHash32);
+tiny_bench!(tiny_xxhash64, xxhash64, XxHash64);
+tiny_bench!(tiny_xxh3_64, xxh3_64, Xxh3Hasher);
+tiny_bench!(tiny_murmur3_32, murmur3_32, Murmur3Hasher32);
+tiny_bench!(tiny_murmur3_x86_128, murmur3_x86_128, Murmur3x86Hasher128);
+tiny_bench!(tiny_murmur3_x64_128, murmur3_x64_128, Murmur3x64Hasher128);
+tiny_bench!(tiny_murmur2, murmur2, Murmur2Hasher);
+tiny_bench!(tiny_murmur64a, murmur64a, Murmur64AHasher);
+tiny_bench!(tiny_murmur64b, murmur64b, Murmur64BHasher);
+tiny_bench!(tiny_cityhash64, cityhash64, CityHasher64);
+tiny_bench!(tiny_farmhash64, farmhash64, FarmHasher64);
+tiny_bench!(tiny_fingerprint64, fingerprint64, FingerprintHasher64);
+tiny_bench!(tiny_wyhash, wyhash, WyHasher);
+tiny_bench!(tiny_rapidhash, rapidhash, RapidHasher);
+tiny_bench!(tiny_siphash24, siphash24, SipHasher24);
+tiny_bench!(tiny_siphash13, siphash13, SipHasher13);
+tiny_bench!(tiny_halfsiphash, halfsiphash, HalfSipHasher);
+tiny_bench!(tiny_highwayhash, highwayhash, HighwayHasher);
+tiny_bench!(tiny_crc32, crc32, Crc32Hasher);
+tiny_bench!(tiny_crc32c, crc32c, Crc32cHasher);
+tiny_bench!(tiny_crc64, crc64, Crc64Hasher);
+tiny_bench!(tiny_adler32, adler32, Adler32Hasher);
+tiny_bench!(tiny_fletcher16, fletcher16, Fletcher16Hasher);
+tiny_bench!(tiny_fletcher32, fletcher32, Fletcher32Hasher);
+tiny_bench!(tiny_fletcher64, fletcher64, Fletcher64Hasher);
+tiny_bench!(tiny_fnv1_64, fnv1_64, FNV1Hasher64);
+tiny_bench!(tiny_lookup2, lookup2, Lookup2Hasher);
+tiny_bench!(tiny_djb2_64, djb2_64, DJB2Hasher64);
+tiny_bench!(tiny_sdbm64, sdbm64, SDBMHasher64);
+tiny_bench!(tiny_oaat32, oaat32, OAATHasher32);
+tiny_bench!(tiny_bricolage64, bricolage64, Bricolage64);
 
 macro_rules! w32_bench {
     ($name:ident, $hasher:ident, $count:expr) => {
@@ -56,6 +96,49 @@ macro_rules! w32_bench {
     };
 }
 
+w32_bench!(w32_1_default, DefaultHasher, 1);
+w32_bench!(w32_1_djb2, DJB2Hasher, 1);
+w32_bench!(w32_1_sdbm, SDBMHasher, 1);
+w32_bench!(w32_1_loselose, LoseLoseHasher, 1);
+w32_bench!(w32_1_oaat, OAATHasher, 1);
+w32_bench!(w32_1_lookup3, Lookup3Hasher, 1);
+w32_bench!(w32_1_passthrough, PassThroughHasher, 1);
+w32_bench!(w32_1_fnv1a64, FNV1aHasher64, 1);
+w32_bench!(w32_1_fxhash, FxHasher, 1);
+w32_bench!(w32_1_spooky, SpookyHasher, 1);
+w32_bench!(w32_1_bricolage, Bricolage, 1);
+w32_bench!(w32_1_xxhash32, XxHash32, 1);
+w32_bench!(w32_1_xxhash64, XxHash64, 1);
+w32_bench!(w32_1_xxh3, Xxh3Hasher, 1);
+w32_bench!(w32_1_murmur3_32, Murmur3Hasher32, 1);
+w32_bench!(w32_1_murmur3_x86_128, Murmur3x86Hasher128, 1);
+w32_bench!(w32_1_murmur3_x64_128, Murmur3x64Hasher128, 1);
+w32_bench!(w32_1_murmur2, Murmur2Hasher, 1);
+w32_bench!(w32_1_murmur64a, Murmur64AHasher, 1);
+w32_bench!(w32_1_murmur64b, Murmur64BHasher, 1);
+w32_bench!(w32_1_cityhash64, CityHasher64, 1);
+w32_bench!(w32_1_farmhash64, FarmHasher64, 1);
+w32_bench!(w32_1_fingerprint64, FingerprintHasher64, 1);
+w32_bench!(w32_1_wyhash, WyHasher, 1);
+w32_bench!(w32_1_rapidhash, RapidHasher, 1);
+w32_bench!(w32_1_siphash24, SipHasher24, 1);
+w32_bench!(w32_1_siphash13, SipHasher13, 1);
+w32_bench!(w32_1_halfsiphash, HalfSipHasher, 1);
+w32_bench!(w32_1_highwayhash, HighwayHasher, 1);
+w32_bench!(w32_1_crc32, Crc32Hasher, 1);
+w32_bench!(w32_1_crc32c, Crc32cHasher, 1);
+w32_bench!(w32_1_crc64, Crc64Hasher, 1);
+w32_bench!(w32_1_adler32, Adler32Hasher, 1);
+w32_bench!(w32_1_fletcher16, Fletcher16Hasher, 1);
+w32_bench!(w32_1_fletcher32, Fletcher32Hasher, 1);
+w32_bench!(w32_1_fletcher64, Fletcher64Hasher, 1);
+w32_bench!(w32_1_fnv1_64, FNV1Hasher64, 1);
+w32_bench!(w32_1_lookup2, Lookup2Hasher, 1);
+w32_bench!(w32_1_djb2_64, DJB2Hasher64, 1);
+w32_bench!(w32_1_sdbm64, SDBMHasher64, 1);
+w32_bench!(w32_1_oaat32, OAATHasher32, 1);
+w32_bench!(w32_1_bricolage64, Bricolage64, 1);
+
 w32_bench!(w32_10_default, DefaultHasher, 10);
 w32_bench!(w32_10_djb2, DJB2Hasher, 10);
 w32_bench!(w32_10_sdbm, SDBMHasher, 10);
@@ -67,6 +150,37 @@ w32_bench!(w32_10_fnv1a64, FNV1aHasher64, 10);
 w32_bench!(w32_10_fxhash, FxHasher, 10);
 w32_bench!(w32_10_spooky, SpookyHasher, 10);
 w32_bench!(w32_10_bricolage, Bricolage, 10);
+w32_bench!(w32_10_xxhash32, XxHash32, 10);
+w32_bench!(w32_10_xxhash64, XxHash64, 10);
+w32_bench!(w32_10_xxh3, Xxh3Hasher, 10);
+w32_bench!(w32_10_murmur3_32, Murmur3Hasher32, 10);
+w32_bench!(w32_10_murmur3_x86_128, Murmur3x86Hasher128, 10);
+w32_bench!(w32_10_murmur3_x64_128, Murmur3x64Hasher128, 10);
+w32_bench!(w32_10_murmur2, Murmur2Hasher, 10);
+w32_bench!(w32_10_murmur64a, Murmur64AHasher, 10);
+w32_bench!(w32_10_murmur64b, Murmur64BHasher, 10);
+w32_bench!(w32_10_cityhash64, CityHasher64, 10);
+w32_bench!(w32_10_farmhash64, FarmHasher64, 10);
+w32_bench!(w32_10_fingerprint64, FingerprintHasher64, 10);
+w32_bench!(w32_10_wyhash, WyHasher, 10);
+w32_bench!(w32_10_rapidhash, RapidHasher, 10);
+w32_bench!(w32_10_siphash24, SipHasher24, 10);
+w32_bench!(w32_10_siphash13, SipHasher13, 10);
+w32_bench!(w32_10_halfsiphash, HalfSipHasher, 10);
+w32_bench!(w32_10_highwayhash, HighwayHasher, 10);
+w32_bench!(w32_10_crc32, Crc32Hasher, 10);
+w32_bench!(w32_10_crc32c, Crc32cHasher, 10);
+w32_bench!(w32_10_crc64, Crc64Hasher, 10);
+w32_bench!(w32_10_adler32, Adler32Hasher, 10);
+w32_bench!(w32_10_fletcher16, Fletcher16Hasher, 10);
+w32_bench!(w32_10_fletcher32, Fletcher32Hasher, 10);
+w32_bench!(w32_10_fletcher64, Fletcher64Hasher, 10);
+w32_bench!(w32_10_fnv1_64, FNV1Hasher64, 10);
+w32_bench!(w32_10_lookup2, Lookup2Hasher, 10);
+w32_bench!(w32_10_djb2_64, DJB2Hasher64, 10);
+w32_bench!(w32_10_sdbm64, SDBMHasher64, 10);
+w32_bench!(w32_10_oaat32, OAATHasher32, 10);
+w32_bench!(w32_10_bricolage64, Bricolage64, 10);
 
 w32_bench!(w32_100_default, DefaultHasher, 100);
 w32_bench!(w32_100_djb2, DJB2Hasher, 100);
@@ -79,6 +193,37 @@ w32_bench!(w32_100_fnv1a64, FNV1aHasher64, 100);
 w32_bench!(w32_100_fxhash, FxHasher, 100);
 w32_bench!(w32_100_spooky, SpookyHasher, 100);
 w32_bench!(w32_100_bricolage, Bricolage, 100);
+w32_bench!(w32_100_xxhash32, XxHash32, 100);
+w32_bench!(w32_100_xxhash64, XxHash64, 100);
+w32_bench!(w32_100_xxh3, Xxh3Hasher, 100);
+w32_bench!(w32_100_murmur3_32, Murmur3Hasher32, 100);
+w32_bench!(w32_100_murmur3_x86_128, Murmur3x86Hasher128, 100);
+w32_bench!(w32_100_murmur3_x64_128, Murmur3x64Hasher128, 100);
+w32_bench!(w32_100_murmur2, Murmur2Hasher, 100);
+w32_bench!(w32_100_murmur64a, Murmur64AHasher, 100);
+w32_bench!(w32_100_murmur64b, Murmur64BHasher, 100);
+w32_bench!(w32_100_cityhash64, CityHasher64, 100);
+w32_bench!(w32_100_farmhash64, FarmHasher64, 100);
+w32_bench!(w32_100_fingerprint64, FingerprintHasher64, 100);
+w32_bench!(w32_100_wyhash, WyHasher, 100);
+w32_bench!(w32_100_rapidhash, RapidHasher, 100);
+w32_bench!(w32_100_siphash24, SipHasher24, 100);
+w32_bench!(w32_100_siphash13, SipHasher13, 100);
+w32_bench!(w32_100_halfsiphash, HalfSipHasher, 100);
+w32_bench!(w32_100_highwayhash, HighwayHasher, 100);
+w32_bench!(w32_100_crc32, Crc32Hasher, 100);
+w32_bench!(w32_100_crc32c, Crc32cHasher, 100);
+w32_bench!(w32_100_crc64, Crc64Hasher, 100);
+w32_bench!(w32_100_adler32, Adler32Hasher, 100);
+w32_bench!(w32_100_fletcher16, Fletcher16Hasher, 100);
+w32_bench!(w32_100_fletcher32, Fletcher32Hasher, 100);
+w32_bench!(w32_100_fletcher64, Fletcher64Hasher, 100);
+w32_bench!(w32_100_fnv1_64, FNV1Hasher64, 100);
+w32_bench!(w32_100_lookup2, Lookup2Hasher, 100);
+w32_bench!(w32_100_djb2_64, DJB2Hasher64, 100);
+w32_bench!(w32_100_sdbm64, SDBMHasher64, 100);
+w32_bench!(w32_100_oaat32, OAATHasher32, 100);
+w32_bench!(w32_100_bricolage64, Bricolage64, 100);
 
 w32_bench!(w32_1000_default, DefaultHasher, 1000);
 w32_bench!(w32_1000_djb2, DJB2Hasher, 1000);
@@ -91,6 +236,37 @@ w32_bench!(w32_1000_fnv1a64, FNV1aHasher64, 1000);
 w32_bench!(w32_1000_fxhash, FxHasher, 1000);
 w32_bench!(w32_1000_spooky, SpookyHasher, 1000);
 w32_bench!(w32_1000_bricolage, Bricolage, 1000);
+w32_bench!(w32_1000_xxhash32, XxHash32, 1000);
+w32_bench!(w32_1000_xxhash64, XxHash64, 1000);
+w32_bench!(w32_1000_xxh3, Xxh3Hasher, 1000);
+w32_bench!(w32_1000_murmur3_32, Murmur3Hasher32, 1000);
+w32_bench!(w32_1000_murmur3_x86_128, Murmur3x86Hasher128, 1000);
+w32_bench!(w32_1000_murmur3_x64_128, Murmur3x64Hasher128, 1000);
+w32_bench!(w32_1000_murmur2, Murmur2Hasher, 1000);
+w32_bench!(w32_1000_murmur64a, Murmur64AHasher, 1000);
+w32_bench!(w32_1000_murmur64b, Murmur64BHasher, 1000);
+w32_bench!(w32_1000_cityhash64, CityHasher64, 1000);
+w32_bench!(w32_1000_farmhash64, FarmHasher64, 1000);
+w32_bench!(w32_1000_fingerprint64, FingerprintHasher64, 1000);
+w32_bench!(w32_1000_wyhash, WyHasher, 1000);
+w32_bench!(w32_1000_rapidhash, RapidHasher, 1000);
+w32_bench!(w32_1000_siphash24, SipHasher24, 1000);
+w32_bench!(w32_1000_siphash13, SipHasher13, 1000);
+w32_bench!(w32_1000_halfsiphash, HalfSipHasher, 1000);
+w32_bench!(w32_1000_highwayhash, HighwayHasher, 1000);
+w32_bench!(w32_1000_crc32, Crc32Hasher, 1000);
+w32_bench!(w32_1000_crc32c, Crc32cHasher, 1000);
+w32_bench!(w32_1000_crc64, Crc64Hasher, 1000);
+w32_bench!(w32_1000_adler32, Adler32Hasher, 1000);
+w32_bench!(w32_1000_fletcher16, Fletcher16Hasher, 1000);
+w32_bench!(w32_1000_fletcher32, Fletcher32Hasher, 1000);
+w32_bench!(w32_1000_fletcher64, Fletcher64Hasher, 1000);
+w32_bench!(w32_1000_fnv1_64, FNV1Hasher64, 1000);
+w32_bench!(w32_1000_lookup2, Lookup2Hasher, 1000);
+w32_bench!(w32_1000_djb2_64, DJB2Hasher64, 1000);
+w32_bench!(w32_1000_sdbm64, SDBMHasher64, 1000);
+w32_bench!(w32_1000_oaat32, OAATHasher32, 1000);
+w32_bench!(w32_1000_bricolage64, Bricolage64, 1000);
 
 macro_rules! w64_bench {
     ($name:ident, $hasher:ident, $count:expr) => {
@@ -118,6 +294,37 @@ w64_bench!(w64_10_fnv1a64, FNV1aHasher64, 10);
 w64_bench!(w64_10_fxhash, FxHasher, 10);
 w64_bench!(w64_10_spooky, SpookyHasher, 10);
 w64_bench!(w64_10_bricolage, Bricolage, 10);
+w64_bench!(w64_10_xxhash32, XxHash32, 10);
+w64_bench!(w64_10_xxhash64, XxHash64, 10);
+w64_bench!(w64_10_xxh3, Xxh3Hasher, 10);
+w64_bench!(w64_10_murmur3_32, Murmur3Hasher32, 10);
+w64_bench!(w64_10_murmur3_x86_128, Murmur3x86Hasher128, 10);
+w64_bench!(w64_10_murmur3_x64_128, Murmur3x64Hasher128, 10);
+w64_bench!(w64_10_murmur2, Murmur2Hasher, 10);
+w64_bench!(w64_10_murmur64a, Murmur64AHasher, 10);
+w64_bench!(w64_10_murmur64b, Murmur64BHasher, 10);
+w64_bench!(w64_10_cityhash64, CityHasher64, 10);
+w64_bench!(w64_10_farmhash64, FarmHasher64, 10);
+w64_bench!(w64_10_fingerprint64, FingerprintHasher64, 10);
+w64_bench!(w64_10_wyhash, WyHasher, 10);
+w64_bench!(w64_10_rapidhash, RapidHasher, 10);
+w64_bench!(w64_10_siphash24, SipHasher24, 10);
+w64_bench!(w64_10_siphash13, SipHasher13, 10);
+w64_bench!(w64_10_halfsiphash, HalfSipHasher, 10);
+w64_bench!(w64_10_highwayhash, HighwayHasher, 10);
+w64_bench!(w64_10_crc32, Crc32Hasher, 10);
+w64_bench!(w64_10_crc32c, Crc32cHasher, 10);
+w64_bench!(w64_10_crc64, Crc64Hasher, 10);
+w64_bench!(w64_10_adler32, Adler32Hasher, 10);
+w64_bench!(w64_10_fletcher16, Fletcher16Hasher, 10);
+w64_bench!(w64_10_fletcher32, Fletcher32Hasher, 10);
+w64_bench!(w64_10_fletcher64, Fletcher64Hasher, 10);
+w64_bench!(w64_10_fnv1_64, FNV1Hasher64, 10);
+w64_bench!(w64_10_lookup2, Lookup2Hasher, 10);
+w64_bench!(w64_10_djb2_64, DJB2Hasher64, 10);
+w64_bench!(w64_10_sdbm64, SDBMHasher64, 10);
+w64_bench!(w64_10_oaat32, OAATHasher32, 10);
+w64_bench!(w64_10_bricolage64, Bricolage64, 10);
 
 w64_bench!(w64_100_default, DefaultHasher, 100);
 w64_bench!(w64_100_djb2, DJB2Hasher, 100);
@@ -130,6 +337,37 @@ w64_bench!(w64_100_fnv1a64, FNV1aHasher64, 100);
 w64_bench!(w64_100_fxhash, FxHasher, 100);
 w64_bench!(w64_100_spooky, SpookyHasher, 100);
 w64_bench!(w64_100_bricolage, Bricolage, 100);
+w64_bench!(w64_100_xxhash32, XxHash32, 100);
+w64_bench!(w64_100_xxhash64, XxHash64, 100);
+w64_bench!(w64_100_xxh3, Xxh3Hasher, 100);
+w64_bench!(w64_100_murmur3_32, Murmur3Hasher32, 100);
+w64_bench!(w64_100_murmur3_x86_128, Murmur3x86Hasher128, 100);
+w64_bench!(w64_100_murmur3_x64_128, Murmur3x64Hasher128, 100);
+w64_bench!(w64_100_murmur2, Murmur2Hasher, 100);
+w64_bench!(w64_100_murmur64a, Murmur64AHasher, 100);
+w64_bench!(w64_100_murmur64b, Murmur64BHasher, 100);
+w64_bench!(w64_100_cityhash64, CityHasher64, 100);
+w64_bench!(w64_100_farmhash64, FarmHasher64, 100);
+w64_bench!(w64_100_fingerprint64, FingerprintHasher64, 100);
+w64_bench!(w64_100_wyhash, WyHasher, 100);
+w64_bench!(w64_100_rapidhash, RapidHasher, 100);
+w64_bench!(w64_100_siphash24, SipHasher24, 100);
+w64_bench!(w64_100_siphash13, SipHasher13, 100);
+w64_bench!(w64_100_halfsiphash, HalfSipHasher, 100);
+w64_bench!(w64_100_highwayhash, HighwayHasher, 100);
+w64_bench!(w64_100_crc32, Crc32Hasher, 100);
+w64_bench!(w64_100_crc32c, Crc32cHasher, 100);
+w64_bench!(w64_100_crc64, Crc64Hasher, 100);
+w64_bench!(w64_100_adler32, Adler32Hasher, 100);
+w64_bench!(w64_100_fletcher16, Fletcher16Hasher, 100);
+w64_bench!(w64_100_fletcher32, Fletcher32Hasher, 100);
+w64_bench!(w64_100_fletcher64, Fletcher64Hasher, 100);
+w64_bench!(w64_100_fnv1_64, FNV1Hasher64, 100);
+w64_bench!(w64_100_lookup2, Lookup2Hasher, 100);
+w64_bench!(w64_100_djb2_64, DJB2Hasher64, 100);
+w64_bench!(w64_100_sdbm64, SDBMHasher64, 100);
+w64_bench!(w64_100_oaat32, OAATHasher32, 100);
+w64_bench!(w64_100_bricolage64, Bricolage64, 100);
 
 w64_bench!(w64_1000_default, DefaultHasher, 1000);
 w64_bench!(w64_1000_djb2, DJB2Hasher, 1000);
@@ -142,6 +380,37 @@ w64_bench!(w64_1000_fnv1a64, FNV1aHasher64, 1000);
 w64_bench!(w64_1000_fxhash, FxHasher, 1000);
 w64_bench!(w64_1000_spooky, SpookyHasher, 1000);
 w64_bench!(w64_1000_bricolage, Bricolage, 1000);
+w64_bench!(w64_1000_xxhash32, XxHash32, 1000);
+w64_bench!(w64_1000_xxhash64, XxHash64, 1000);
+w64_bench!(w64_1000_xxh3, Xxh3Hasher, 1000);
+w64_bench!(w64_1000_murmur3_32, Murmur3Hasher32, 1000);
+w64_bench!(w64_1000_murmur3_x86_128, Murmur3x86Hasher128, 1000);
+w64_bench!(w64_1000_murmur3_x64_128, Murmur3x64Hasher128, 1000);
+w64_bench!(w64_1000_murmur2, Murmur2Hasher, 1000);
+w64_bench!(w64_1000_murmur64a, Murmur64AHasher, 1000);
+w64_bench!(w64_1000_murmur64b, Murmur64BHasher, 1000);
+w64_bench!(w64_1000_cityhash64, CityHasher64, 1000);
+w64_bench!(w64_1000_farmhash64, FarmHasher64, 1000);
+w64_bench!(w64_1000_fingerprint64, FingerprintHasher64, 1000);
+w64_bench!(w64_1000_wyhash, WyHasher, 1000);
+w64_bench!(w64_1000_rapidhash, RapidHasher, 1000);
+w64_bench!(w64_1000_siphash24, SipHasher24, 1000);
+w64_bench!(w64_1000_siphash13, SipHasher13, 1000);
+w64_bench!(w64_1000_halfsiphash, HalfSipHasher, 1000);
+w64_bench!(w64_1000_highwayhash, HighwayHasher, 1000);
+w64_bench!(w64_1000_crc32, Crc32Hasher, 1000);
+w64_bench!(w64_1000_crc32c, Crc32cHasher, 1000);
+w64_bench!(w64_1000_crc64, Crc64Hasher, 1000);
+w64_bench!(w64_1000_adler32, Adler32Hasher, 1000);
+w64_bench!(w64_1000_fletcher16, Fletcher16Hasher, 1000);
+w64_bench!(w64_1000_fletcher32, Fletcher32Hasher, 1000);
+w64_bench!(w64_1000_fletcher64, Fletcher64Hasher, 1000);
+w64_bench!(w64_1000_fnv1_64, FNV1Hasher64, 1000);
+w64_bench!(w64_1000_lookup2, Lookup2Hasher, 1000);
+w64_bench!(w64_1000_djb2_64, DJB2Hasher64, 1000);
+w64_bench!(w64_1000_sdbm64, SDBMHasher64, 1000);
+w64_bench!(w64_1000_oaat32, OAATHasher32, 1000);
+w64_bench!(w64_1000_bricolage64, Bricolage64, 1000);
 
 fn read_words() -> Vec<String> {
     use std::fs::File;
@@ -182,6 +451,37 @@ words_bench!(words1000_fnv1a64, FNV1aHasher64, 1000);
 words_bench!(words1000_fxhash, FxHasher, 1000);
 words_bench!(words1000_spooky, SpookyHasher, 1000);
 words_bench!(words1000_bricolage, Bricolage, 1000);
+words_bench!(words1000_xxhash32, XxHash32, 1000);
+words_bench!(words1000_xxhash64, XxHash64, 1000);
+words_bench!(words1000_xxh3, Xxh3Hasher, 1000);
+words_bench!(words1000_murmur3_32, Murmur3Hasher32, 1000);
+words_bench!(words1000_murmur3_x86_128, Murmur3x86Hasher128, 1000);
+words_bench!(words1000_murmur3_x64_128, Murmur3x64Hasher128, 1000);
+words_bench!(words1000_murmur2, Murmur2Hasher, 1000);
+words_bench!(words1000_murmur64a, Murmur64AHasher, 1000);
+words_bench!(words1000_murmur64b, Murmur64BHasher, 1000);
+words_bench!(words1000_cityhash64, CityHasher64, 1000);
+words_bench!(words1000_farmhash64, FarmHasher64, 1000);
+words_bench!(words1000_fingerprint64, FingerprintHasher64, 1000);
+words_bench!(words1000_wyhash, WyHasher, 1000);
+words_bench!(words1000_rapidhash, RapidHasher, 1000);
+words_bench!(words1000_siphash24, SipHasher24, 1000);
+words_bench!(words1000_siphash13, SipHasher13, 1000);
+words_bench!(words1000_halfsiphash, HalfSipHasher, 1000);
+words_bench!(words1000_highwayhash, HighwayHasher, 1000);
+words_bench!(words1000_crc32, Crc32Hasher, 1000);
+words_bench!(words1000_crc32c, Crc32cHasher, 1000);
+words_bench!(words1000_crc64, Crc64Hasher, 1000);
+words_bench!(words1000_adler32, Adler32Hasher, 1000);
+words_bench!(words1000_fletcher16, Fletcher16Hasher, 1000);
+words_bench!(words1000_fletcher32, Fletcher32Hasher, 1000);
+words_bench!(words1000_fletcher64, Fletcher64Hasher, 1000);
+words_bench!(words1000_fnv1_64, FNV1Hasher64, 1000);
+words_bench!(words1000_lookup2, Lookup2Hasher, 1000);
+words_bench!(words1000_djb2_64, DJB2Hasher64, 1000);
+words_bench!(words1000_sdbm64, SDBMHasher64, 1000);
+words_bench!(words1000_oaat32, OAATHasher32, 1000);
+words_bench!(words1000_bricolage64, Bricolage64, 1000);
 
 macro_rules! file_bench {
     ($name:ident, $fcn:ident) => {
@@ -206,3 +506,34 @@ file_bench!(file_fnv1a32, fnv1a32);
 file_bench!(file_fxhash, fxhash);
 file_bench!(file_spooky, spooky);
 file_bench!(file_bricolage, bricolage);
+file_bench!(file_xxhash32, xxhash32);
+file_bench!(file_xxhash64, xxhash64);
+file_bench!(file_xxh3_64, xxh3_64);
+file_bench!(file_murmur3_32, murmur3_32);
+file_bench!(file_murmur3_x86_128, murmur3_x86_128);
+file_bench!(file_murmur3_x64_128, murmur3_x64_128);
+file_bench!(file_murmur2, murmur2);
+file_bench!(file_murmur64a, murmur64a);
+file_bench!(file_murmur64b, murmur64b);
+file_bench!(file_cityhash64, cityhash64);
+file_bench!(file_farmhash64, farmhash64);
+file_bench!(file_fingerprint64, fingerprint64);
+file_bench!(file_wyhash, wyhash);
+file_bench!(file_rapidhash, rapidhash);
+file_bench!(file_siphash24, siphash24);
+file_bench!(file_siphash13, siphash13);
+file_bench!(file_halfsiphash, halfsiphash);
+file_bench!(file_highwayhash, highwayhash);
+file_bench!(file_crc32, crc32);
+file_bench!(file_crc32c, crc32c);
+file_bench!(file_crc64, crc64);
+file_bench!(file_adler32, adler32);
+file_bench!(file_fletcher16, fletcher16);
+file_bench!(file_fletcher32, fletcher32);
+file_bench!(file_fletcher64, fletcher64);
+file_bench!(file_fnv1_64, fnv1_64);
+file_bench!(file_lookup2, lookup2);
+file_bench!(file_djb2_64, djb2_64);
+file_bench!(file_sdbm64, sdbm64);
+file_bench!(file_oaat32, oaat32);
+file_bench!(file_bricolage64, bricolage64);
@@ -8,7 +8,7 @@ use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
 use std::io::{BufRead, BufReader};
 use std::{fs, time};
 
-use hashers::{builtin, fnv, fx_hash, jenkins, oz, pigeon};
+use hashers::{builtin, fnv, fx_hash, jenkins, oz, pigeon, wy};
 
 pub mod combinations;
 
@@ -112,6 +112,9 @@ fn main() {
     time::<fx_hash::FxHasher>("fxhash", baseline);
     time::<fx_hash::FxHasher32>("fxhash32", baseline);
     time::<fx_hash::FxHasher64>("fxhash64", baseline);
+    time::<wy::WyHasher>("wyhash", baseline);
+    time::<wy::RapidHasher>("rapidhash", baseline);
     time::<jenkins::spooky_hash::SpookyHasher>("spooky", baseline);
     time::<pigeon::Bricolage>("bricolage", baseline);
+    time::<pigeon::Bricolage64>("bricolage64", baseline);
 }
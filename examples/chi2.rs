@@ -4,7 +4,7 @@ extern crate rand;
 
 extern crate hashers;
 
-use hashers::{builtin, fnv, fx_hash, jenkins, null, pigeon, oz};
+use hashers::{builtin, crc, fnv, fx_hash, google, highway, jenkins, murmur, null, pigeon, oz, sip, wy, xxhash};
 
 mod samples;
 
@@ -64,6 +64,42 @@ fn do_hashes(samples: &[Vec<u8>]) {
     do_print("Pass",      chi2(&samples, null::passthrough,            7));
     do_print("sdbm",      chi2(&samples, oz::sdbm,                     7));
     do_print("spooky",    chi2(&samples, jenkins::spooky_hash::spooky, 7));
+    do_print("xxhash32",  chi2(&samples, xxhash::xxhash32,             7));
+    do_print("xxhash64",  chi2(&samples, xxhash::xxhash64,             7));
+    do_print("xxh3",      chi2(&samples, xxhash::xxh3::xxh3_64,        7));
+    do_print("mmh3_32",   chi2(&samples, murmur::murmur3_32,           7));
+    do_print("mmh3_x64",  chi2(&samples, murmur::murmur3_x64_128,      7));
+    do_print("city64",    chi2(&samples, google::cityhash64,           7));
+    do_print("farm64",    chi2(&samples, google::farmhash64,           7));
+    do_print("wyhash",    chi2(&samples, wy::wyhash,                   7));
+    do_print("rapidhash", chi2(&samples, wy::rapidhash,                7));
+    do_print("siphash24", chi2(&samples, sip::siphash24,               7));
+    do_print("siphash13", chi2(&samples, sip::siphash13,               7));
+    do_print("highway",   chi2(&samples, highway::highwayhash,         7));
+    do_print("crc32",     chi2(&samples, crc::crc32,                   7));
+    do_print("crc32c",    chi2(&samples, crc::crc32c,                  7));
+    do_print("crc64",     chi2(&samples, crc::crc64,                   7));
+    do_print("lookup2",   chi2(&samples, jenkins::lookup2,             7));
+    do_print("additive",  chi2(&samples, jenkins::additive,            7));
+    do_print("rotating",  chi2(&samples, jenkins::rotating,            7));
+    do_print("bernstein", chi2(&samples, jenkins::bernstein,           7));
+    do_print("zobrist",   chi2(&samples, jenkins::zobrist,             7));
+    do_print("pearson",   chi2(&samples, jenkins::pearson,             7));
+    do_print("djb2 64",   chi2(&samples, oz::djb2_64,                  7));
+    do_print("sdbm 64",   chi2(&samples, oz::sdbm64,                   7));
+    do_print("kr2",       chi2(&samples, oz::kr2,                      7));
+    do_print("elf",       chi2(&samples, oz::elf,                      7));
+    do_print("java",      chi2(&samples, oz::java,                     7));
+    do_print("djbx33a",   chi2(&samples, oz::djbx33a,                  7));
+    do_print("perl oaat", chi2(&samples, oz::perl_oaat_hard,           7));
+    do_print("checksum",  chi2(&samples, pigeon::checksum,             7));
+    do_print("shift add", chi2(&samples, pigeon::shift_add,            7));
+    do_print("rot xor",   chi2(&samples, pigeon::rotate_xor,           7));
+    do_print("cut deck",  chi2(&samples, pigeon::cut_deck_hash,        7));
+    do_print("shuffle",   chi2(&samples, pigeon::perfect_shuffle,      7));
+    do_print("shuf cut",  chi2(&samples, pigeon::shuffle_cut_deck,     7));
+    do_print("scrambler", chi2(&samples, pigeon::scrambler,            7));
+    do_print("brico 64",  chi2(&samples, pigeon::bricolage64,          7));
 }
 
 fn main() {
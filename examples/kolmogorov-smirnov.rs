@@ -62,6 +62,28 @@ fn run_sample(name: &str, samples: &[Vec<u8>]) {
     print_ks(name, "passthru ", ks(&do_hashes(hashers::null::passthrough, samples)));
     print_ks(name, "sdbm     ", ks(&do_hashes(hashers::oz::sdbm, samples)));
     print_ks(name, "spooky   ", ks(&do_hashes(hashers::jenkins::spooky_hash::spooky, samples)));
+    print_ks(name, "xxhash32 ", ks(&do_hashes(hashers::xxhash::xxhash32, samples)));
+    print_ks(name, "xxhash64 ", ks(&do_hashes(hashers::xxhash::xxhash64, samples)));
+    print_ks(name, "xxh3     ", ks(&do_hashes(hashers::xxhash::xxh3::xxh3_64, samples)));
+    print_ks(name, "mmh3_32  ", ks(&do_hashes(hashers::murmur::murmur3_32, samples)));
+    print_ks(name, "mmh3_x64 ", ks(&do_hashes(hashers::murmur::murmur3_x64_128, samples)));
+    print_ks(name, "city64   ", ks(&do_hashes(hashers::google::cityhash64, samples)));
+    print_ks(name, "farm64   ", ks(&do_hashes(hashers::google::farmhash64, samples)));
+    print_ks(name, "wyhash   ", ks(&do_hashes(hashers::wy::wyhash, samples)));
+    print_ks(name, "rapidhash", ks(&do_hashes(hashers::wy::rapidhash, samples)));
+    print_ks(name, "siphash24", ks(&do_hashes(hashers::sip::siphash24, samples)));
+    print_ks(name, "siphash13", ks(&do_hashes(hashers::sip::siphash13, samples)));
+    print_ks(name, "highway  ", ks(&do_hashes(hashers::highway::highwayhash, samples)));
+    print_ks(name, "djb2 64  ", ks(&do_hashes(hashers::oz::djb2_64, samples)));
+    print_ks(name, "sdbm 64  ", ks(&do_hashes(hashers::oz::sdbm64, samples)));
+    print_ks(name, "checksum ", ks(&do_hashes(hashers::pigeon::checksum, samples)));
+    print_ks(name, "shift add", ks(&do_hashes(hashers::pigeon::shift_add, samples)));
+    print_ks(name, "rot xor  ", ks(&do_hashes(hashers::pigeon::rotate_xor, samples)));
+    print_ks(name, "cut deck ", ks(&do_hashes(hashers::pigeon::cut_deck_hash, samples)));
+    print_ks(name, "shuffle  ", ks(&do_hashes(hashers::pigeon::perfect_shuffle, samples)));
+    print_ks(name, "shuf cut ", ks(&do_hashes(hashers::pigeon::shuffle_cut_deck, samples)));
+    print_ks(name, "scrambler", ks(&do_hashes(hashers::pigeon::scrambler, samples)));
+    print_ks(name, "brico 64 ", ks(&do_hashes(hashers::pigeon::bricolage64, samples)));
 }
 
 fn main() {
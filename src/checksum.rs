@@ -0,0 +1,438 @@
+//! Position-dependent checksums: Adler-32 and the Fletcher family.
+//!
+//! Both keep two running sums: the sum of the input words, and the sum
+//! of those sums, so that, unlike a simple sum, reordering the input
+//! changes the result. Like the CRCs (see crc), these are integrity
+//! checks rather than hash table hashes.
+//!
+//! Adler-32 is the checksum of the zlib format, described in RFC 1950:
+//!
+//! > Adler-32 is composed of two sums accumulated per byte: s1 is
+//! > the sum of all bytes, s2 is the sum of all s1 values. Both sums
+//! > are done modulo 65521.
+//!
+//! Fletcher-16, -32 and -64 sum 8-, 16- and 32-bit words modulo 255,
+//! 65535 and 2^32 - 1. The wider variants read their words as
+//! little-endian, and pad a partial final word with zeros, which is what
+//! the usual implementations on x86 compute.
+//!
+//! All of these defer the expensive modulo operations as long as the
+//! sums cannot overflow.
+//!
+//! A seed gives the sums their starting values, as if the checksum were
+//! continuing from some earlier input.
+
+use std::hash::Hasher;
+
+use SeedableHasher;
+
+// ====================================
+// Adler-32
+
+/// > largest prime smaller than 65536
+const ADLER_BASE: u32 = 65521;
+
+/// The largest number of bytes that can be summed before the modulo
+/// without overflowing a u32, from zlib:
+///
+/// > NMAX is the largest n such that 255n(n+1)/2 + (n+1)(BASE-1) <= 2^32-1
+const ADLER_NMAX: usize = 5552;
+
+/// Adler-32, as used by zlib.
+pub struct Adler32Hasher {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32Hasher {
+    /// Continue the checksum from a previous result; Default starts
+    /// from the initial value, 1.
+    pub fn new(adler: u32) -> Adler32Hasher {
+        Adler32Hasher {
+            a: adler & 0xffff,
+            b: adler >> 16,
+        }
+    }
+
+    /// The checksum of the input so far.
+    pub fn finish32(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+impl Default for Adler32Hasher {
+    fn default() -> Adler32Hasher {
+        Adler32Hasher::new(1)
+    }
+}
+
+/// The seed's low 32 bits, exclusive-ored with the initial value 1, are
+/// a previous checksum to continue from, each half reduced modulo 65521.
+impl SeedableHasher for Adler32Hasher {
+    fn with_seed(seed: u64) -> Adler32Hasher {
+        let adler = 1 ^ seed as u32;
+        Adler32Hasher {
+            a: (adler & 0xffff) % ADLER_BASE,
+            b: (adler >> 16) % ADLER_BASE,
+        }
+    }
+}
+
+impl Hasher for Adler32Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(ADLER_NMAX) {
+            for &byte in chunk {
+                self.a += byte as u32;
+                self.b += self.a;
+            }
+            self.a %= ADLER_BASE;
+            self.b %= ADLER_BASE;
+        }
+    }
+}
+
+random_state!(RandomAdler32State, Adler32Hasher);
+hash_collections!(Adler32HashMap, Adler32HashSet, Adler32Hasher);
+hasher_to_fcn!(
+    /// Provide access to Adler32Hasher in a single call.
+    adler32,
+    Adler32Hasher
+);
+
+/// Combine the Adler-32 checksums of two pieces of data into the
+/// checksum of their concatenation, given the length of the second,
+/// without access to the data itself. This is zlib's
+/// `adler32_combine`.
+pub fn adler32_combine(adler1: u32, adler2: u32, len2: u64) -> u32 {
+    let base = ADLER_BASE as u64;
+    let rem = len2 % base;
+    let mut sum1 = (adler1 & 0xffff) as u64;
+    let mut sum2 = (rem * sum1) % base;
+    sum1 += (adler2 & 0xffff) as u64 + base - 1;
+    sum2 += (adler1 >> 16) as u64 + (adler2 >> 16) as u64 + base - rem;
+    if sum1 >= base {
+        sum1 -= base;
+    }
+    if sum1 >= base {
+        sum1 -= base;
+    }
+    if sum2 >= base << 1 {
+        sum2 -= base << 1;
+    }
+    if sum2 >= base {
+        sum2 -= base;
+    }
+    (sum1 | (sum2 << 16)) as u32
+}
+
+// ====================================
+// Fletcher
+
+/// The number of words summed before reducing the sums. With 32-bit
+/// words, sum2 stays below 2^57.
+const FLETCHER_BLOCK: usize = 4096;
+
+/// The Fletcher sums over words of `width` bytes.
+#[derive(Clone, Copy)]
+struct Fletcher {
+    sum1: u64,
+    sum2: u64,
+    modulus: u64,
+    width: usize,
+    // words summed since the last reduction
+    pending: usize,
+    // unhashed data, for partial words
+    buf: [u8; 4],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+}
+
+impl Fletcher {
+    fn new(width: usize) -> Fletcher {
+        Fletcher::with_seed(width, 0)
+    }
+
+    // Start the sums from the seed's low and high 32 bits, reduced.
+    fn with_seed(width: usize, seed: u64) -> Fletcher {
+        let modulus = (1u64 << (8 * width)) - 1;
+        Fletcher {
+            sum1: (seed & 0xffff_ffff) % modulus,
+            sum2: (seed >> 32) % modulus,
+            modulus,
+            width,
+            pending: 0,
+            buf: [0; 4],
+            buf_len: 0,
+        }
+    }
+
+    #[inline]
+    fn add(&mut self, word: &[u8]) {
+        let word = word
+            .iter()
+            .rev()
+            .fold(0u64, |acc, &byte| (acc << 8) | byte as u64);
+        self.sum1 += word;
+        self.sum2 += self.sum1;
+        self.pending += 1;
+        if self.pending == FLETCHER_BLOCK {
+            self.reduce();
+        }
+    }
+
+    #[inline]
+    fn reduce(&mut self) {
+        self.sum1 %= self.modulus;
+        self.sum2 %= self.modulus;
+        self.pending = 0;
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        if self.buf_len > 0 {
+            let fill = (self.width - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+            if self.buf_len < self.width {
+                return;
+            }
+            let word = self.buf;
+            self.add(&word[..self.width]);
+            self.buf_len = 0;
+        }
+        let mut words = bytes.chunks_exact(self.width);
+        for word in &mut words {
+            self.add(word);
+        }
+        let rest = words.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+
+    fn finish(&self) -> u64 {
+        let mut sums = *self;
+        if self.buf_len > 0 {
+            let mut word = [0u8; 4];
+            word[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+            sums.add(&word[..self.width]);
+        }
+        sums.reduce();
+        (sums.sum2 << (8 * self.width)) | sums.sum1
+    }
+}
+
+/// Fletcher-16: bytes, summed modulo 255.
+pub struct Fletcher16Hasher(Fletcher);
+
+impl Default for Fletcher16Hasher {
+    fn default() -> Fletcher16Hasher {
+        Fletcher16Hasher(Fletcher::new(1))
+    }
+}
+
+impl SeedableHasher for Fletcher16Hasher {
+    fn with_seed(seed: u64) -> Fletcher16Hasher {
+        Fletcher16Hasher(Fletcher::with_seed(1, seed))
+    }
+}
+
+impl Hasher for Fletcher16Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomFletcher16State, Fletcher16Hasher);
+hash_collections!(Fletcher16HashMap, Fletcher16HashSet, Fletcher16Hasher);
+hasher_to_fcn!(
+    /// Provide access to Fletcher16Hasher in a single call.
+    fletcher16,
+    Fletcher16Hasher
+);
+
+/// Fletcher-32: 16-bit words, summed modulo 65535.
+pub struct Fletcher32Hasher(Fletcher);
+
+impl Fletcher32Hasher {
+    /// The checksum of the input so far.
+    pub fn finish32(&self) -> u32 {
+        self.0.finish() as u32
+    }
+}
+
+impl Default for Fletcher32Hasher {
+    fn default() -> Fletcher32Hasher {
+        Fletcher32Hasher(Fletcher::new(2))
+    }
+}
+
+impl SeedableHasher for Fletcher32Hasher {
+    fn with_seed(seed: u64) -> Fletcher32Hasher {
+        Fletcher32Hasher(Fletcher::with_seed(2, seed))
+    }
+}
+
+impl Hasher for Fletcher32Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomFletcher32State, Fletcher32Hasher);
+hash_collections!(Fletcher32HashMap, Fletcher32HashSet, Fletcher32Hasher);
+hasher_to_fcn!(
+    /// Provide access to Fletcher32Hasher in a single call.
+    fletcher32,
+    Fletcher32Hasher
+);
+
+/// Fletcher-64: 32-bit words, summed modulo 2^32 - 1.
+pub struct Fletcher64Hasher(Fletcher);
+
+impl Default for Fletcher64Hasher {
+    fn default() -> Fletcher64Hasher {
+        Fletcher64Hasher(Fletcher::new(4))
+    }
+}
+
+impl SeedableHasher for Fletcher64Hasher {
+    fn with_seed(seed: u64) -> Fletcher64Hasher {
+        Fletcher64Hasher(Fletcher::with_seed(4, seed))
+    }
+}
+
+impl Hasher for Fletcher64Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomFletcher64State, Fletcher64Hasher);
+hash_collections!(Fletcher64HashMap, Fletcher64HashSet, Fletcher64Hasher);
+hasher_to_fcn!(
+    /// Provide access to Fletcher64Hasher in a single call.
+    fletcher64,
+    Fletcher64Hasher
+);
+
+#[cfg(test)]
+mod checksum_tests {
+    use super::*;
+    use check_seedable;
+
+    fn big() -> Vec<u8> {
+        (0..100_000u32).map(|i| (i * 7 + 3) as u8).collect()
+    }
+
+    #[test]
+    fn adler() {
+        // Computed with zlib's adler32.
+        assert_eq!(adler32(b""), 0x00000001);
+        assert_eq!(adler32(b"a"), 0x00620062);
+        assert_eq!(adler32(b"abc"), 0x024d0127);
+        assert_eq!(adler32(b"message digest"), 0x29750586);
+        assert_eq!(adler32(b"abcdefghijklmnopqrstuvwxyz"), 0x90860b20);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+        // long enough to need the deferred modulo
+        assert_eq!(adler32(&big()), 0x2dfb940f);
+        assert_eq!(adler32(&[0xff; 100_000]), 0x149a302c);
+    }
+
+    #[test]
+    fn adler_continue() {
+        let mut hasher = Adler32Hasher::new(adler32(b"message ") as u32);
+        hasher.write(b"digest");
+        assert_eq!(hasher.finish(), adler32(b"message digest"));
+    }
+
+    #[test]
+    fn adler_combine() {
+        let first = b"The quick brown fox ";
+        let second = b"jumps over the lazy dog".repeat(400);
+        let mut whole = first.to_vec();
+        whole.extend_from_slice(&second);
+        let combined = adler32_combine(
+            adler32(first) as u32,
+            adler32(&second) as u32,
+            second.len() as u64,
+        );
+        assert_eq!(combined, 0xec878b57);
+        assert_eq!(combined as u64, adler32(&whole));
+        assert_eq!(adler32_combine(adler32(b"abc") as u32, 1, 0), 0x024d0127);
+    }
+
+    #[test]
+    fn fletcher() {
+        assert_eq!(fletcher16(b"abcde"), 0xc8f0);
+        assert_eq!(fletcher16(b"abcdef"), 0x2057);
+        assert_eq!(fletcher16(b"abcdefgh"), 0x0627);
+        assert_eq!(fletcher32(b"abcde"), 0xf04fc729);
+        assert_eq!(fletcher32(b"abcdef"), 0x56502d2a);
+        assert_eq!(fletcher32(b"abcdefgh"), 0xebe19591);
+        assert_eq!(fletcher64(b"abcde"), 0xc8c6c527646362c6);
+        assert_eq!(fletcher64(b"abcdef"), 0xc8c72b276463c8c6);
+        assert_eq!(fletcher64(b"abcdefgh"), 0x312e2b28cccac8c6);
+        let big = big();
+        assert_eq!(fletcher16(&big), 0xecfb);
+        assert_eq!(fletcher32(&big), 0xa9e6f704);
+        assert_eq!(fletcher64(&big), 0x9510183fb17c4588);
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut adler = Adler32Hasher::default();
+            let mut f16 = Fletcher16Hasher::default();
+            let mut f32 = Fletcher32Hasher::default();
+            let mut f64 = Fletcher64Hasher::default();
+            for chunk in data.chunks(split + 1) {
+                adler.write(chunk);
+                f16.write(chunk);
+                f32.write(chunk);
+                f64.write(chunk);
+            }
+            assert_eq!(adler.finish(), adler32(&data));
+            assert_eq!(f16.finish(), fletcher16(&data));
+            assert_eq!(f32.finish(), fletcher32(&data));
+            assert_eq!(f64.finish(), fletcher64(&data));
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Adler32Hasher>(true);
+        check_seedable::<Fletcher16Hasher>(true);
+        check_seedable::<Fletcher32Hasher>(true);
+        check_seedable::<Fletcher64Hasher>(true);
+        // Seeding with a checksum continues from it.
+        let mut hasher = Adler32Hasher::with_seed(1 ^ adler32(b"Wiki"));
+        hasher.write(b"pedia");
+        assert_eq!(hasher.finish(), adler32(b"Wikipedia"));
+    }
+}
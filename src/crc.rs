@@ -0,0 +1,341 @@
+//! Cyclic redundancy checks.
+//!
+//! CRCs are checksums, designed to detect burst errors in data, rather
+//! than hash functions: they are linear, so flipping a given bit of the
+//! input always flips the same bits of the result, and they do poorly as
+//! hash table hashes (see the chi2 example). They are here for
+//! integrity checks of data written elsewhere by other tools.
+//!
+//! All of these are the reflected (least-significant bit first) forms,
+//! with the register initialized to all ones and the result inverted:
+//!
+//! - CRC-32: the IEEE 802.3 polynomial, as used by Ethernet, zlib, gzip
+//!   and PNG.
+//! - CRC-32C: Castagnoli's polynomial, as used by iSCSI, ext4 and
+//!   SSE 4.2's `crc32` instruction.
+//! - CRC-64: the ECMA-182 polynomial with the parameters of xz (and Go's
+//!   `crc64.ECMA` table), also known as CRC-64/XZ.
+//!
+//! A seed is a previous CRC to continue from: the CRC of some input,
+//! seeded with the CRC of what came before it, is the CRC of the whole.
+//!
+//! The implementations are table-driven, "slicing-by-8": eight 256-entry
+//! tables, computed at compile time, let each step consume eight bytes
+//! at once.
+
+use std::hash::Hasher;
+use std::{mem, ptr};
+
+/// Reflected CRC-32 polynomials.
+const CRC32_POLY: u32 = 0xedb88320;
+const CRC32C_POLY: u32 = 0x82f63b78;
+/// The reflected ECMA-182 polynomial.
+const CRC64_POLY: u64 = 0xc96c5795d7870f42;
+
+/// Build the slicing-by-8 tables: `table[0]` is the usual byte-at-a-time
+/// table and `table[k][i]` is the CRC of byte i followed by k zero bytes.
+const fn tables32(poly: u32) -> [[u32; 256]; 8] {
+    let mut table = [[0u32; 256]; 8];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[0][i] = crc;
+        i += 1;
+    }
+    let mut k = 1;
+    while k < 8 {
+        let mut i = 0;
+        while i < 256 {
+            let prev = table[k - 1][i];
+            table[k][i] = (prev >> 8) ^ table[0][(prev & 0xff) as usize];
+            i += 1;
+        }
+        k += 1;
+    }
+    table
+}
+
+/// As tables32, for 64-bit CRCs.
+const fn tables64(poly: u64) -> [[u64; 256]; 8] {
+    let mut table = [[0u64; 256]; 8];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[0][i] = crc;
+        i += 1;
+    }
+    let mut k = 1;
+    while k < 8 {
+        let mut i = 0;
+        while i < 256 {
+            let prev = table[k - 1][i];
+            table[k][i] = (prev >> 8) ^ table[0][(prev & 0xff) as usize];
+            i += 1;
+        }
+        k += 1;
+    }
+    table
+}
+
+static CRC32_TABLES: [[u32; 256]; 8] = tables32(CRC32_POLY);
+static CRC32C_TABLES: [[u32; 256]; 8] = tables32(CRC32C_POLY);
+static CRC64_TABLES: [[u64; 256]; 8] = tables64(CRC64_POLY);
+
+/// Update the (uninverted) crc with bytes.
+#[inline]
+fn update32(table: &[[u32; 256]; 8], mut crc: u32, bytes: &[u8]) -> u32 {
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let lo = crc ^ load_int_le!(chunk, 0, u32);
+        let hi = load_int_le!(chunk, 4, u32);
+        crc = table[7][(lo & 0xff) as usize]
+            ^ table[6][((lo >> 8) & 0xff) as usize]
+            ^ table[5][((lo >> 16) & 0xff) as usize]
+            ^ table[4][(lo >> 24) as usize]
+            ^ table[3][(hi & 0xff) as usize]
+            ^ table[2][((hi >> 8) & 0xff) as usize]
+            ^ table[1][((hi >> 16) & 0xff) as usize]
+            ^ table[0][(hi >> 24) as usize];
+    }
+    for &byte in chunks.remainder() {
+        crc = table[0][((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    crc
+}
+
+/// Update the (uninverted) crc with bytes.
+#[inline]
+fn update64(table: &[[u64; 256]; 8], mut crc: u64, bytes: &[u8]) -> u64 {
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let v = crc ^ load_int_le!(chunk, 0, u64);
+        crc = table[7][(v & 0xff) as usize]
+            ^ table[6][((v >> 8) & 0xff) as usize]
+            ^ table[5][((v >> 16) & 0xff) as usize]
+            ^ table[4][((v >> 24) & 0xff) as usize]
+            ^ table[3][((v >> 32) & 0xff) as usize]
+            ^ table[2][((v >> 40) & 0xff) as usize]
+            ^ table[1][((v >> 48) & 0xff) as usize]
+            ^ table[0][(v >> 56) as usize];
+    }
+    for &byte in chunks.remainder() {
+        crc = table[0][((crc ^ byte as u64) & 0xff) as usize] ^ (crc >> 8);
+    }
+    crc
+}
+
+// ====================================
+// CRC-32
+
+/// CRC-32 (IEEE 802.3).
+pub struct Crc32Hasher(u32);
+
+impl Crc32Hasher {
+    /// The CRC of the input so far.
+    pub fn finish32(&self) -> u32 {
+        !self.0
+    }
+}
+
+default_for_constant!(Crc32Hasher, !0);
+seedable_for_constant!(Crc32Hasher, seed => !(seed as u32));
+
+impl Hasher for Crc32Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = update32(&CRC32_TABLES, self.0, bytes);
+    }
+}
+
+random_state!(RandomCrc32State, Crc32Hasher);
+hash_collections!(Crc32HashMap, Crc32HashSet, Crc32Hasher);
+hasher_to_fcn!(
+    /// Provide access to Crc32Hasher in a single call.
+    crc32,
+    Crc32Hasher
+);
+
+// ------------------------------------
+// CRC-32C
+
+/// CRC-32C (Castagnoli).
+pub struct Crc32cHasher(u32);
+
+impl Crc32cHasher {
+    /// The CRC of the input so far.
+    pub fn finish32(&self) -> u32 {
+        !self.0
+    }
+}
+
+default_for_constant!(Crc32cHasher, !0);
+seedable_for_constant!(Crc32cHasher, seed => !(seed as u32));
+
+impl Hasher for Crc32cHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = update32(&CRC32C_TABLES, self.0, bytes);
+    }
+}
+
+random_state!(RandomCrc32cState, Crc32cHasher);
+hash_collections!(Crc32cHashMap, Crc32cHashSet, Crc32cHasher);
+hasher_to_fcn!(
+    /// Provide access to Crc32cHasher in a single call.
+    crc32c,
+    Crc32cHasher
+);
+
+// ====================================
+// CRC-64
+
+/// CRC-64/XZ (the ECMA-182 polynomial).
+pub struct Crc64Hasher(u64);
+
+default_for_constant!(Crc64Hasher, !0);
+seedable_for_constant!(Crc64Hasher, seed => !seed);
+
+impl Hasher for Crc64Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        !self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = update64(&CRC64_TABLES, self.0, bytes);
+    }
+}
+
+random_state!(RandomCrc64State, Crc64Hasher);
+hash_collections!(Crc64HashMap, Crc64HashSet, Crc64Hasher);
+hasher_to_fcn!(
+    /// Provide access to Crc64Hasher in a single call.
+    crc64,
+    Crc64Hasher
+);
+
+#[cfg(test)]
+mod crc_tests {
+    use super::*;
+    use {check_seedable, SeedableHasher};
+
+    // Bit-at-a-time CRCs, straight from the definition.
+    fn bitwise32(poly: u32, bytes: &[u8]) -> u32 {
+        let mut crc = !0u32;
+        for &byte in bytes {
+            crc ^= byte as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ poly
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+        !crc
+    }
+
+    fn bitwise64(poly: u64, bytes: &[u8]) -> u64 {
+        let mut crc = !0u64;
+        for &byte in bytes {
+            crc ^= byte as u64;
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ poly
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+        !crc
+    }
+
+    #[test]
+    fn check() {
+        // The "check" values from the catalogue of CRC parameters.
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(crc32c(b"123456789"), 0xe3069283);
+        assert_eq!(crc64(b"123456789"), 0x995dc9bbdf1939fa);
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32c(b""), 0);
+        assert_eq!(crc64(b""), 0);
+        assert_eq!(
+            crc32(b"The quick brown fox jumps over the lazy dog"),
+            0x414fa339
+        );
+    }
+
+    #[test]
+    fn slicing() {
+        let data: Vec<u8> = (0..100u32).map(|i| (i * 37 + 11) as u8).collect();
+        for n in 0..data.len() {
+            let bytes = &data[..n];
+            assert_eq!(crc32(bytes), bitwise32(CRC32_POLY, bytes) as u64);
+            assert_eq!(crc32c(bytes), bitwise32(CRC32C_POLY, bytes) as u64);
+            assert_eq!(crc64(bytes), bitwise64(CRC64_POLY, bytes));
+        }
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut h32 = Crc32Hasher::default();
+            let mut h32c = Crc32cHasher::default();
+            let mut h64 = Crc64Hasher::default();
+            for chunk in data.chunks(split + 1) {
+                h32.write(chunk);
+                h32c.write(chunk);
+                h64.write(chunk);
+            }
+            assert_eq!(h32.finish(), crc32(&data));
+            assert_eq!(h32c.finish(), crc32c(&data));
+            assert_eq!(h64.finish(), crc64(&data));
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Crc32Hasher>(true);
+        check_seedable::<Crc32cHasher>(true);
+        check_seedable::<Crc64Hasher>(true);
+        let mut hasher = Crc32Hasher::with_seed(crc32(b"1234"));
+        hasher.write(b"56789");
+        assert_eq!(hasher.finish(), crc32(b"123456789"));
+        let mut hasher = Crc32cHasher::with_seed(crc32c(b"1234"));
+        hasher.write(b"56789");
+        assert_eq!(hasher.finish(), crc32c(b"123456789"));
+        let mut hasher = Crc64Hasher::with_seed(crc64(b"1234"));
+        hasher.write(b"56789");
+        assert_eq!(hasher.finish(), crc64(b"123456789"));
+    }
+}
@@ -0,0 +1,642 @@
+//! The [Fowler–Noll–Vo hash function](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function).
+//!
+//! http://www.isthe.com/chongo/tech/comp/fnv/
+//!
+//! > The basis of the FNV hash algorithm was taken from an idea sent as reviewer comments to the
+//! > IEEE POSIX P1003.2 committee by Glenn Fowler and Phong Vo back in 1991. In a subsequent ballot
+//! > round: Landon Curt Noll improved on their algorithm. Some people tried this hash and found that
+//! > it worked rather well. In an EMail message to Landon, they named it the ``Fowler/Noll/Vo'' or
+//! > FNV hash.
+//! >
+//! > FNV hashes are designed to be fast while maintaining a low collision rate. The FNV speed allows
+//! > one to quickly hash lots of data while maintaining a reasonable collision rate. The high
+//! > dispersion of the FNV hashes makes them well suited for hashing nearly identical strings such
+//! > as URLs, hostnames, filenames, text, IP addresses, etc.
+//! >
+//! > The IETF has an informational draft on The FNV Non-Cryptographic Hash Algorithm
+//!
+//! This module provides:
+//!
+//! - FNV-1a, which xors each byte into the hash and then multiplies by the FNV prime, in 32 and
+//!   64 bits, and in the wide 128-, 256-, 512- and 1024-bit versions.
+//! - FNV-1, which multiplies first and then xors, in the same widths, for compatibility with data
+//!   hashed by it. FNV-1a disperses better and should be preferred.
+//! - FNV-0, the historic FNV-1 with a zero offset basis, in 32 and 64 bits only. It is deprecated,
+//!   and here only for compatibility.
+//!
+//! The wide Hashers' `finish` returns the low 64 bits of the hash; `finish_wide` returns all of
+//! it, as 64-bit words, least significant first.
+//!
+//! Seeds are exclusive-ored into the offset basis (its low word, for the wide Hashers: carries
+//! only run upward, so higher words would not affect `finish`), so `with_seed(0)` is the standard
+//! hash, and FNV-0 with a seed is FNV-1 with a different offset basis.
+//!
+//! For other sizes, the IETF draft recommends xor-folding the next larger FNV hash down to the
+//! desired number of bits, rather than masking off the low bits (see xor_fold32 and xor_fold64),
+//! and for ranges that are not powers of two, "lazy mod mapping" (see lazy_mod32 and
+//! lazy_mod64). The 32- and 64-bit FNV-1a and FNV-1 Hashers provide both as `finish_bits` and
+//! `finish_lazy_mod`.
+
+use std::hash::Hasher;
+
+use SeedableHasher;
+
+macro_rules! fnv1a {
+    ($name:ident, $size:ty, $fnv_prime:expr, $offset_basis:expr) => {
+        pub struct $name($size);
+        impl Hasher for $name {
+            #[inline]
+            fn finish(&self) -> u64 {
+                self.0 as u64
+            }
+            #[inline]
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes.iter() {
+                    self.0 ^= *byte as $size;
+                    self.0 = self.0.wrapping_mul($fnv_prime);
+                }
+            }
+        }
+        default_for_constant!($name, $offset_basis);
+        seedable_for_constant!($name, seed => $offset_basis ^ seed as $size);
+    };
+}
+
+macro_rules! fnv1 {
+    ($name:ident, $size:ty, $fnv_prime:expr, $offset_basis:expr) => {
+        pub struct $name($size);
+        impl Hasher for $name {
+            #[inline]
+            fn finish(&self) -> u64 {
+                self.0 as u64
+            }
+            #[inline]
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes.iter() {
+                    self.0 = self.0.wrapping_mul($fnv_prime);
+                    self.0 ^= *byte as $size;
+                }
+            }
+        }
+        default_for_constant!($name, $offset_basis);
+        seedable_for_constant!($name, seed => $offset_basis ^ seed as $size);
+    };
+}
+
+fnv1a!(FNV1aHasher32, u32, 16777619, 0x811c9dc5);
+fnv1a!(FNV1aHasher64, u64, 1099511628211, 0xcbf29ce484222325);
+random_state!(RandomFNV1aState32, FNV1aHasher32);
+random_state!(RandomFNV1aState64, FNV1aHasher64);
+hash_collections!(FNV1aHashMap32, FNV1aHashSet32, FNV1aHasher32);
+hash_collections!(FNV1aHashMap64, FNV1aHashSet64, FNV1aHasher64);
+
+/// The usual FNV HashMap, using FNV-1a with 64 bits.
+pub type FnvHashMap<K, V> = FNV1aHashMap64<K, V>;
+/// The usual FNV HashSet, using FNV-1a with 64 bits.
+pub type FnvHashSet<T> = FNV1aHashSet64<T>;
+
+hasher_to_fcn!(
+    /// Provide access to FNV1aHasher32 in a single call.
+    fnv1a32,
+    FNV1aHasher32
+);
+
+hasher_to_fcn!(
+    /// Provide access to FNV1aHasher64 in a single call.
+    fnv1a64,
+    FNV1aHasher64
+);
+
+fnv1!(FNV1Hasher32, u32, 16777619, 0x811c9dc5);
+fnv1!(FNV1Hasher64, u64, 1099511628211, 0xcbf29ce484222325);
+random_state!(RandomFNV1State32, FNV1Hasher32);
+random_state!(RandomFNV1State64, FNV1Hasher64);
+hash_collections!(FNV1HashMap32, FNV1HashSet32, FNV1Hasher32);
+hash_collections!(FNV1HashMap64, FNV1HashSet64, FNV1Hasher64);
+
+hasher_to_fcn!(
+    /// Provide access to FNV1Hasher32 in a single call.
+    fnv1_32,
+    FNV1Hasher32
+);
+
+hasher_to_fcn!(
+    /// Provide access to FNV1Hasher64 in a single call.
+    fnv1_64,
+    FNV1Hasher64
+);
+
+fnv1!(FNV0Hasher32, u32, 16777619, 0);
+fnv1!(FNV0Hasher64, u64, 1099511628211, 0);
+random_state!(RandomFNV0State32, FNV0Hasher32);
+random_state!(RandomFNV0State64, FNV0Hasher64);
+hash_collections!(FNV0HashMap32, FNV0HashSet32, FNV0Hasher32);
+hash_collections!(FNV0HashMap64, FNV0HashSet64, FNV0Hasher64);
+
+hasher_to_fcn!(
+    /// Provide access to FNV0Hasher32 in a single call.
+    fnv0_32,
+    FNV0Hasher32
+);
+
+hasher_to_fcn!(
+    /// Provide access to FNV0Hasher64 in a single call.
+    fnv0_64,
+    FNV0Hasher64
+);
+
+// ====================================
+// Other hash sizes
+
+/// Xor-fold a 32-bit FNV hash to `bits` bits, for 0 < bits <= 32.
+///
+/// From the IETF draft: for 16 bits or more, the high bits beyond `bits` are shifted down and
+/// xored into the low bits,
+///
+/// ```c
+/// hash = (hash>>x) ^ (hash & MASK_x);
+/// ```
+///
+/// and for fewer than 16 bits, with the "tiny" folding,
+///
+/// ```c
+/// hash = (((hash>>x) ^ hash) & TINY_MASK(x));
+/// ```
+pub fn xor_fold32(hash: u32, bits: u32) -> u32 {
+    assert!(
+        (1..=32).contains(&bits),
+        "cannot fold a 32-bit hash to {} bits",
+        bits
+    );
+    if bits == 32 {
+        hash
+    } else if bits < 16 {
+        ((hash >> bits) ^ hash) & ((1 << bits) - 1)
+    } else {
+        (hash >> bits) ^ (hash & ((1 << bits) - 1))
+    }
+}
+
+/// Xor-fold a 64-bit FNV hash to `bits` bits, for 32 <= bits <= 64, as xor_fold32. For fewer
+/// bits, the draft recommends folding a 32-bit hash.
+pub fn xor_fold64(hash: u64, bits: u32) -> u64 {
+    assert!(
+        (32..=64).contains(&bits),
+        "cannot fold a 64-bit hash to {} bits",
+        bits
+    );
+    if bits == 64 {
+        hash
+    } else {
+        (hash >> bits) ^ (hash & ((1 << bits) - 1))
+    }
+}
+
+/// Map a 32-bit FNV hash into `[0, range)` with the draft's "lazy mod mapping": simply
+/// `hash % range`. The result is slightly biased toward lower values unless `range` divides
+/// 2^32, but the bias is small when `range` is much less than 2^32.
+pub fn lazy_mod32(hash: u32, range: u32) -> u32 {
+    assert!(range > 0, "cannot map a hash into an empty range");
+    hash % range
+}
+
+/// Map a 64-bit FNV hash into `[0, range)`, as lazy_mod32. Use this when `range` is 2^32 or
+/// more.
+pub fn lazy_mod64(hash: u64, range: u64) -> u64 {
+    assert!(range > 0, "cannot map a hash into an empty range");
+    hash % range
+}
+
+macro_rules! other_sizes {
+    ($name:ident, $size:ty, $fold:ident, $lazy_mod:ident) => {
+        impl $name {
+            /// The hash, xor-folded to `bits` bits; see the fold function for the valid sizes.
+            pub fn finish_bits(&self, bits: u32) -> $size {
+                $fold(self.0, bits)
+            }
+            /// The hash, mapped into `[0, range)` with lazy mod mapping.
+            pub fn finish_lazy_mod(&self, range: $size) -> $size {
+                $lazy_mod(self.0, range)
+            }
+        }
+    };
+}
+
+other_sizes!(FNV1aHasher32, u32, xor_fold32, lazy_mod32);
+other_sizes!(FNV1aHasher64, u64, xor_fold64, lazy_mod64);
+other_sizes!(FNV1Hasher32, u32, xor_fold32, lazy_mod32);
+other_sizes!(FNV1Hasher64, u64, xor_fold64, lazy_mod64);
+
+// ====================================
+// Wide FNV
+
+/// Multiply the little-endian multi-word `h` by an FNV prime, modulo
+/// 2^(64 * h.len()).
+///
+/// The wide FNV primes all have the form 2^shift + 2^8 + c, so the
+/// product is `(h << shift) + h * (2^8 + c)`.
+#[inline]
+fn wide_mul(h: &mut [u64], shift: u32, low: u64) {
+    let mut product = [0u64; 16];
+    let product = &mut product[..h.len()];
+    let mut carry = 0u128;
+    for (p, &word) in product.iter_mut().zip(h.iter()) {
+        let t = (word as u128) * (low as u128) + carry;
+        *p = t as u64;
+        carry = t >> 64;
+    }
+    let words = (shift / 64) as usize;
+    let bits = shift % 64;
+    let mut carry = false;
+    for (src, p) in product.iter_mut().skip(words).enumerate() {
+        let mut shifted = h[src] << bits;
+        if bits > 0 && src > 0 {
+            shifted |= h[src - 1] >> (64 - bits);
+        }
+        let (sum, c1) = p.overflowing_add(shifted);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        *p = sum;
+        carry = c1 || c2;
+    }
+    h.copy_from_slice(product);
+}
+
+macro_rules! fnv_wide {
+    ($name:ident, $words:expr, $shift:expr, $low:expr, $offset_basis:expr, $xor_first:expr) => {
+        pub struct $name([u64; $words]);
+        impl $name {
+            /// The full hash, least significant word first.
+            pub fn finish_wide(&self) -> [u64; $words] {
+                self.0
+            }
+        }
+        impl Hasher for $name {
+            /// The low 64 bits of the hash.
+            #[inline]
+            fn finish(&self) -> u64 {
+                self.0[0]
+            }
+            #[inline]
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes.iter() {
+                    if $xor_first {
+                        self.0[0] ^= *byte as u64;
+                        wide_mul(&mut self.0, $shift, $low);
+                    } else {
+                        wide_mul(&mut self.0, $shift, $low);
+                        self.0[0] ^= *byte as u64;
+                    }
+                }
+            }
+        }
+        default_for_constant!($name, $offset_basis);
+        impl SeedableHasher for $name {
+            #[inline]
+            fn with_seed(seed: u64) -> $name {
+                let mut basis = $offset_basis;
+                basis[0] ^= seed;
+                $name(basis)
+            }
+        }
+    };
+}
+
+// FNV prime 2^88 + 2^8 + 0x3b
+const OFFSET_128: [u64; 2] = [0x62b821756295c58d, 0x6c62272e07bb0142];
+
+fnv_wide!(FNV1aHasher128, 2, 88, 0x13b, OFFSET_128, true);
+fnv_wide!(FNV1Hasher128, 2, 88, 0x13b, OFFSET_128, false);
+random_state!(RandomFNV1aState128, FNV1aHasher128);
+random_state!(RandomFNV1State128, FNV1Hasher128);
+hash_collections!(FNV1aHashMap128, FNV1aHashSet128, FNV1aHasher128);
+hash_collections!(FNV1HashMap128, FNV1HashSet128, FNV1Hasher128);
+
+impl FNV1aHasher128 {
+    /// The full 128-bit hash, as (low, high).
+    pub fn finish128(&self) -> (u64, u64) {
+        (self.0[0], self.0[1])
+    }
+}
+
+impl FNV1Hasher128 {
+    /// The full 128-bit hash, as (low, high).
+    pub fn finish128(&self) -> (u64, u64) {
+        (self.0[0], self.0[1])
+    }
+}
+
+// FNV prime 2^168 + 2^8 + 0x63
+const OFFSET_256: [u64; 4] = [
+    0x1023b4c8caee0535,
+    0xc8b1536847b6bbb3,
+    0x2d98c384c4e576cc,
+    0xdd268dbcaac55036,
+];
+
+fnv_wide!(FNV1aHasher256, 4, 168, 0x163, OFFSET_256, true);
+fnv_wide!(FNV1Hasher256, 4, 168, 0x163, OFFSET_256, false);
+random_state!(RandomFNV1aState256, FNV1aHasher256);
+random_state!(RandomFNV1State256, FNV1Hasher256);
+hash_collections!(FNV1aHashMap256, FNV1aHashSet256, FNV1aHasher256);
+hash_collections!(FNV1HashMap256, FNV1HashSet256, FNV1Hasher256);
+
+// FNV prime 2^344 + 2^8 + 0x57
+const OFFSET_512: [u64; 8] = [
+    0xac982aac4afe9fd9,
+    0x182036415f56e34b,
+    0x2ea79bc942dbe7ce,
+    0xe948f68a34c192f6,
+    0x0000000000000d21,
+    0xac87d059c9000000,
+    0xdca1e50f309990ac,
+    0xb86db0b1171f4416,
+];
+
+fnv_wide!(FNV1aHasher512, 8, 344, 0x157, OFFSET_512, true);
+fnv_wide!(FNV1Hasher512, 8, 344, 0x157, OFFSET_512, false);
+random_state!(RandomFNV1aState512, FNV1aHasher512);
+random_state!(RandomFNV1State512, FNV1Hasher512);
+hash_collections!(FNV1aHashMap512, FNV1aHashSet512, FNV1aHasher512);
+hash_collections!(FNV1HashMap512, FNV1HashSet512, FNV1Hasher512);
+
+// FNV prime 2^680 + 2^8 + 0x8d
+const OFFSET_1024: [u64; 16] = [
+    0xaff4b16c71ee90b3,
+    0x6bde8cc9c6a93b21,
+    0x555f256cc005ae55,
+    0xeb6e73802734510a,
+    0x000000000004c6d7,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x9a21d90000000000,
+    0x6c3bf34eda3674da,
+    0x4b29fc4223fdada1,
+    0x32e56d5a591028b7,
+    0x005f7a76758ecc4d,
+    0x0000000000000000,
+];
+
+fnv_wide!(FNV1aHasher1024, 16, 680, 0x18d, OFFSET_1024, true);
+fnv_wide!(FNV1Hasher1024, 16, 680, 0x18d, OFFSET_1024, false);
+random_state!(RandomFNV1aState1024, FNV1aHasher1024);
+random_state!(RandomFNV1State1024, FNV1Hasher1024);
+hash_collections!(FNV1aHashMap1024, FNV1aHashSet1024, FNV1aHasher1024);
+hash_collections!(FNV1HashMap1024, FNV1HashSet1024, FNV1Hasher1024);
+
+#[cfg(test)]
+mod fnv1a_tests {
+    use super::*;
+
+    #[test]
+    fn basic() {
+        assert_eq!(fnv1a64(b""), 14695981039346656037);
+        assert_eq!(fnv1a64(b"a"), 12638187200555641996);
+        assert_eq!(fnv1a64(b"b"), 12638190499090526629);
+        assert_eq!(fnv1a64(b"ab"), 620445648566982762);
+        assert_eq!(fnv1a64(b"abcd"), 18165163011005162717);
+        assert_eq!(fnv1a64(b"abcdefg"), 4642726675185563447);
+    }
+}
+
+#[cfg(test)]
+mod fnv_tests {
+    use super::*;
+    use check_seedable;
+
+    // The published FNV test vectors, for "", "a" and "foobar"; the
+    // FNV-1a ones are also the IETF draft's.
+    #[test]
+    fn narrow() {
+        assert_eq!(fnv1_32(b""), 0x811c9dc5);
+        assert_eq!(fnv1_32(b"a"), 0x050c5d7e);
+        assert_eq!(fnv1_32(b"foobar"), 0x31f0b262);
+        assert_eq!(fnv1a32(b""), 0x811c9dc5);
+        assert_eq!(fnv1a32(b"a"), 0xe40c292c);
+        assert_eq!(fnv1a32(b"foobar"), 0xbf9cf968);
+        assert_eq!(fnv1_64(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1_64(b"a"), 0xaf63bd4c8601b7be);
+        assert_eq!(fnv1_64(b"foobar"), 0x340d8765a4dda9c2);
+        assert_eq!(fnv1a64(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a64(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a64(b"foobar"), 0x85944171f73967e8);
+        assert_eq!(fnv0_32(b""), 0);
+        assert_eq!(fnv0_32(b"a"), 0x61);
+        assert_eq!(fnv0_32(b"foobar"), 0xb74bb5ef);
+        assert_eq!(fnv0_64(b""), 0);
+        assert_eq!(fnv0_64(b"foobar"), 0x0b91ae3f7ccdc5ef);
+    }
+
+    // The offset bases are the FNV-0 hashes of this string.
+    const SIGNATURE: &[u8] = b"chongo <Landon Curt Noll> /\\../\\";
+
+    #[test]
+    fn offset_basis() {
+        assert_eq!(fnv0_32(SIGNATURE), FNV1Hasher32::default().finish());
+        assert_eq!(fnv0_64(SIGNATURE), FNV1Hasher64::default().finish());
+        macro_rules! check {
+            ($name:ident, $words:expr, $offset_basis:expr) => {
+                let mut fnv0 = $name([0; $words]);
+                fnv0.write(SIGNATURE);
+                assert_eq!(fnv0.finish_wide(), $offset_basis);
+            };
+        }
+        check!(FNV1Hasher128, 2, OFFSET_128);
+        check!(FNV1Hasher256, 4, OFFSET_256);
+        check!(FNV1Hasher512, 8, OFFSET_512);
+        check!(FNV1Hasher1024, 16, OFFSET_1024);
+    }
+
+    /// The hash in hex, most significant digit first.
+    fn hex(words: &[u64]) -> String {
+        words.iter().rev().map(|w| format!("{:016x}", w)).collect()
+    }
+
+    fn wide<H: Hasher>(mut hasher: H, bytes: &[u8]) -> H {
+        hasher.write(bytes);
+        hasher
+    }
+
+    #[test]
+    fn wide_hashes() {
+        // (message, FNV-1, FNV-1a). The FNV-1a values are the test vectors
+        // from the IETF draft (draft-eastlake-fnv, "The FNV
+        // Non-Cryptographic Hash Algorithm"); the FNV-1 values are computed
+        // from the definitions with arbitrary-precision integers.
+        let expected128 = [
+            (
+                "",
+                "6c62272e07bb014262b821756295c58d",
+                "6c62272e07bb014262b821756295c58d",
+            ),
+            (
+                "a",
+                "d228cb69101a8caf78912b704e4a141e",
+                "d228cb696f1a8caf78912b704e4a8964",
+            ),
+            (
+                "foobar",
+                "7896bfea9c3c64bf6dc58353d2c293aa",
+                "343e1662793c64bf6f0d3597ba446f18",
+            ),
+        ];
+        for &(msg, h1, h1a) in expected128.iter() {
+            let msg = msg.as_bytes();
+            assert_eq!(hex(&wide(FNV1Hasher128::default(), msg).finish_wide()), h1);
+            assert_eq!(
+                hex(&wide(FNV1aHasher128::default(), msg).finish_wide()),
+                h1a
+            );
+        }
+        let expected256 = [
+            (
+                "",
+                "dd268dbcaac550362d98c384c4e576ccc8b1536847b6bbb31023b4c8caee0535",
+                "dd268dbcaac550362d98c384c4e576ccc8b1536847b6bbb31023b4c8caee0535",
+            ),
+            (
+                "a",
+                "63323fb0f35303ec28dc561d0a33bdfa4de6a99b7266494f6183b2716811381e",
+                "63323fb0f35303ec28dc751d0a33bdfa4de6a99b7266494f6183b2716811637c",
+            ),
+            (
+                "foobar",
+                "b055ea2f2cc3908dddb794c02d3889dc32453dad5ae35b753ac86c6c2ac80d72",
+                "b055ea2f306cadad4f0f81c02d3889dc32453dad5ae35b753ba1a91084af3428",
+            ),
+        ];
+        for &(msg, h1, h1a) in expected256.iter() {
+            let msg = msg.as_bytes();
+            assert_eq!(hex(&wide(FNV1Hasher256::default(), msg).finish_wide()), h1);
+            assert_eq!(
+                hex(&wide(FNV1aHasher256::default(), msg).finish_wide()),
+                h1a
+            );
+        }
+        let expected512 = [
+            (
+                "",
+                "b86db0b1171f4416dca1e50f309990acac87d059c90000000000000000000d21e948f68a34c192f62ea79bc942dbe7ce182036415f56e34bac982aac4afe9fd9",
+                "b86db0b1171f4416dca1e50f309990acac87d059c90000000000000000000d21e948f68a34c192f62ea79bc942dbe7ce182036415f56e34bac982aac4afe9fd9",
+            ),
+            (
+                "a",
+                "e43a992dc8fc5ad7de493e3d696d6f85d64326ec28000000000000000011986f90c2532caf5be7d88291baa894a395225328b196bd6a8a643fe12cd87b282bde",
+                "e43a992dc8fc5ad7de493e3d696d6f85d64326ec07000000000000000011986f90c2532caf5be7d88291baa894a395225328b196bd6a8a643fe12cd87b27ff88",
+            ),
+            (
+                "foobar",
+                "b0ec738d9c6fd969d05f0b35f6c0effd20209465290000004bf99f58ee4196afb9700e20110830fea5396b76280e47fd022b6e81331ca1a9cf6faf7123c3fc56",
+                "b0ec738d9c6fd969d05f0b35f6c0ed53adcacccd8e0000004bf99f58ee4196afb9700e20110830fea5396b76280e47fd022b6e81331ca1a9ced729c364be7788",
+            ),
+        ];
+        for &(msg, h1, h1a) in expected512.iter() {
+            let msg = msg.as_bytes();
+            assert_eq!(hex(&wide(FNV1Hasher512::default(), msg).finish_wide()), h1);
+            assert_eq!(
+                hex(&wide(FNV1aHasher512::default(), msg).finish_wide()),
+                h1a
+            );
+        }
+        let expected1024 = [
+            (
+                "",
+                "0000000000000000005f7a76758ecc4d32e56d5a591028b74b29fc4223fdada16c3bf34eda3674da9a21d9000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004c6d7eb6e73802734510a555f256cc005ae556bde8cc9c6a93b21aff4b16c71ee90b3",
+                "0000000000000000005f7a76758ecc4d32e56d5a591028b74b29fc4223fdada16c3bf34eda3674da9a21d9000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004c6d7eb6e73802734510a555f256cc005ae556bde8cc9c6a93b21aff4b16c71ee90b3",
+            ),
+            (
+                "a",
+                "000000000000000098d7c19fbce653df221b9f717d3490ff95ca87fdaef30d1b823372f85b24a372f50e380000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007685cd81a491dbccc21ad06648d09a5c8cf5a78482054e91470b33dde77252caef665f6",
+                "000000000000000098d7c19fbce653df221b9f717d3490ff95ca87fdaef30d1b823372f85b24a372f50e570000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007685cd81a491dbccc21ad06648d09a5c8cf5a78482054e91470b33dde77252caef695aa",
+            ),
+            (
+                "foobar",
+                "00000631175fa7ae643ad08723d312c9fd024adb91f77f6b19587197a22bcdf23727166c3e596993cf5a8d00000000000000000000000000000000000000000000000000000000000000000000000000000000000000004270d11ef418ef08b8a49e1e825e547eb39937f819222f3b7fc92a0e470790088882a53ca30e08f65c",
+                "00000631175fa7ae643ad08723d312c9fd024adb91f77f6b19587197a22bcdf23727166c4572d0b985d5ae00000000000000000000000000000000000000000000000000000000000000000000000000000000000000004270d11ef418ef08b8a49e1e825e547eb39937f819222f3b7fc92a0e4707900888847a554bacec98b0",
+            ),
+        ];
+        for &(msg, h1, h1a) in expected1024.iter() {
+            let msg = msg.as_bytes();
+            assert_eq!(hex(&wide(FNV1Hasher1024::default(), msg).finish_wide()), h1);
+            assert_eq!(
+                hex(&wide(FNV1aHasher1024::default(), msg).finish_wide()),
+                h1a
+            );
+        }
+        let fnv1a = wide(FNV1aHasher128::default(), b"a");
+        assert_eq!(fnv1a.finish128(), (0x78912b704e4a8964, 0xd228cb696f1a8caf));
+        assert_eq!(fnv1a.finish(), 0x78912b704e4a8964);
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<FNV1aHasher32>(true);
+        check_seedable::<FNV1aHasher64>(true);
+        check_seedable::<FNV1Hasher32>(true);
+        check_seedable::<FNV1Hasher64>(true);
+        check_seedable::<FNV0Hasher32>(true);
+        check_seedable::<FNV0Hasher64>(true);
+        check_seedable::<FNV1aHasher128>(true);
+        check_seedable::<FNV1Hasher128>(true);
+        check_seedable::<FNV1aHasher256>(true);
+        check_seedable::<FNV1Hasher256>(true);
+        check_seedable::<FNV1aHasher512>(true);
+        check_seedable::<FNV1Hasher512>(true);
+        check_seedable::<FNV1aHasher1024>(true);
+        check_seedable::<FNV1Hasher1024>(true);
+        // A seeded FNV-0 is FNV-1 with the seed as the offset basis.
+        let mut hasher = FNV0Hasher64::with_seed(0xcbf29ce484222325);
+        hasher.write(b"foobar");
+        assert_eq!(hasher.finish(), fnv1_64(b"foobar"));
+    }
+}
+
+#[cfg(test)]
+mod other_sizes_tests {
+    use super::*;
+
+    #[test]
+    fn fold32() {
+        // fnv1a32("foobar") is 0xbf9cf968
+        let mut hasher = FNV1aHasher32::default();
+        hasher.write(b"foobar");
+        assert_eq!(hasher.finish_bits(32), 0xbf9cf968);
+        assert_eq!(hasher.finish_bits(31), 0x3f9cf969);
+        assert_eq!(hasher.finish_bits(24), 0x9cf9d7);
+        assert_eq!(hasher.finish_bits(16), 0x46f4);
+        assert_eq!(hasher.finish_bits(15), 0x0651);
+        assert_eq!(hasher.finish_bits(8), 0x91);
+        assert_eq!(hasher.finish_bits(1), 0);
+    }
+
+    #[test]
+    fn fold64() {
+        // fnv1a64("foobar") is 0x85944171f73967e8
+        let mut hasher = FNV1aHasher64::default();
+        hasher.write(b"foobar");
+        assert_eq!(hasher.finish_bits(64), 0x85944171f73967e8);
+        assert_eq!(hasher.finish_bits(63), 0x05944171f73967e9);
+        assert_eq!(hasher.finish_bits(56), 0x944171f739676d);
+        assert_eq!(hasher.finish_bits(40), 0x71f7bcf3a9);
+        assert_eq!(hasher.finish_bits(32), 0x72ad2699);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fold64_too_narrow() {
+        xor_fold64(0x85944171f73967e8, 31);
+    }
+
+    #[test]
+    fn lazy_mod() {
+        let mut h32 = FNV1aHasher32::default();
+        h32.write(b"foobar");
+        assert_eq!(h32.finish_lazy_mod(50000), 35720);
+        assert_eq!(h32.finish_lazy_mod(2142779559), 1071956161);
+        let mut h64 = FNV1aHasher64::default();
+        h64.write(b"foobar");
+        assert_eq!(h64.finish_lazy_mod(2142779559), 1047975393);
+    }
+}
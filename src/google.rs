@@ -0,0 +1,1219 @@
+//! From https://github.com/google/cityhash and https://github.com/google/farmhash.
+//!
+//! Geoff Pike and Jyrki Alakuijala's CityHash, and its successor
+//! FarmHash. From the FarmHash README:
+//!
+//! > FarmHash provides hash functions for strings and other data. The
+//! > functions mix the input bits thoroughly but are not suitable for
+//! > cryptography.
+//! >
+//! > ...
+//! >
+//! > All members of the FarmHash family were designed with heavy reliance on
+//! > previous work by Jyrki Alakuijala, Austin Appleby, Bob Jenkins, and others.
+//!
+//! The CityHash functions here are CityHash v1.1, the final version;
+//! earlier versions produce different results.
+//!
+//! FarmHash's `Hash64` "may change from time to time, may differ on
+//! different platforms"; the reference picks a SIMD variant when it is
+//! compiled for SSE4.2. `farmhash64` here is the portable variant,
+//! farmhashxo, which is what the reference computes everywhere else.
+//! The fingerprints are fixed forever:
+//!
+//! > FINGERPRINTING (i.e., good, portable, forever-fixed hash functions)
+//!
+//! `Fingerprint64` is farmhashna's `Hash64`, and `Fingerprint128` is
+//! CityHash128 v1.1.
+//!
+//! The seeded Hashers (see SeedableHasher) compute the seeded versions
+//! of each function: CityHash64WithSeed and CityHash128WithSeed,
+//! FarmHash's Hash64WithSeed and, for the fingerprints, the seeded
+//! versions of the functions they are built on, farmhashna's
+//! Hash64WithSeed and CityHash128WithSeed. A seeded fingerprint is no
+//! longer the fingerprint.
+//!
+//! FarmHash's `Hash64WithSeed` and `Hash64WithSeeds` (farmhash.cc's
+//! `util::Hash64WithSeed`, which the C++ and Go libraries export) are
+//! farmhashna's on every platform, not seeded versions of farmhashxo's
+//! `Hash64`. `farmhash64_with_seed` and `farmhash64_with_seeds` match
+//! them, so for inputs longer than 64 bytes they are not `farmhash64`
+//! with a seed.
+//!
+//! None of these functions can be computed incrementally: the length of
+//! the input picks the algorithm and long inputs are hashed starting
+//! from both ends. The Hashers buffer their input and do all of the
+//! work in finish.
+
+use std::hash::Hasher;
+use std::{mem, ptr};
+
+use {SeedableHasher, SeedableHasher128};
+
+// ====================================
+// Shared pieces
+
+/// > Some primes between 2^63 and 2^64 for various uses.
+const K0: u64 = 0xc3a5c85c97cb3127;
+const K1: u64 = 0xb492b66fbe98f273;
+const K2: u64 = 0x9ae16a3b2f90404f;
+
+/// The multiplier for `Hash128to64`.
+const K_MUL: u64 = 0x9ddfea08eb382d69;
+
+#[inline]
+fn fetch64(s: &[u8], i: usize) -> u64 {
+    load_int_le!(s, i, u64)
+}
+
+#[inline]
+fn fetch32(s: &[u8], i: usize) -> u64 {
+    load_int_le!(s, i, u32) as u64
+}
+
+#[inline]
+fn shift_mix(val: u64) -> u64 {
+    val ^ (val >> 47)
+}
+
+/// > Murmur-inspired hashing.
+#[inline]
+fn hash_len_16_mul(u: u64, v: u64, mul: u64) -> u64 {
+    let mut a = (u ^ v).wrapping_mul(mul);
+    a ^= a >> 47;
+    let mut b = (v ^ a).wrapping_mul(mul);
+    b ^= b >> 47;
+    b.wrapping_mul(mul)
+}
+
+/// `Hash128to64`, hashing u as the low half and v as the high half.
+#[inline]
+fn hash_len_16(u: u64, v: u64) -> u64 {
+    hash_len_16_mul(u, v, K_MUL)
+}
+
+fn hash_len_0_to_16(s: &[u8]) -> u64 {
+    let len = s.len() as u64;
+    if s.len() >= 8 {
+        let mul = K2.wrapping_add(len * 2);
+        let a = fetch64(s, 0).wrapping_add(K2);
+        let b = fetch64(s, s.len() - 8);
+        let c = b.rotate_right(37).wrapping_mul(mul).wrapping_add(a);
+        let d = a.rotate_right(25).wrapping_add(b).wrapping_mul(mul);
+        return hash_len_16_mul(c, d, mul);
+    }
+    if s.len() >= 4 {
+        let mul = K2.wrapping_add(len * 2);
+        let a = fetch32(s, 0);
+        return hash_len_16_mul(len + (a << 3), fetch32(s, s.len() - 4), mul);
+    }
+    if !s.is_empty() {
+        let a = s[0] as u32;
+        let b = s[s.len() >> 1] as u32;
+        let c = s[s.len() - 1] as u32;
+        let y = a + (b << 8);
+        let z = len as u32 + (c << 2);
+        return shift_mix((y as u64).wrapping_mul(K2) ^ (z as u64).wrapping_mul(K0))
+            .wrapping_mul(K2);
+    }
+    K2
+}
+
+/// > This probably works well for 16-byte strings as well, but it may be overkill
+/// > in that case.
+fn hash_len_17_to_32(s: &[u8]) -> u64 {
+    let len = s.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(s, 0).wrapping_mul(K1);
+    let b = fetch64(s, 8);
+    let c = fetch64(s, len - 8).wrapping_mul(mul);
+    let d = fetch64(s, len - 16).wrapping_mul(K2);
+    hash_len_16_mul(
+        a.wrapping_add(b)
+            .rotate_right(43)
+            .wrapping_add(c.rotate_right(30))
+            .wrapping_add(d),
+        a.wrapping_add(b.wrapping_add(K2).rotate_right(18))
+            .wrapping_add(c),
+        mul,
+    )
+}
+
+/// > Return a 16-byte hash for 48 bytes.  Quick and dirty.
+/// > Callers do best to use "random-looking" values for a and b.
+#[inline]
+fn weak_hash_len_32_with_seeds(w: u64, x: u64, y: u64, z: u64, a: u64, b: u64) -> (u64, u64) {
+    let mut a = a.wrapping_add(w);
+    let mut b = b.wrapping_add(a).wrapping_add(z).rotate_right(21);
+    let c = a;
+    a = a.wrapping_add(x).wrapping_add(y);
+    b = b.wrapping_add(a.rotate_right(44));
+    (a.wrapping_add(z), b.wrapping_add(c))
+}
+
+/// > Return a 16-byte hash for s[0] ... s[31], a, and b.  Quick and dirty.
+#[inline]
+fn weak_hash_len_32_with_seeds_bytes(s: &[u8], i: usize, a: u64, b: u64) -> (u64, u64) {
+    weak_hash_len_32_with_seeds(
+        fetch64(s, i),
+        fetch64(s, i + 8),
+        fetch64(s, i + 16),
+        fetch64(s, i + 24),
+        a,
+        b,
+    )
+}
+
+// ====================================
+// CityHash64
+
+/// > Return an 8-byte hash for 33 to 64 bytes.
+fn city_hash_len_33_to_64(s: &[u8]) -> u64 {
+    let len = s.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(s, 0).wrapping_mul(K2);
+    let b = fetch64(s, 8);
+    let c = fetch64(s, len - 24);
+    let d = fetch64(s, len - 32);
+    let e = fetch64(s, 16).wrapping_mul(K2);
+    let f = fetch64(s, 24).wrapping_mul(9);
+    let g = fetch64(s, len - 8);
+    let h = fetch64(s, len - 16).wrapping_mul(mul);
+    let u = a
+        .wrapping_add(g)
+        .rotate_right(43)
+        .wrapping_add(b.rotate_right(30).wrapping_add(c).wrapping_mul(9));
+    let v = (a.wrapping_add(g) ^ d).wrapping_add(f).wrapping_add(1);
+    let w = u
+        .wrapping_add(v)
+        .wrapping_mul(mul)
+        .swap_bytes()
+        .wrapping_add(h);
+    let x = e.wrapping_add(f).rotate_right(42).wrapping_add(c);
+    let y = v
+        .wrapping_add(w)
+        .wrapping_mul(mul)
+        .swap_bytes()
+        .wrapping_add(g)
+        .wrapping_mul(mul);
+    let z = e.wrapping_add(f).wrapping_add(c);
+    let a = x
+        .wrapping_add(z)
+        .wrapping_mul(mul)
+        .wrapping_add(y)
+        .swap_bytes()
+        .wrapping_add(b);
+    let b = shift_mix(
+        z.wrapping_add(a)
+            .wrapping_mul(mul)
+            .wrapping_add(d)
+            .wrapping_add(h),
+    )
+    .wrapping_mul(mul);
+    b.wrapping_add(x)
+}
+
+/// CityHash64 v1.1.
+pub fn cityhash64(bytes: &[u8]) -> u64 {
+    let s = bytes;
+    let len = s.len();
+    if len <= 32 {
+        if len <= 16 {
+            return hash_len_0_to_16(s);
+        } else {
+            return hash_len_17_to_32(s);
+        }
+    } else if len <= 64 {
+        return city_hash_len_33_to_64(s);
+    }
+
+    // > For strings over 64 bytes we hash the end first, and then as we
+    // > loop we keep 56 bytes of state: v, w, x, y, and z.
+    let mut x = fetch64(s, len - 40);
+    let mut y = fetch64(s, len - 16).wrapping_add(fetch64(s, len - 56));
+    let mut z = hash_len_16(
+        fetch64(s, len - 48).wrapping_add(len as u64),
+        fetch64(s, len - 24),
+    );
+    let mut v = weak_hash_len_32_with_seeds_bytes(s, len - 64, len as u64, z);
+    let mut w = weak_hash_len_32_with_seeds_bytes(s, len - 32, y.wrapping_add(K1), x);
+    x = x.wrapping_mul(K1).wrapping_add(fetch64(s, 0));
+
+    // > Decrease len to the nearest multiple of 64, and operate on 64-byte chunks.
+    let end = (len - 1) & !63;
+    let mut i = 0;
+    while i < end {
+        x = x
+            .wrapping_add(y)
+            .wrapping_add(v.0)
+            .wrapping_add(fetch64(s, i + 8))
+            .rotate_right(37)
+            .wrapping_mul(K1);
+        y = y
+            .wrapping_add(v.1)
+            .wrapping_add(fetch64(s, i + 48))
+            .rotate_right(42)
+            .wrapping_mul(K1);
+        x ^= w.1;
+        y = y.wrapping_add(v.0).wrapping_add(fetch64(s, i + 40));
+        z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(K1);
+        v = weak_hash_len_32_with_seeds_bytes(s, i, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+        w = weak_hash_len_32_with_seeds_bytes(
+            s,
+            i + 32,
+            z.wrapping_add(w.1),
+            y.wrapping_add(fetch64(s, i + 16)),
+        );
+        mem::swap(&mut z, &mut x);
+        i += 64;
+    }
+    hash_len_16(
+        hash_len_16(v.0, w.0)
+            .wrapping_add(shift_mix(y).wrapping_mul(K1))
+            .wrapping_add(z),
+        hash_len_16(v.1, w.1).wrapping_add(x),
+    )
+}
+
+/// CityHash64WithSeed v1.1.
+pub fn cityhash64_with_seed(bytes: &[u8], seed: u64) -> u64 {
+    cityhash64_with_seeds(bytes, K2, seed)
+}
+
+/// CityHash64WithSeeds v1.1.
+pub fn cityhash64_with_seeds(bytes: &[u8], seed0: u64, seed1: u64) -> u64 {
+    hash_len_16(cityhash64(bytes).wrapping_sub(seed0), seed1)
+}
+
+/// CityHash64, as a Hasher. `new` gives CityHash64WithSeed and
+/// `with_seeds` gives CityHash64WithSeeds; the Default is unseeded.
+#[derive(Default)]
+pub struct CityHasher64 {
+    seeds: Option<(u64, u64)>,
+    buf: Vec<u8>,
+}
+
+impl CityHasher64 {
+    pub fn new(seed: u64) -> CityHasher64 {
+        CityHasher64::with_seeds(K2, seed)
+    }
+
+    pub fn with_seeds(seed0: u64, seed1: u64) -> CityHasher64 {
+        CityHasher64 {
+            seeds: Some((seed0, seed1)),
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl SeedableHasher for CityHasher64 {
+    fn with_seed(seed: u64) -> CityHasher64 {
+        CityHasher64::new(seed)
+    }
+}
+
+impl SeedableHasher128 for CityHasher64 {
+    fn with_seed128(seed: (u64, u64)) -> CityHasher64 {
+        CityHasher64::with_seeds(seed.0, seed.1)
+    }
+}
+
+random_state!(RandomCityState64, CityHasher64, 128);
+hash_collections!(CityHashMap64, CityHashSet64, CityHasher64);
+
+impl Hasher for CityHasher64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        match self.seeds {
+            Some((seed0, seed1)) => cityhash64_with_seeds(&self.buf, seed0, seed1),
+            None => cityhash64(&self.buf),
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+// ====================================
+// CityHash128
+
+/// > A subroutine for CityHash128().  Returns a decent 128-bit hash for strings
+/// > of any length representable in signed long.  Based on City and Murmur.
+fn city_murmur(s: &[u8], seed: (u64, u64)) -> (u64, u64) {
+    let len = s.len();
+    let (mut a, mut b) = seed;
+    let mut c;
+    let mut d;
+    if len <= 16 {
+        a = shift_mix(a.wrapping_mul(K1)).wrapping_mul(K1);
+        c = b.wrapping_mul(K1).wrapping_add(hash_len_0_to_16(s));
+        d = shift_mix(a.wrapping_add(if len >= 8 { fetch64(s, 0) } else { c }));
+    } else {
+        c = hash_len_16(fetch64(s, len - 8).wrapping_add(K1), a);
+        d = hash_len_16(
+            b.wrapping_add(len as u64),
+            c.wrapping_add(fetch64(s, len - 16)),
+        );
+        a = a.wrapping_add(d);
+        let mut i = 0;
+        while i + 16 < len {
+            a ^= shift_mix(fetch64(s, i).wrapping_mul(K1)).wrapping_mul(K1);
+            a = a.wrapping_mul(K1);
+            b ^= a;
+            c ^= shift_mix(fetch64(s, i + 8).wrapping_mul(K1)).wrapping_mul(K1);
+            c = c.wrapping_mul(K1);
+            d ^= c;
+            i += 16;
+        }
+    }
+    a = hash_len_16(a, c);
+    b = hash_len_16(d, b);
+    (a ^ b, hash_len_16(b, a))
+}
+
+/// CityHash128WithSeed v1.1, with the seed given as its low and high
+/// halves. Returns the (low, high) halves of the result.
+pub fn cityhash128_with_seed(bytes: &[u8], seed0: u64, seed1: u64) -> (u64, u64) {
+    let s = bytes;
+    let mut len = s.len();
+    if len < 128 {
+        return city_murmur(s, (seed0, seed1));
+    }
+
+    // > We expect len >= 128 to be the common case.  Keep 56 bytes of state:
+    // > v, w, x, y, and z.
+    let mut x = seed0;
+    let mut y = seed1;
+    let mut z = (len as u64).wrapping_mul(K1);
+    let mut v = (0, 0);
+    let mut w = (0, 0);
+    v.0 = (y ^ K1)
+        .rotate_right(49)
+        .wrapping_mul(K1)
+        .wrapping_add(fetch64(s, 0));
+    v.1 =
+        v.0.rotate_right(42)
+            .wrapping_mul(K1)
+            .wrapping_add(fetch64(s, 8));
+    w.0 = y
+        .wrapping_add(z)
+        .rotate_right(35)
+        .wrapping_mul(K1)
+        .wrapping_add(x);
+    w.1 = x
+        .wrapping_add(fetch64(s, 88))
+        .rotate_right(53)
+        .wrapping_mul(K1);
+
+    // > This is the same inner loop as CityHash64(), manually unrolled.
+    let mut i = 0;
+    while len >= 128 {
+        for _ in 0..2 {
+            x = x
+                .wrapping_add(y)
+                .wrapping_add(v.0)
+                .wrapping_add(fetch64(s, i + 8))
+                .rotate_right(37)
+                .wrapping_mul(K1);
+            y = y
+                .wrapping_add(v.1)
+                .wrapping_add(fetch64(s, i + 48))
+                .rotate_right(42)
+                .wrapping_mul(K1);
+            x ^= w.1;
+            y = y.wrapping_add(v.0).wrapping_add(fetch64(s, i + 40));
+            z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(K1);
+            v = weak_hash_len_32_with_seeds_bytes(s, i, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+            w = weak_hash_len_32_with_seeds_bytes(
+                s,
+                i + 32,
+                z.wrapping_add(w.1),
+                y.wrapping_add(fetch64(s, i + 16)),
+            );
+            mem::swap(&mut z, &mut x);
+            i += 64;
+        }
+        len -= 128;
+    }
+    x = x.wrapping_add(v.0.wrapping_add(z).rotate_right(49).wrapping_mul(K0));
+    y = y.wrapping_mul(K0).wrapping_add(w.1.rotate_right(37));
+    z = z.wrapping_mul(K0).wrapping_add(w.0.rotate_right(27));
+    w.0 = w.0.wrapping_mul(9);
+    v.0 = v.0.wrapping_mul(K0);
+    // > If 0 < len < 128, hash up to 4 chunks of 32 bytes each from the end of s.
+    let mut tail_done = 0;
+    while tail_done < len {
+        tail_done += 32;
+        let tail = i + len - tail_done;
+        y = x
+            .wrapping_add(y)
+            .rotate_right(42)
+            .wrapping_mul(K0)
+            .wrapping_add(v.1);
+        w.0 = w.0.wrapping_add(fetch64(s, tail + 16));
+        x = x.wrapping_mul(K0).wrapping_add(w.0);
+        z = z.wrapping_add(w.1).wrapping_add(fetch64(s, tail));
+        w.1 = w.1.wrapping_add(v.0);
+        v = weak_hash_len_32_with_seeds_bytes(s, tail, v.0.wrapping_add(z), v.1);
+        v.0 = v.0.wrapping_mul(K0);
+    }
+    // > At this point our 56 bytes of state should contain more than
+    // > enough information for a strong 128-bit hash.  We use two
+    // > different 56-byte-to-8-byte hashes to get a 16-byte final result.
+    x = hash_len_16(x, v.0);
+    y = hash_len_16(y.wrapping_add(z), w.0);
+    (
+        hash_len_16(x.wrapping_add(v.1), w.1).wrapping_add(y),
+        hash_len_16(x.wrapping_add(w.1), y.wrapping_add(v.1)),
+    )
+}
+
+/// CityHash128 v1.1. Returns the (low, high) halves of the result.
+pub fn cityhash128(bytes: &[u8]) -> (u64, u64) {
+    if bytes.len() >= 16 {
+        cityhash128_with_seed(
+            &bytes[16..],
+            fetch64(bytes, 0),
+            fetch64(bytes, 8).wrapping_add(K0),
+        )
+    } else {
+        cityhash128_with_seed(bytes, K0, K1)
+    }
+}
+
+/// CityHash128, as a Hasher. `finish` returns the low half of the
+/// result. `new` gives CityHash128WithSeed; the Default is unseeded.
+#[derive(Default)]
+pub struct CityHasher128 {
+    seed: Option<(u64, u64)>,
+    buf: Vec<u8>,
+}
+
+impl CityHasher128 {
+    pub fn new(seed0: u64, seed1: u64) -> CityHasher128 {
+        CityHasher128 {
+            seed: Some((seed0, seed1)),
+            buf: Vec::new(),
+        }
+    }
+
+    pub fn finish128(&self) -> (u64, u64) {
+        match self.seed {
+            Some((seed0, seed1)) => cityhash128_with_seed(&self.buf, seed0, seed1),
+            None => cityhash128(&self.buf),
+        }
+    }
+}
+
+impl SeedableHasher for CityHasher128 {
+    fn with_seed(seed: u64) -> CityHasher128 {
+        CityHasher128::new(seed, 0)
+    }
+}
+
+impl SeedableHasher128 for CityHasher128 {
+    fn with_seed128(seed: (u64, u64)) -> CityHasher128 {
+        CityHasher128::new(seed.0, seed.1)
+    }
+}
+
+random_state!(RandomCityState128, CityHasher128, 128);
+hash_collections!(CityHashMap128, CityHashSet128, CityHasher128);
+
+impl Hasher for CityHasher128 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish128().0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+// ====================================
+// FarmHash
+//
+// farmhashna, farmhashuo and farmhashxo, the portable 64-bit
+// variants.
+
+// ------------------------------------
+// farmhashna
+
+/// > Return an 8-byte hash for 33 to 64 bytes.
+fn na_hash_len_33_to_64(s: &[u8]) -> u64 {
+    let len = s.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(s, 0).wrapping_mul(K2);
+    let b = fetch64(s, 8);
+    let c = fetch64(s, len - 8).wrapping_mul(mul);
+    let d = fetch64(s, len - 16).wrapping_mul(K2);
+    let y = a
+        .wrapping_add(b)
+        .rotate_right(43)
+        .wrapping_add(c.rotate_right(30))
+        .wrapping_add(d);
+    let z = hash_len_16_mul(
+        y,
+        a.wrapping_add(b.wrapping_add(K2).rotate_right(18))
+            .wrapping_add(c),
+        mul,
+    );
+    let e = fetch64(s, 16).wrapping_mul(mul);
+    let f = fetch64(s, 24);
+    let g = y.wrapping_add(fetch64(s, len - 32)).wrapping_mul(mul);
+    let h = z.wrapping_add(fetch64(s, len - 24)).wrapping_mul(mul);
+    hash_len_16_mul(
+        e.wrapping_add(f)
+            .rotate_right(43)
+            .wrapping_add(g.rotate_right(30))
+            .wrapping_add(h),
+        e.wrapping_add(f.wrapping_add(a).rotate_right(18))
+            .wrapping_add(g),
+        mul,
+    )
+}
+
+fn na_hash64(s: &[u8]) -> u64 {
+    const SEED: u64 = 81;
+    let len = s.len();
+    if len <= 32 {
+        if len <= 16 {
+            return hash_len_0_to_16(s);
+        } else {
+            return hash_len_17_to_32(s);
+        }
+    } else if len <= 64 {
+        return na_hash_len_33_to_64(s);
+    }
+
+    // > For strings over 64 bytes we loop.  Internal state consists of
+    // > 56 bytes: v, w, x, y, and z.
+    let mut x = SEED;
+    let mut y = SEED.wrapping_mul(K1).wrapping_add(113);
+    let mut z = shift_mix(y.wrapping_mul(K2).wrapping_add(113)).wrapping_mul(K2);
+    let mut v = (0, 0);
+    let mut w = (0, 0);
+    x = x.wrapping_mul(K2).wrapping_add(fetch64(s, 0));
+
+    // > Set end so that after the loop we have 1 to 64 bytes left to process.
+    let end = ((len - 1) / 64) * 64;
+    let last64 = len - 64;
+    let mut i = 0;
+    while i < end {
+        x = x
+            .wrapping_add(y)
+            .wrapping_add(v.0)
+            .wrapping_add(fetch64(s, i + 8))
+            .rotate_right(37)
+            .wrapping_mul(K1);
+        y = y
+            .wrapping_add(v.1)
+            .wrapping_add(fetch64(s, i + 48))
+            .rotate_right(42)
+            .wrapping_mul(K1);
+        x ^= w.1;
+        y = y.wrapping_add(v.0).wrapping_add(fetch64(s, i + 40));
+        z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(K1);
+        v = weak_hash_len_32_with_seeds_bytes(s, i, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+        w = weak_hash_len_32_with_seeds_bytes(
+            s,
+            i + 32,
+            z.wrapping_add(w.1),
+            y.wrapping_add(fetch64(s, i + 16)),
+        );
+        mem::swap(&mut z, &mut x);
+        i += 64;
+    }
+    let mul = K1.wrapping_add((z & 0xff) << 1);
+    // > Make s point to the last 64 bytes of input.
+    let i = last64;
+    w.0 = w.0.wrapping_add(((len - 1) & 63) as u64);
+    v.0 = v.0.wrapping_add(w.0);
+    w.0 = w.0.wrapping_add(v.0);
+    x = x
+        .wrapping_add(y)
+        .wrapping_add(v.0)
+        .wrapping_add(fetch64(s, i + 8))
+        .rotate_right(37)
+        .wrapping_mul(mul);
+    y = y
+        .wrapping_add(v.1)
+        .wrapping_add(fetch64(s, i + 48))
+        .rotate_right(42)
+        .wrapping_mul(mul);
+    x ^= w.1.wrapping_mul(9);
+    y = y
+        .wrapping_add(v.0.wrapping_mul(9))
+        .wrapping_add(fetch64(s, i + 40));
+    z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(mul);
+    v = weak_hash_len_32_with_seeds_bytes(s, i, v.1.wrapping_mul(mul), x.wrapping_add(w.0));
+    w = weak_hash_len_32_with_seeds_bytes(
+        s,
+        i + 32,
+        z.wrapping_add(w.1),
+        y.wrapping_add(fetch64(s, i + 16)),
+    );
+    mem::swap(&mut z, &mut x);
+    hash_len_16_mul(
+        hash_len_16_mul(v.0, w.0, mul)
+            .wrapping_add(shift_mix(y).wrapping_mul(K0))
+            .wrapping_add(z),
+        hash_len_16_mul(v.1, w.1, mul).wrapping_add(x),
+        mul,
+    )
+}
+
+// ------------------------------------
+// farmhashuo
+
+#[inline]
+fn uo_h(x: u64, y: u64, mul: u64, r: u32) -> u64 {
+    let mut a = (x ^ y).wrapping_mul(mul);
+    a ^= a >> 47;
+    let b = (y ^ a).wrapping_mul(mul);
+    b.rotate_right(r).wrapping_mul(mul)
+}
+
+/// farmhashuo's Hash64WithSeeds, for inputs over 64 bytes.
+fn uo_hash64_with_seeds(s: &[u8], seed0: u64, seed1: u64) -> u64 {
+    let len = s.len();
+    debug_assert!(len > 64);
+
+    // > For strings over 64 bytes we loop.  Internal state consists of
+    // > 64 bytes: u, v, w, x, y, and z.
+    let mut x = seed0;
+    let mut y = seed1.wrapping_mul(K2).wrapping_add(113);
+    let mut z = shift_mix(y.wrapping_mul(K2)).wrapping_mul(K2);
+    let mut v = (seed0, seed1);
+    let mut w = (0u64, 0u64);
+    let mut u = x.wrapping_sub(z);
+    x = x.wrapping_mul(K2);
+    let mul = K2.wrapping_add(u & 0x82);
+
+    // > Set end so that after the loop we have 1 to 64 bytes left to process.
+    let end = ((len - 1) / 64) * 64;
+    let last64 = len - 64;
+    let mut i = 0;
+    while i < end {
+        let a0 = fetch64(s, i);
+        let a1 = fetch64(s, i + 8);
+        let a2 = fetch64(s, i + 16);
+        let a3 = fetch64(s, i + 24);
+        let a4 = fetch64(s, i + 32);
+        let a5 = fetch64(s, i + 40);
+        let a6 = fetch64(s, i + 48);
+        let a7 = fetch64(s, i + 56);
+        x = x.wrapping_add(a0).wrapping_add(a1);
+        y = y.wrapping_add(a2);
+        z = z.wrapping_add(a3);
+        v.0 = v.0.wrapping_add(a4);
+        v.1 = v.1.wrapping_add(a5).wrapping_add(a1);
+        w.0 = w.0.wrapping_add(a6);
+        w.1 = w.1.wrapping_add(a7);
+
+        x = x.rotate_right(26).wrapping_mul(9);
+        y = y.rotate_right(29);
+        z = z.wrapping_mul(mul);
+        v.0 = v.0.rotate_right(33);
+        v.1 = v.1.rotate_right(30);
+        w.0 = (w.0 ^ x).wrapping_mul(9);
+        z = z.rotate_right(32).wrapping_add(w.1);
+        w.1 = w.1.wrapping_add(z);
+        z = z.wrapping_mul(9);
+        mem::swap(&mut u, &mut y);
+
+        z = z.wrapping_add(a0).wrapping_add(a6);
+        v.0 = v.0.wrapping_add(a2);
+        v.1 = v.1.wrapping_add(a3);
+        w.0 = w.0.wrapping_add(a4);
+        w.1 = w.1.wrapping_add(a5).wrapping_add(a6);
+        x = x.wrapping_add(a1);
+        y = y.wrapping_add(a7);
+
+        y = y.wrapping_add(v.0);
+        v.0 = v.0.wrapping_add(x.wrapping_sub(y));
+        v.1 = v.1.wrapping_add(w.0);
+        w.0 = w.0.wrapping_add(v.1);
+        w.1 = w.1.wrapping_add(x.wrapping_sub(y));
+        x = x.wrapping_add(w.1);
+        w.1 = w.1.rotate_right(34);
+        mem::swap(&mut u, &mut z);
+        i += 64;
+    }
+    // > Make s point to the last 64 bytes of input.
+    let i = last64;
+    u = u.wrapping_mul(9);
+    v.1 = v.1.rotate_right(28);
+    v.0 = v.0.rotate_right(20);
+    w.0 = w.0.wrapping_add(((len - 1) & 63) as u64);
+    u = u.wrapping_add(y);
+    y = y.wrapping_add(u);
+    x = y
+        .wrapping_sub(x)
+        .wrapping_add(v.0)
+        .wrapping_add(fetch64(s, i + 8))
+        .rotate_right(37)
+        .wrapping_mul(mul);
+    y = (y ^ v.1 ^ fetch64(s, i + 48))
+        .rotate_right(42)
+        .wrapping_mul(mul);
+    x ^= w.1.wrapping_mul(9);
+    y = y.wrapping_add(v.0).wrapping_add(fetch64(s, i + 40));
+    z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(mul);
+    v = weak_hash_len_32_with_seeds_bytes(s, i, v.1.wrapping_mul(mul), x.wrapping_add(w.0));
+    w = weak_hash_len_32_with_seeds_bytes(
+        s,
+        i + 32,
+        z.wrapping_add(w.1),
+        y.wrapping_add(fetch64(s, i + 16)),
+    );
+    uo_h(
+        hash_len_16_mul(v.0.wrapping_add(x), w.0 ^ y, mul)
+            .wrapping_add(z)
+            .wrapping_sub(u),
+        uo_h(v.1.wrapping_add(y), w.1.wrapping_add(z), K2, 30) ^ x,
+        K2,
+        31,
+    )
+}
+
+// ------------------------------------
+// farmhashxo
+
+#[inline]
+fn xo_h32(s: &[u8], i: usize, mul: u64, seed0: u64, seed1: u64) -> u64 {
+    let a = fetch64(s, i).wrapping_mul(K1);
+    let b = fetch64(s, i + 8);
+    let c = fetch64(s, i + 24).wrapping_mul(mul);
+    let d = fetch64(s, i + 16).wrapping_mul(K2);
+    let u = a
+        .wrapping_add(b)
+        .rotate_right(43)
+        .wrapping_add(c.rotate_right(30))
+        .wrapping_add(d)
+        .wrapping_add(seed0);
+    let v = a
+        .wrapping_add(b.wrapping_add(K2).rotate_right(18))
+        .wrapping_add(c)
+        .wrapping_add(seed1);
+    let a = shift_mix((u ^ v).wrapping_mul(mul));
+    shift_mix((v ^ a).wrapping_mul(mul))
+}
+
+/// > Return an 8-byte hash for 33 to 64 bytes.
+fn xo_hash_len_33_to_64(s: &[u8]) -> u64 {
+    let len = s.len();
+    let mul0 = K2.wrapping_sub(30);
+    let mul1 = K2.wrapping_sub(30).wrapping_add(2 * len as u64);
+    let h0 = xo_h32(s, 0, mul0, 0, 0);
+    let h1 = xo_h32(s, len - 32, mul1, 0, 0);
+    h1.wrapping_mul(mul1).wrapping_add(h0).wrapping_mul(mul1)
+}
+
+/// > Return an 8-byte hash for 65 to 96 bytes.
+fn xo_hash_len_65_to_96(s: &[u8]) -> u64 {
+    let len = s.len();
+    let mul0 = K2.wrapping_sub(114);
+    let mul1 = K2.wrapping_sub(114).wrapping_add(2 * len as u64);
+    let h0 = xo_h32(s, 0, mul0, 0, 0);
+    let h1 = xo_h32(s, 32, mul1, 0, 0);
+    let h2 = xo_h32(s, len - 32, mul1, h0, h1);
+    h2.wrapping_mul(9)
+        .wrapping_add(h0 >> 17)
+        .wrapping_add(h1 >> 21)
+        .wrapping_mul(mul1)
+}
+
+// ------------------------------------
+// FarmHash API
+
+/// FarmHash's Hash64, as computed by the portable farmhashxo variant.
+pub fn farmhash64(bytes: &[u8]) -> u64 {
+    let s = bytes;
+    let len = s.len();
+    if len <= 32 {
+        if len <= 16 {
+            hash_len_0_to_16(s)
+        } else {
+            hash_len_17_to_32(s)
+        }
+    } else if len <= 64 {
+        xo_hash_len_33_to_64(s)
+    } else if len <= 96 {
+        xo_hash_len_65_to_96(s)
+    } else if len <= 256 {
+        na_hash64(s)
+    } else {
+        uo_hash64_with_seeds(s, 81, 0)
+    }
+}
+
+/// FarmHash's Hash64WithSeed, which is farmhashna's.
+pub fn farmhash64_with_seed(bytes: &[u8], seed: u64) -> u64 {
+    farmhash64_with_seeds(bytes, K2, seed)
+}
+
+/// FarmHash's Hash64WithSeeds, which is farmhashna's.
+pub fn farmhash64_with_seeds(bytes: &[u8], seed0: u64, seed1: u64) -> u64 {
+    hash_len_16(na_hash64(bytes).wrapping_sub(seed0), seed1)
+}
+
+/// FarmHash's Fingerprint64, which is fixed across platforms and
+/// releases.
+pub fn fingerprint64(bytes: &[u8]) -> u64 {
+    na_hash64(bytes)
+}
+
+/// FarmHash's Fingerprint128, which is fixed across platforms and
+/// releases. This is CityHash128 v1.1. Returns the (low, high) halves of
+/// the result.
+pub fn fingerprint128(bytes: &[u8]) -> (u64, u64) {
+    cityhash128(bytes)
+}
+
+/// The seeds of FarmHasher64, which has two seeded forms.
+#[derive(Default)]
+enum FarmSeeds {
+    #[default]
+    Unseeded,
+    Seed(u64),
+    Seeds(u64, u64),
+}
+
+/// FarmHash's Hash64 (farmhashxo), as a Hasher. `new` gives
+/// Hash64WithSeed and `with_seeds` gives Hash64WithSeeds, both
+/// farmhashna's; the Default is unseeded.
+#[derive(Default)]
+pub struct FarmHasher64 {
+    seeds: FarmSeeds,
+    buf: Vec<u8>,
+}
+
+impl FarmHasher64 {
+    pub fn new(seed: u64) -> FarmHasher64 {
+        FarmHasher64 {
+            seeds: FarmSeeds::Seed(seed),
+            buf: Vec::new(),
+        }
+    }
+
+    pub fn with_seeds(seed0: u64, seed1: u64) -> FarmHasher64 {
+        FarmHasher64 {
+            seeds: FarmSeeds::Seeds(seed0, seed1),
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl SeedableHasher for FarmHasher64 {
+    fn with_seed(seed: u64) -> FarmHasher64 {
+        FarmHasher64::new(seed)
+    }
+}
+
+impl SeedableHasher128 for FarmHasher64 {
+    fn with_seed128(seed: (u64, u64)) -> FarmHasher64 {
+        FarmHasher64::with_seeds(seed.0, seed.1)
+    }
+}
+
+random_state!(RandomFarmState64, FarmHasher64, 128);
+hash_collections!(FarmHashMap64, FarmHashSet64, FarmHasher64);
+
+impl Hasher for FarmHasher64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        match self.seeds {
+            FarmSeeds::Unseeded => farmhash64(&self.buf),
+            FarmSeeds::Seed(seed) => farmhash64_with_seed(&self.buf, seed),
+            FarmSeeds::Seeds(seed0, seed1) => farmhash64_with_seeds(&self.buf, seed0, seed1),
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+/// FarmHash's Fingerprint64, as a Hasher.
+#[derive(Default)]
+pub struct FingerprintHasher64 {
+    seed: Option<u64>,
+    buf: Vec<u8>,
+}
+
+impl SeedableHasher for FingerprintHasher64 {
+    fn with_seed(seed: u64) -> FingerprintHasher64 {
+        FingerprintHasher64 {
+            seed: Some(seed),
+            buf: Vec::new(),
+        }
+    }
+}
+
+random_state!(RandomFingerprintState64, FingerprintHasher64);
+hash_collections!(FingerprintHashMap64, FingerprintHashSet64, FingerprintHasher64);
+
+impl Hasher for FingerprintHasher64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        match self.seed {
+            Some(seed) => farmhash64_with_seed(&self.buf, seed),
+            None => fingerprint64(&self.buf),
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+/// FarmHash's Fingerprint128, as a Hasher. `finish` returns the low half
+/// of the result.
+#[derive(Default)]
+pub struct FingerprintHasher128 {
+    seed: Option<(u64, u64)>,
+    buf: Vec<u8>,
+}
+
+impl FingerprintHasher128 {
+    pub fn finish128(&self) -> (u64, u64) {
+        match self.seed {
+            Some((seed0, seed1)) => cityhash128_with_seed(&self.buf, seed0, seed1),
+            None => fingerprint128(&self.buf),
+        }
+    }
+}
+
+impl SeedableHasher for FingerprintHasher128 {
+    fn with_seed(seed: u64) -> FingerprintHasher128 {
+        FingerprintHasher128::with_seed128((seed, 0))
+    }
+}
+
+impl SeedableHasher128 for FingerprintHasher128 {
+    fn with_seed128(seed: (u64, u64)) -> FingerprintHasher128 {
+        FingerprintHasher128 {
+            seed: Some(seed),
+            buf: Vec::new(),
+        }
+    }
+}
+
+random_state!(RandomFingerprintState128, FingerprintHasher128, 128);
+hash_collections!(FingerprintHashMap128, FingerprintHashSet128, FingerprintHasher128);
+
+impl Hasher for FingerprintHasher128 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish128().0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+#[cfg(test)]
+mod google_tests {
+    use super::*;
+    use {check_seedable, check_seedable128};
+
+    // The test data and vectors from city-test.cc, CityHash v1.1, and
+    // from farmhash.cc's self-tests: test i hashes data[i * i..i * i + i].
+    const K_SEED0: u64 = 1234567;
+    const K_SEED1: u64 = K0;
+
+    fn setup() -> Vec<u8> {
+        let mut data = vec![0u8; 300 * 300];
+        let mut a: u64 = 9;
+        let mut b: u64 = 777;
+        for (i, byte) in data.iter_mut().enumerate() {
+            a = a.wrapping_add(b);
+            b = b.wrapping_add(a);
+            a = (a ^ (a >> 41)).wrapping_mul(K0);
+            b = (b ^ (b >> 41)).wrapping_mul(K0).wrapping_add(i as u64);
+            *byte = (b >> 37) as u8;
+        }
+        data
+    }
+
+    // CityHash64, CityHash64WithSeed, CityHash64WithSeeds, CityHash128
+    // (low, high), CityHash128WithSeed (low, high), farmhashxo Hash64,
+    // Fingerprint64
+    const TESTDATA: [(usize, [u64; 9]); 29] = [
+    (0, [0x9ae16a3b2f90404f, 0x75106db890237a4a, 0x3feac5f636039766, 0x3df09dfc64c09a2b, 0x3cb540c392e51e29, 0x06b56343feac0663, 0x5b7bc50fd8e8ad92, 0x9ae16a3b2f90404f, 0x9ae16a3b2f90404f]),
+    (1, [0x541150e87f415e96, 0x1aef0d24b3148a1a, 0xbacc300e1e82345a, 0xc3cdc41e1df33513, 0x2c138ff2596d42f6, 0xf58e9082aed3055f, 0x162e192b2957163d, 0x541150e87f415e96, 0x541150e87f415e96]),
+    (2, [0x0f3786a4b25827c1, 0x34ee1a2bf767bd1c, 0x2f15ca2ebfb631f2, 0x3149ba1dac77270d, 0x070e2e076e30703c, 0x059bcc9659bc5296, 0x9ecbc8132ae2f1d7, 0x0f3786a4b25827c1, 0x0f3786a4b25827c1]),
+    (3, [0xef923a7a1af78eab, 0x79163b1e1e9a9b18, 0xdf3b2aca6e1e4a30, 0x2193fb7620cbf23b, 0x8b6a8ff06cda8302, 0x1a44469afd3e091f, 0x08b0449376612506, 0xef923a7a1af78eab, 0xef923a7a1af78eab]),
+    (4, [0x11df592596f41d88, 0x843ec0bce9042f9c, 0xcce2ea1e08b1eb30, 0x4d09e42f09cc3495, 0x666236631b9f253b, 0xd28b3763cd02b6a3, 0x43b249e57c4d0c1b, 0x11df592596f41d88, 0x11df592596f41d88]),
+    (7, [0x1b5a063fb4c7f9f1, 0x318dbc24af66dee9, 0x10ef7b32d5c719af, 0xb140a02ef5c97712, 0xb7d00ef065b51b33, 0x635121d532897d98, 0x532daf21b312a6d6, 0x1b5a063fb4c7f9f1, 0x1b5a063fb4c7f9f1]),
+    (8, [0xa0f10149a0e538d6, 0x69d008c20f87419f, 0x41b36376185b3e9e, 0x26b6689960ccf81d, 0x55f23b27bb9efd94, 0x3a17f6166dd765db, 0xc891a8a62931e782, 0xa0f10149a0e538d6, 0xa0f10149a0e538d6]),
+    (9, [0xfb8d9c70660b910b, 0xa45b0cc3476bff1b, 0xb28d1996144f0207, 0x98ec31113e5e35d2, 0x5e4aeb853f1b9aa7, 0xbcf5c8fe4465b7c8, 0xb1ea3a8243996f15, 0xfb8d9c70660b910b, 0xfb8d9c70660b910b]),
+    (15, [0x44473e03be306c88, 0x30097761f872472a, 0x9fd1b669bfad82d7, 0x3bab18b164396783, 0x047e385ff9d4c06f, 0x018062081bf558df, 0x63416eb68f104a36, 0x44473e03be306c88, 0x44473e03be306c88]),
+    (16, [0x03ead5f21d344056, 0xfb6420393cfb05c3, 0x407932394cbbd303, 0xac059617f5906673, 0x94d50d3dcd3069a7, 0x02b26c3b92dea0f0, 0x99b7374cc78fc3fb, 0x03ead5f21d344056, 0x03ead5f21d344056]),
+    (17, [0x6abbfde37ee03b5b, 0x83febf188d2cc113, 0xcda7b62d94d5b8ee, 0xa4375590b8ae7c82, 0x168fd42f9ecae4ff, 0x23bbde43de2cb214, 0xa8c333112a243c8c, 0x6abbfde37ee03b5b, 0x6abbfde37ee03b5b]),
+    (24, [0x36a097aa49519d97, 0x08204380a73c4065, 0x77c2004bdd9e276a, 0x6ee1f817ce0b7aee, 0xe9dcb3507f0596ca, 0x6bc63c666b5100e2, 0xe0b056f1821752af, 0x36a097aa49519d97, 0x36a097aa49519d97]),
+    (32, [0x0782fa1b08b475e7, 0xfb7138951c61b23b, 0x9829105e234fb11e, 0x9a8c431f500ef06e, 0xd848581a580b6c12, 0xfecfe11e13a2bdb4, 0x6c4fa0273d7db08c, 0x0782fa1b08b475e7, 0x0782fa1b08b475e7]),
+    (33, [0xc5dc19b876d37a80, 0x15ffcff666cfd710, 0xe8c30c72003103e2, 0x7870765b470b2c5d, 0x078a9103ff960d82, 0x7bb50ffc9fac74b3, 0x477e70ab2b347db2, 0x3b09d6f3b6340467, 0x09deb3da5e16b5b8]),
+    (40, [0x4ec0b54cf1566aff, 0x30d2c7269b206bf4, 0x77c22e82295e1061, 0x3df9b04434771542, 0xfeddce785ccb661f, 0xa644aff716928297, 0xdd46aee73824b4ed, 0xb32e6a9b81f939aa, 0x01596fbb269fb44e]),
+    (64, [0xe88419922b87176f, 0xbcf32f41a7ddbf6f, 0xd6ebefd8085c1a0f, 0xd1d44fe99451ef72, 0xec951ba8e51e3545, 0xc0ca86b360746e96, 0xaa679cc066a8040b, 0x39069ab022abf776, 0xdef74bf4c9f1bc1b]),
+    (65, [0x105191e0ec8f7f60, 0x5918dbfcca971e79, 0x6b285c8a944767b9, 0xd3e86ac4f5eccfa4, 0x0e5399df2b106ca1, 0x814aadfacd217f1d, 0x2754e3def1c405a9, 0x626b3525c640cc96, 0x3cceab392bc39b3f]),
+    (80, [0x58d943503bb6748f, 0x419c6c8e88ac70f6, 0x586760cbf3d3d368, 0xb7e164979d5ccfc1, 0x12cb4230d26bf286, 0xf1bf910d44bd84cb, 0x00b32c24c6a40272, 0x175ca510572ccf26, 0x93ffa56c72e1911a]),
+    (96, [0x930380a3741e862a, 0x348d28638dc71658, 0x89dedcfd1654ea0d, 0x07e7f61684080106, 0x837ace9794582976, 0x5ac8ca76a357eb1b, 0x32b58308625661fb, 0x3eee7ec1e5592eb3, 0x1262318f0fffe11e]),
+    (97, [0x94808b5d2aa25f9a, 0xcec72968128195e0, 0xd9f4da2bdc1e130f, 0x272d8dd74f3006cc, 0xec6c2ad1ec03f554, 0x4ad276b249a5d5dd, 0x549a22a17c0cde12, 0xfcf0dc0da9df5e16, 0xfcf0dc0da9df5e16]),
+    (127, [0xcbaa3cb8f64f54e0, 0x76c3b48ee5c08417, 0x09f7d24e87e61ce9, 0x85b8e53f22e19507, 0xbb57137739ca486b, 0xc77f131cca38f761, 0xc56ac3cf275be121, 0x99096f55f2e75eb0, 0x99096f55f2e75eb0]),
+    (128, [0xb2e23e8116c2ba9f, 0x7e4d9c0060101151, 0x3310da5e5028f367, 0xadc52dddb76f6e5e, 0x4aad4e925a962b68, 0x204b79b7f7168e64, 0xdf29ed6671c36952, 0xf4a9a248b462909d, 0xf4a9a248b462909d]),
+    (129, [0x8aa77f52d7868eb9, 0x4d55bd587584e6e2, 0x0d2db37041f495f5, 0x0ce030d15b5fe2f4, 0x86b4a7a0780c2431, 0xee070a9ae5b51db7, 0xedc293d9595be5d8, 0xd4f7c858d78153b6, 0xd4f7c858d78153b6]),
+    (160, [0xa90d81060932dbb0, 0x8acfaa88c5fbe92b, 0x7c6f3447e90f7f3f, 0xdd52fc14c8dd3143, 0x1bc7508516e40628, 0x3059730266ade626, 0x0ffa526822f391c2, 0xa785317e5d12e3cf, 0xa785317e5d12e3cf]),
+    (200, [0x07fc98006e25cac9, 0x77fee0484cda86a7, 0x376ec3d447060456, 0x84064a6dcf916340, 0xfbf55a26790e0ebb, 0x2e7f84151c31a5c2, 0x9f7f6d76b950f9bf, 0x40f32eb90e5caa01, 0x40f32eb90e5caa01]),
+    (255, [0x915263c671b28809, 0xa815378e7ad762fd, 0xabec6dc9b669f559, 0xd17c928c5342477f, 0x745130b795254ad5, 0x8c5db926fe88f8ba, 0x742a95c953e6d974, 0x74fbce74d2fe60f8, 0x74fbce74d2fe60f8]),
+    (256, [0x2b67cdd38c307a5e, 0x0cb1d45bb5c9fe1c, 0x800baf2a02ec18ad, 0x6531c1fe32bcb417, 0x8c970d8df8cdbeb4, 0x917ba5fc67e72b40, 0x4b65e4e263e0a426, 0x7fd20f1452dfbc03, 0x7fd20f1452dfbc03]),
+    (257, [0x2d107419073b9cd0, 0xa96db0740cef8f54, 0xec41ee91b3ecdc1b, 0xffe319654c8e7ebc, 0x6a67b8f13ead5a72, 0x6dd10a34f80d532f, 0x06e9cfaece9fbca4, 0x6579174d69470023, 0xd66e58c6be0bb6f5]),
+    (298, [0x74c0b8a6821faafe, 0xabac39d7491370e7, 0xfaf0b2a48a4e6aed, 0x967e970df9673d2a, 0xd465247cffa415c0, 0x33a1df0ca1107722, 0x49fc2a10adce4a32, 0xea5e4337205827a5, 0x2031c84bc57a1a9f]),
+    ];
+
+    #[test]
+    fn basic() {
+        assert_eq!(cityhash64(b""), K2);
+        assert_eq!(farmhash64(b""), K2);
+        assert_eq!(fingerprint64(b""), K2);
+    }
+
+    #[test]
+    fn testdata() {
+        let data = setup();
+        for &(i, ref expected) in TESTDATA.iter() {
+            let s = &data[i * i..i * i + i];
+            assert_eq!(cityhash64(s), expected[0], "CityHash64 {}", i);
+            assert_eq!(cityhash64_with_seed(s, K_SEED0), expected[1], "CityHash64WithSeed {}", i);
+            assert_eq!(
+                cityhash64_with_seeds(s, K_SEED0, K_SEED1),
+                expected[2],
+                "CityHash64WithSeeds {}",
+                i
+            );
+            assert_eq!(cityhash128(s), (expected[3], expected[4]), "CityHash128 {}", i);
+            assert_eq!(
+                cityhash128_with_seed(s, K_SEED0, K_SEED1),
+                (expected[5], expected[6]),
+                "CityHash128WithSeed {}",
+                i
+            );
+            assert_eq!(farmhash64(s), expected[7], "Hash64 {}", i);
+            assert_eq!(fingerprint64(s), expected[8], "Fingerprint64 {}", i);
+            assert_eq!(fingerprint128(s), (expected[3], expected[4]), "Fingerprint128 {}", i);
+        }
+    }
+
+    #[test]
+    fn hashers() {
+        let data = setup();
+        let s = &data[..1000];
+        let mut city64 = CityHasher64::default();
+        let mut city64_seed = CityHasher64::new(K_SEED0);
+        let mut city64_seeds = CityHasher64::with_seeds(K_SEED0, K_SEED1);
+        let mut city128 = CityHasher128::default();
+        let mut city128_seed = CityHasher128::new(K_SEED0, K_SEED1);
+        let mut farm64 = FarmHasher64::default();
+        let mut fp64 = FingerprintHasher64::default();
+        let mut fp128 = FingerprintHasher128::default();
+        for chunk in s.chunks(7) {
+            city64.write(chunk);
+            city64_seed.write(chunk);
+            city64_seeds.write(chunk);
+            city128.write(chunk);
+            city128_seed.write(chunk);
+            farm64.write(chunk);
+            fp64.write(chunk);
+            fp128.write(chunk);
+        }
+        assert_eq!(city64.finish(), cityhash64(s));
+        assert_eq!(city64_seed.finish(), cityhash64_with_seed(s, K_SEED0));
+        assert_eq!(city64_seeds.finish(), cityhash64_with_seeds(s, K_SEED0, K_SEED1));
+        assert_eq!(city128.finish128(), cityhash128(s));
+        assert_eq!(city128_seed.finish128(), cityhash128_with_seed(s, K_SEED0, K_SEED1));
+        assert_eq!(farm64.finish(), farmhash64(s));
+        assert_eq!(fp64.finish(), fingerprint64(s));
+        assert_eq!(fp128.finish128(), fingerprint128(s));
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<CityHasher64>(false);
+        check_seedable128::<CityHasher64>();
+        check_seedable::<CityHasher128>(false);
+        check_seedable128::<CityHasher128>();
+        check_seedable::<FarmHasher64>(false);
+        check_seedable128::<FarmHasher64>();
+        check_seedable::<FingerprintHasher64>(false);
+        check_seedable::<FingerprintHasher128>(false);
+        check_seedable128::<FingerprintHasher128>();
+    }
+
+    /// farmhash.cc's self-test seeds: `CreateSeed(offset, salt)`.
+    fn create_seed(offset: usize, salt: i32) -> u64 {
+        const C1: u32 = 0xcc9e2d51;
+        let mut h = salt as u32;
+        for _ in 0..3 {
+            h = h.wrapping_mul(C1);
+            h ^= h >> 17;
+        }
+        h = h.wrapping_add(offset as u32);
+        for _ in 0..3 {
+            h = h.wrapping_mul(C1);
+            h ^= h >> 17;
+        }
+        h as u64
+    }
+
+    #[test]
+    fn farmhash_seeded() {
+        // From farmhashnaTest's expected[] in farmhash.cc: test i hashes
+        // data[i * i..i * i + i] with Hash64WithSeeds(SEED0, SEED1),
+        // Hash64WithSeed(SEED) and Hash64.
+        let expected = [
+            (0, [0x4401934a3358ec46, 0xc35e45919fd7dd98, 0x9ae16a3b2f90404f]),
+            (1, [0x3515e3132fb1b728, 0x9b35c9ff36e55eee, 0x541150e87f415e96]),
+            (7, [0x8963b16181537b1a, 0xce003b6b1071f4b3, 0x1b5a063fb4c7f9f1]),
+            (16, [0xacfa924ed69eb62e, 0x0e27e44620332b91, 0x03ead5f21d344056]),
+            (33, [0x3c98f3d9b4206f0b, 0xc1f4510a19937c37, 0x09deb3da5e16b5b8]),
+            (64, [0x9e6497bcff0ff82d, 0x64a3a37029d67333, 0xdef74bf4c9f1bc1b]),
+            (65, [0x6fa92df18050e6e8, 0x34a757413bf38662, 0x3cceab392bc39b3f]),
+            (100, [0x495cffb71091bf6e, 0xfe7bcdd13b194437, 0x05ad36f677825ce0]),
+            (128, [0x052ac816de2004b3, 0x52fa55f649e8e61e, 0xf4a9a248b462909d]),
+            (200, [0x1d91fa31f4fe1e89, 0xa31f98c2475512bb, 0x40f32eb90e5caa01]),
+            (257, [0x4af22fc4837420d2, 0x7e4e394602cb1bab, 0xd66e58c6be0bb6f5]),
+            (298, [0x7d798d10f93e9850, 0x3e3d412cc6d3917f, 0x2031c84bc57a1a9f]),
+        ];
+        let data = setup();
+        for &(i, ref expected) in expected.iter() {
+            let offset = i * i;
+            let s = &data[offset..offset + i];
+            let (seed, seed0, seed1) = (
+                create_seed(offset, -1),
+                create_seed(offset, 0),
+                create_seed(offset, 1),
+            );
+            assert_eq!(farmhash64_with_seeds(s, seed0, seed1), expected[0], "{}", i);
+            assert_eq!(farmhash64_with_seed(s, seed), expected[1], "{}", i);
+            assert_eq!(fingerprint64(s), expected[2], "{}", i);
+            let mut hasher = FarmHasher64::new(seed);
+            hasher.write(s);
+            assert_eq!(hasher.finish(), expected[1], "{}", i);
+            let mut hasher = FarmHasher64::with_seeds(seed0, seed1);
+            hasher.write(s);
+            assert_eq!(hasher.finish(), expected[0], "{}", i);
+            let mut hasher = FingerprintHasher64::with_seed(seed);
+            hasher.write(s);
+            assert_eq!(hasher.finish(), expected[1], "{}", i);
+        }
+    }
+}
@@ -0,0 +1,424 @@
+//! From https://github.com/google/highwayhash.
+//!
+//! Jyrki Alakuijala, Bill Cox and Jan Wassenberg's HighwayHash, a keyed
+//! hash built from 32x32-bit multiplications and byte permutations over
+//! four 64-bit lanes. It is designed to be used with a secret key, like
+//! SipHash, but is considerably faster on long input.
+//!
+//! This is a port of the portable C implementation, without the SIMD
+//! versions; the reference implementations all produce the same
+//! results. The key is 256 bits, as four 64-bit words, and the same
+//! state can be finished to 64, 128 or 256 bits.
+//!
+//! As with the SipHash Hashers, `Default` uses an all-zero key, which
+//! provides none of HighwayHash's protection.
+
+use std::hash::Hasher;
+use std::{mem, ptr};
+
+use {SeedableHasher, SeedableHasher128};
+
+const INIT0: [u64; 4] = [
+    0xdbe6d5d5fe4cce2f,
+    0xa4093822299f31d0,
+    0x13198a2e03707344,
+    0x243f6a8885a308d3,
+];
+
+const INIT1: [u64; 4] = [
+    0x3bd39e10cb0ef593,
+    0xc0acf169b5f18a8c,
+    0xbe5466cf34e90c6c,
+    0x452821e638d01377,
+];
+
+/// Shuffle the bytes of (v1, v0), returning the (high, low) words to
+/// be added to the other half of the state.
+#[inline]
+fn zipper_merge(v1: u64, v0: u64) -> (u64, u64) {
+    let add0 = (((v0 & 0xff000000) | (v1 & 0xff00000000)) >> 24)
+        | (((v0 & 0xff0000000000) | (v1 & 0xff000000000000)) >> 16)
+        | (v0 & 0xff0000)
+        | ((v0 & 0xff00) << 32)
+        | ((v1 & 0xff00000000000000) >> 8)
+        | (v0 << 56);
+    let add1 = (((v1 & 0xff000000) | (v0 & 0xff00000000)) >> 24)
+        | (v1 & 0xff0000)
+        | ((v1 & 0xff0000000000) >> 16)
+        | ((v1 & 0xff00) << 24)
+        | ((v0 & 0xff000000000000) >> 8)
+        | ((v1 & 0xff) << 48)
+        | (v0 & 0xff00000000000000);
+    (add1, add0)
+}
+
+/// Reduce the 256-bit (a3, a2, a1, a0) modulo an irreducible
+/// polynomial to the 128-bit (m1, m0).
+#[inline]
+fn modular_reduction(a3_unmasked: u64, a2: u64, a1: u64, a0: u64) -> (u64, u64) {
+    let a3 = a3_unmasked & 0x3fffffffffffffff;
+    let m1 = a1 ^ ((a3 << 1) | (a2 >> 63)) ^ ((a3 << 2) | (a2 >> 62));
+    let m0 = a0 ^ (a2 << 1) ^ (a2 << 2);
+    (m1, m0)
+}
+
+/// HighwayHash, finishing to 64, 128 or 256 bits.
+///
+/// The input is consumed in 32-byte packets; the remaining 1 to 31
+/// bytes are folded into a final, specially constructed packet.
+#[derive(Clone, Copy)]
+pub struct HighwayHasher {
+    v0: [u64; 4],
+    v1: [u64; 4],
+    mul0: [u64; 4],
+    mul1: [u64; 4],
+    // unhashed data, for partial packets
+    buf: [u8; 32],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+}
+
+impl HighwayHasher {
+    pub fn new(key: [u64; 4]) -> HighwayHasher {
+        let mut v0 = [0; 4];
+        let mut v1 = [0; 4];
+        for i in 0..4 {
+            v0[i] = INIT0[i] ^ key[i];
+            v1[i] = INIT1[i] ^ key[i].rotate_left(32);
+        }
+        HighwayHasher {
+            v0,
+            v1,
+            mul0: INIT0,
+            mul1: INIT1,
+            buf: [0; 32],
+            buf_len: 0,
+        }
+    }
+
+    #[inline]
+    fn update(&mut self, lanes: [u64; 4]) {
+        for (i, lane) in lanes.iter().enumerate() {
+            self.v1[i] = self.v1[i].wrapping_add(self.mul0[i].wrapping_add(*lane));
+            self.mul0[i] ^= (self.v1[i] & 0xffffffff).wrapping_mul(self.v0[i] >> 32);
+            self.v0[i] = self.v0[i].wrapping_add(self.mul1[i]);
+            self.mul1[i] ^= (self.v0[i] & 0xffffffff).wrapping_mul(self.v1[i] >> 32);
+        }
+        for &i in [0, 2].iter() {
+            let (add1, add0) = zipper_merge(self.v1[i + 1], self.v1[i]);
+            self.v0[i + 1] = self.v0[i + 1].wrapping_add(add1);
+            self.v0[i] = self.v0[i].wrapping_add(add0);
+        }
+        for &i in [0, 2].iter() {
+            let (add1, add0) = zipper_merge(self.v0[i + 1], self.v0[i]);
+            self.v1[i + 1] = self.v1[i + 1].wrapping_add(add1);
+            self.v1[i] = self.v1[i].wrapping_add(add0);
+        }
+    }
+
+    #[inline]
+    fn update_packet(&mut self, packet: &[u8]) {
+        self.update([
+            load_int_le!(packet, 0, u64),
+            load_int_le!(packet, 8, u64),
+            load_int_le!(packet, 16, u64),
+            load_int_le!(packet, 24, u64),
+        ]);
+    }
+
+    /// Hash the final 1 to 31 bytes of the input.
+    fn update_remainder(&mut self, bytes: &[u8]) {
+        let size = bytes.len();
+        let size_mod4 = size & 3;
+        let remainder = size & !3;
+        for i in 0..4 {
+            self.v0[i] = self.v0[i].wrapping_add(((size as u64) << 32) + size as u64);
+            // rotate each 32-bit half of the lane
+            let half0 = (self.v1[i] as u32).rotate_left(size as u32);
+            let half1 = ((self.v1[i] >> 32) as u32).rotate_left(size as u32);
+            self.v1[i] = (half0 as u64) | (half1 as u64) << 32;
+        }
+        let mut packet = [0u8; 32];
+        packet[..remainder].copy_from_slice(&bytes[..remainder]);
+        if size & 16 != 0 {
+            // the last four bytes, even if some were copied above
+            packet[28..].copy_from_slice(&bytes[size - 4..]);
+        } else if size_mod4 != 0 {
+            packet[16] = bytes[remainder];
+            packet[17] = bytes[remainder + (size_mod4 >> 1)];
+            packet[18] = bytes[size - 1];
+        }
+        self.update_packet(&packet);
+    }
+
+    #[inline]
+    fn permute_and_update(&mut self) {
+        let v0 = self.v0;
+        self.update([
+            v0[2].rotate_left(32),
+            v0[3].rotate_left(32),
+            v0[0].rotate_left(32),
+            v0[1].rotate_left(32),
+        ]);
+    }
+
+    /// A copy of the state with the remaining input hashed.
+    fn finalizing(&self) -> HighwayHasher {
+        let mut state = *self;
+        if self.buf_len > 0 {
+            state.update_remainder(&self.buf[..self.buf_len]);
+        }
+        state
+    }
+
+    /// The 128-bit result, as (low, high).
+    pub fn finish128(&self) -> (u64, u64) {
+        let mut s = self.finalizing();
+        for _ in 0..6 {
+            s.permute_and_update();
+        }
+        (
+            s.v0[0]
+                .wrapping_add(s.mul0[0])
+                .wrapping_add(s.v1[2])
+                .wrapping_add(s.mul1[2]),
+            s.v0[1]
+                .wrapping_add(s.mul0[1])
+                .wrapping_add(s.v1[3])
+                .wrapping_add(s.mul1[3]),
+        )
+    }
+
+    /// The 256-bit result, least significant word first.
+    pub fn finish256(&self) -> [u64; 4] {
+        let mut s = self.finalizing();
+        for _ in 0..10 {
+            s.permute_and_update();
+        }
+        let (h1, h0) = modular_reduction(
+            s.v1[1].wrapping_add(s.mul1[1]),
+            s.v1[0].wrapping_add(s.mul1[0]),
+            s.v0[1].wrapping_add(s.mul0[1]),
+            s.v0[0].wrapping_add(s.mul0[0]),
+        );
+        let (h3, h2) = modular_reduction(
+            s.v1[3].wrapping_add(s.mul1[3]),
+            s.v1[2].wrapping_add(s.mul1[2]),
+            s.v0[3].wrapping_add(s.mul0[3]),
+            s.v0[2].wrapping_add(s.mul0[2]),
+        );
+        [h0, h1, h2, h3]
+    }
+}
+
+impl Default for HighwayHasher {
+    fn default() -> HighwayHasher {
+        HighwayHasher::new([0; 4])
+    }
+}
+
+/// The seed is the first word of the 256-bit key; the rest of the key is zero.
+impl SeedableHasher for HighwayHasher {
+    fn with_seed(seed: u64) -> HighwayHasher {
+        HighwayHasher::new([seed, 0, 0, 0])
+    }
+}
+
+impl SeedableHasher128 for HighwayHasher {
+    fn with_seed128(seed: (u64, u64)) -> HighwayHasher {
+        HighwayHasher::new([seed.0, seed.1, 0, 0])
+    }
+}
+
+impl Hasher for HighwayHasher {
+    /// The 64-bit result.
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut s = self.finalizing();
+        for _ in 0..4 {
+            s.permute_and_update();
+        }
+        s.v0[0]
+            .wrapping_add(s.v1[0])
+            .wrapping_add(s.mul0[0])
+            .wrapping_add(s.mul1[0])
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        if self.buf_len > 0 {
+            let fill = (32 - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+            if self.buf_len < 32 {
+                return;
+            }
+            let packet = self.buf;
+            self.update_packet(&packet);
+            self.buf_len = 0;
+        }
+        let mut packets = bytes.chunks_exact(32);
+        for packet in &mut packets {
+            self.update_packet(packet);
+        }
+        let rest = packets.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+}
+
+random_state!(RandomHighwayState, HighwayHasher, 128);
+hash_collections!(HighwayHashMap, HighwayHashSet, HighwayHasher);
+hasher_to_fcn!(
+    /// Provide access to HighwayHasher in a single call.
+    highwayhash,
+    HighwayHasher
+);
+
+/// 64-bit HighwayHash of `bytes` with the given key.
+pub fn highwayhash64_with_key(bytes: &[u8], key: [u64; 4]) -> u64 {
+    let mut hasher = HighwayHasher::new(key);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// 128-bit HighwayHash of `bytes` with the given key, as (low, high).
+pub fn highwayhash128_with_key(bytes: &[u8], key: [u64; 4]) -> (u64, u64) {
+    let mut hasher = HighwayHasher::new(key);
+    hasher.write(bytes);
+    hasher.finish128()
+}
+
+/// 256-bit HighwayHash of `bytes` with the given key, least
+/// significant word first.
+pub fn highwayhash256_with_key(bytes: &[u8], key: [u64; 4]) -> [u64; 4] {
+    let mut hasher = HighwayHasher::new(key);
+    hasher.write(bytes);
+    hasher.finish256()
+}
+
+#[cfg(test)]
+mod highway_tests {
+    use super::*;
+    use {check_seedable, check_seedable128};
+
+    const KEY: [u64; 4] = [
+        0x0706050403020100,
+        0x0f0e0d0c0b0a0908,
+        0x1716151413121110,
+        0x1f1e1d1c1b1a1918,
+    ];
+
+    // The reference test vectors: the 64-bit hashes of 00, 00 01,
+    // 00 01 02, ... with KEY.
+    const EXPECTED64: [u64; 65] = [
+        0x907A56DE22C26E53, 0x7EAB43AAC7CDDD78, 0xB8D0569AB0B53D62, 0x5C6BEFAB8A463D80,
+        0xF205A46893007EDA, 0x2B8A1668E4A94541, 0xBD4CCC325BEFCA6F, 0x4D02AE1738F59482,
+        0xE1205108E55F3171, 0x32D2644EC77A1584, 0xF6E10ACDB103A90B, 0xC3BBF4615B415C15,
+        0x243CC2040063FA9C, 0xA89A58CE65E641FF, 0x24B031A348455A23, 0x40793F86A449F33B,
+        0xCFAB3489F97EB832, 0x19FE67D2C8C5C0E2, 0x04DD90A69C565CC2, 0x75D9518E2371C504,
+        0x38AD9B1141D3DD16, 0x0264432CCD8A70E0, 0xA9DB5A6288683390, 0xD7B05492003F028C,
+        0x205F615AEA59E51E, 0xEEE0C89621052884, 0x1BFC1A93A7284F4F, 0x512175B5B70DA91D,
+        0xF71F8976A0A2C639, 0xAE093FEF1F84E3E7, 0x22CA92B01161860F, 0x9FC7007CCF035A68,
+        0xA0C964D9ECD580FC, 0x2C90F73CA03181FC, 0x185CF84E5691EB9E, 0x4FC1F5EF2752AA9B,
+        0xF5B7391A5E0A33EB, 0xB9B84B83B4E96C9C, 0x5E42FE712A5CD9B4, 0xA150F2F90C3F97DC,
+        0x7FA522D75E2D637D, 0x181AD0CC0DFFD32B, 0x3889ED981E854028, 0xFB4297E8C586EE2D,
+        0x6D064A45BB28059C, 0x90563609B3EC860C, 0x7AA4FCE94097C666, 0x1326BAC06B911E08,
+        0xB926168D2B154F34, 0x9919848945B1948D, 0xA2A98FC534825EBE, 0xE9809095213EF0B6,
+        0x582E5483707BC0E9, 0x086E9414A88A6AF5, 0xEE86B98D20F6743D, 0xF89B7FF609B1C0A7,
+        0x4C7D9CC19E22C3E8, 0x9A97005024562A6F, 0x5DD41CF423E6EBEF, 0xDF13609C0468E227,
+        0x6E0DA4F64188155A, 0xB755BA4B50D7D4A1, 0x887A3484647479BD, 0xAB8EEBE9BF2139A0,
+        0x75542C5D4CD2A6FF,
+    ];
+
+    fn data() -> Vec<u8> {
+        (0..64).collect()
+    }
+
+    #[test]
+    fn hash64() {
+        let data = data();
+        for (n, &expected) in EXPECTED64.iter().enumerate() {
+            assert_eq!(highwayhash64_with_key(&data[..n], KEY), expected, "{}", n);
+        }
+        let data: Vec<u8> = (128..161).collect();
+        assert_eq!(
+            highwayhash64_with_key(&data, [1, 2, 3, 4]),
+            0x53c516cce478cad7
+        );
+    }
+
+    #[test]
+    fn hash128_256() {
+        // Computed with the portable C implementation, as EXPECTED64.
+        let data = data();
+        let expected: [(usize, (u64, u64), [u64; 4]); 4] = [
+            (
+                0,
+                (0x0fed268f9d8ffec7, 0x33565e767f093e6f),
+                [
+                    0xdd44482ac2c874f5,
+                    0xd946017313c7351f,
+                    0xb3aebeccb98714ff,
+                    0x41da233145751df4,
+                ],
+            ),
+            (
+                17,
+                (0xc52a4b96c51c9962, 0xecb878b1169b5ea0),
+                [
+                    0x19f4cfa82ca4068e,
+                    0x3b9d4abd3a9275b9,
+                    0x8000b0dde9c010c6,
+                    0x8884d50949215613,
+                ],
+            ),
+            (
+                33,
+                (0x111678afe0c6c36c, 0xf958b59de5a2849d),
+                [
+                    0xf60115cbf034a6e5,
+                    0x6c36ea75bfce46d0,
+                    0x3b17c8d382725990,
+                    0x7edaa2ed11007a35,
+                ],
+            ),
+            (
+                64,
+                (0x98bb1f7198d4c4f2, 0xe0bc0571de918fc8),
+                [
+                    0x90d8e6ff6ac12475,
+                    0x1a422a196edac1f2,
+                    0x9e3765fe1f8eb002,
+                    0xc1bdd7c4c351cfbe,
+                ],
+            ),
+        ];
+        for &(n, h128, h256) in expected.iter() {
+            assert_eq!(highwayhash128_with_key(&data[..n], KEY), h128, "{}", n);
+            assert_eq!(highwayhash256_with_key(&data[..n], KEY), h256, "{}", n);
+        }
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut hasher = HighwayHasher::new(KEY);
+            for chunk in data.chunks(split + 1) {
+                hasher.write(chunk);
+            }
+            assert_eq!(hasher.finish(), highwayhash64_with_key(&data, KEY));
+            assert_eq!(hasher.finish128(), highwayhash128_with_key(&data, KEY));
+            assert_eq!(hasher.finish256(), highwayhash256_with_key(&data, KEY));
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<HighwayHasher>(true);
+        check_seedable128::<HighwayHasher>();
+    }
+}
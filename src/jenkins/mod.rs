@@ -3,14 +3,19 @@
 //! This module mostly comes from his survey of hash functions. See also
 //! https://en.wikipedia.org/wiki/Jenkins_hash_function.
 //!
-//! This module includes a sub-module implementing SpookyHash.
+//! This module includes a sub-module implementing SpookyHash, whose
+//! HashMap, HashSet and random state are re-exported here.
 
 use std::hash::Hasher;
 use std::num::Wrapping;
 use std::{mem, ptr};
 
+use SeedableHasher;
+
 pub mod spooky_hash;
 
+pub use self::spooky_hash::{RandomSpookyState, SpookyHashMap, SpookyHashSet};
+
 // ================================
 // one_at_a_time
 
@@ -28,6 +33,9 @@ pub mod spooky_hash;
 /// > implemented it to fill a set of requirements posed by Colin
 /// > Plumb. Colin ended up using an even simpler (and weaker) hash
 /// > that was sufficient for his purpose.
+///
+/// This Hasher runs the algorithm in 64 bits, which keeps more of the
+/// input in the hash; OAATHasher32 is the reference 32-bit algorithm.
 pub struct OAATHasher(Wrapping<u64>);
 
 impl Hasher for OAATHasher {
@@ -51,17 +59,62 @@ impl Hasher for OAATHasher {
 }
 
 default_for_constant!(OAATHasher, Wrapping(0));
+seedable_for_constant!(OAATHasher, seed => Wrapping(seed));
+random_state!(RandomOAATState, OAATHasher);
+hash_collections!(OAATHashMap, OAATHashSet, OAATHasher);
 hasher_to_fcn!(
     /// Provide access to OAATHasher in a single call.
     oaat,
     OAATHasher
 );
 
+/// The one-at-a-time hash exactly as published, in 32 bits.
+pub struct OAATHasher32(Wrapping<u32>);
+
+impl OAATHasher32 {
+    /// Start the hash from `seed`; Default uses 0.
+    pub fn new(seed: u32) -> OAATHasher32 {
+        OAATHasher32(Wrapping(seed))
+    }
+}
+
+impl Hasher for OAATHasher32 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut hash = self.0;
+        hash += hash << 3;
+        hash ^= hash >> 11;
+        hash += hash << 15;
+        hash.0 as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 += Wrapping(*byte as u32);
+            self.0 += self.0 << 10;
+            self.0 ^= self.0 >> 6;
+        }
+    }
+}
+
+default_for_constant!(OAATHasher32, Wrapping(0));
+seedable_for_constant!(OAATHasher32, seed => Wrapping(seed as u32));
+random_state!(RandomOAATState32, OAATHasher32);
+hash_collections!(OAATHashMap32, OAATHashSet32, OAATHasher32);
+hasher_to_fcn!(
+    /// Provide access to OAATHasher32 in a single call.
+    oaat32,
+    OAATHasher32
+);
+
 // ------------------------------------
 
 #[cfg(test)]
 mod oaat_tests {
     use super::*;
+    use check_seedable;
+    use smhasher_verification;
 
     #[test]
     fn basic() {
@@ -71,6 +124,594 @@ mod oaat_tests {
         assert_eq!(oaat(b"ab"), 30087418617432);
         assert_eq!(oaat(b"abcdefg"), 3103867595652801641);
     }
+
+    #[test]
+    fn reference() {
+        assert_eq!(oaat32(b""), 0);
+        assert_eq!(oaat32(b"a"), 0xca2e9442);
+        assert_eq!(oaat32(b"The quick brown fox jumps over the lazy dog"), 0x519e91f5);
+        // SMHasher's "JenkinsOOAT_perl", perl's one_at_a_time_old.
+        let v = smhasher_verification(4, |key, seed| {
+            let mut hasher = OAATHasher32::new(seed);
+            hasher.write(key);
+            (hasher.finish() as u32).to_le_bytes().to_vec()
+        });
+        assert_eq!(v, 0xEE05869B);
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<OAATHasher>(true);
+        check_seedable::<OAATHasher32>(true);
+    }
+}
+
+// ================================
+// The 1997 Dr. Dobb's article
+
+// The hashes compared in the article, as it describes them, except that
+// the key's bytes are unsigned. The article's versions reduce the hash
+// to the table size themselves, modulo a prime or with a mask; these
+// return the whole hash. Several start from the length of the key;
+// where they can, these Hashers add it in `finish` instead, so that the
+// input can be streamed. Seeds are mixed into the starting hash.
+
+/// The additive hash: the length plus the sum of the bytes. One of the
+/// article's examples of what not to do; anagrams, for instance, collide.
+#[derive(Default)]
+pub struct AdditiveHasher {
+    sum: Wrapping<u32>,
+    len: Wrapping<u32>,
+}
+
+impl Hasher for AdditiveHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.sum + self.len).0 as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.sum += Wrapping(byte as u32);
+        }
+        self.len += Wrapping(bytes.len() as u32);
+    }
+}
+
+impl SeedableHasher for AdditiveHasher {
+    fn with_seed(seed: u64) -> AdditiveHasher {
+        AdditiveHasher {
+            sum: Wrapping(seed as u32),
+            len: Wrapping(0),
+        }
+    }
+}
+
+random_state!(RandomAdditiveState, AdditiveHasher);
+hash_collections!(AdditiveHashMap, AdditiveHashSet, AdditiveHasher);
+hasher_to_fcn!(
+    /// Provide access to AdditiveHasher in a single call.
+    additive,
+    AdditiveHasher
+);
+
+/// The rotating hash: starting from the length, rotate the hash left by
+/// four bits and xor in each byte.
+#[derive(Default)]
+pub struct RotatingHasher {
+    // the hash of the input so far, starting from 0
+    hash: u32,
+    len: u32,
+}
+
+impl Hasher for RotatingHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        // By the time the length is known, it has been rotated once per
+        // byte.
+        let len = self.len.rotate_left(self.len.wrapping_mul(4) % 32);
+        (self.hash ^ len) as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.hash = self.hash.rotate_left(4) ^ byte as u32;
+        }
+        self.len = self.len.wrapping_add(bytes.len() as u32);
+    }
+}
+
+impl SeedableHasher for RotatingHasher {
+    fn with_seed(seed: u64) -> RotatingHasher {
+        RotatingHasher {
+            hash: seed as u32,
+            len: 0,
+        }
+    }
+}
+
+random_state!(RandomRotatingState, RotatingHasher);
+hash_collections!(RotatingHashMap, RotatingHashSet, RotatingHasher);
+hasher_to_fcn!(
+    /// Provide access to RotatingHasher in a single call.
+    rotating,
+    RotatingHasher
+);
+
+/// Bernstein's hash as the article describes it: `33 * hash + byte`,
+/// starting from `level`. See oz::DJB2Hasher for the xor variant and
+/// for more of the article's comments.
+pub struct BernsteinHasher(Wrapping<u32>);
+
+impl BernsteinHasher {
+    /// Start the hash from `level`; Default uses 0.
+    pub fn new(level: u32) -> BernsteinHasher {
+        BernsteinHasher(Wrapping(level))
+    }
+}
+
+impl Hasher for BernsteinHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0 as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0 * Wrapping(33) + Wrapping(byte as u32);
+        }
+    }
+}
+
+default_for_constant!(BernsteinHasher, Wrapping(0));
+seedable_for_constant!(BernsteinHasher, seed => Wrapping(seed as u32));
+random_state!(RandomBernsteinState, BernsteinHasher);
+hash_collections!(BernsteinHashMap, BernsteinHashSet, BernsteinHasher);
+hasher_to_fcn!(
+    /// Provide access to BernsteinHasher in a single call.
+    bernstein,
+    BernsteinHasher
+);
+
+/// Fill the tables for Zobrist and Pearson hashing with a fixed
+/// xorshift32 sequence; the article only asks for random values.
+const fn xorshift32(x: u32) -> u32 {
+    let x = x ^ (x << 13);
+    let x = x ^ (x >> 17);
+    x ^ (x << 5)
+}
+
+/// The number of positions in the Zobrist table; longer keys wrap around
+/// to the start of the table.
+const ZOBRIST_LEN: usize = 64;
+
+const fn zobrist_table() -> [[u32; 256]; ZOBRIST_LEN] {
+    let mut table = [[0u32; 256]; ZOBRIST_LEN];
+    let mut x = 0x2545f491;
+    let mut i = 0;
+    while i < ZOBRIST_LEN {
+        let mut j = 0;
+        while j < 256 {
+            x = xorshift32(x);
+            table[i][j] = x;
+            j += 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+static ZOBRIST_TABLE: [[u32; 256]; ZOBRIST_LEN] = zobrist_table();
+
+/// Zobrist hashing: xor together a random value for each byte at each
+/// position, and the length. Given the random table, it is as good as
+/// hashes get, but the table has to be as long as the longest key;
+/// this one has 64 positions, and later bytes reuse them.
+#[derive(Default)]
+pub struct ZobristHasher {
+    hash: u32,
+    len: usize,
+}
+
+impl Hasher for ZobristHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.hash ^ self.len as u32) as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.hash ^= ZOBRIST_TABLE[self.len % ZOBRIST_LEN][byte as usize];
+            self.len += 1;
+        }
+    }
+}
+
+impl SeedableHasher for ZobristHasher {
+    fn with_seed(seed: u64) -> ZobristHasher {
+        ZobristHasher {
+            hash: seed as u32,
+            len: 0,
+        }
+    }
+}
+
+random_state!(RandomZobristState, ZobristHasher);
+hash_collections!(ZobristHashMap, ZobristHashSet, ZobristHasher);
+hasher_to_fcn!(
+    /// Provide access to ZobristHasher in a single call.
+    zobrist,
+    ZobristHasher
+);
+
+/// A permutation of 0..255, shuffled with Fisher-Yates.
+const fn pearson_table() -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = i as u8;
+        i += 1;
+    }
+    let mut x = 0x9e3779b9;
+    let mut i = 255;
+    while i > 0 {
+        x = xorshift32(x);
+        let j = (x % (i as u32 + 1)) as usize;
+        let t = table[i];
+        table[i] = table[j];
+        table[j] = t;
+        i -= 1;
+    }
+    table
+}
+
+static PEARSON_TABLE: [u8; 256] = pearson_table();
+
+/// Pearson's hash: an 8-bit hash which, starting from the length, looks
+/// up the xor of the hash and each byte in a permutation of 0..255.
+///
+/// The length seeds the hash, so the Hasher keeps its input until
+/// `finish`.
+#[derive(Default)]
+pub struct PearsonHasher {
+    // xored into the starting hash; only its low byte matters
+    seed: u8,
+    // the input so far
+    buf: Vec<u8>,
+}
+
+impl SeedableHasher for PearsonHasher {
+    fn with_seed(seed: u64) -> PearsonHasher {
+        PearsonHasher {
+            seed: seed as u8,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl Hasher for PearsonHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut hash = self.buf.len() as u8 ^ self.seed;
+        for &byte in &self.buf {
+            hash = PEARSON_TABLE[(hash ^ byte) as usize];
+        }
+        hash as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+random_state!(RandomPearsonState, PearsonHasher);
+hash_collections!(PearsonHashMap, PearsonHashSet, PearsonHasher);
+hasher_to_fcn!(
+    /// Provide access to PearsonHasher in a single call.
+    pearson,
+    PearsonHasher
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod dobbs_tests {
+    use super::*;
+    use check_seedable;
+    use smhasher_verification;
+
+    // The article's code, with unsigned bytes and without the reduction.
+    fn additive_ref(key: &[u8]) -> u32 {
+        key.iter().fold(key.len() as u32, |hash, &byte| hash + byte as u32)
+    }
+
+    fn rotating_ref(key: &[u8]) -> u32 {
+        key.iter()
+            .fold(key.len() as u32, |hash, &byte| (hash << 4) ^ (hash >> 28) ^ byte as u32)
+    }
+
+    fn zobrist_ref(key: &[u8]) -> u32 {
+        key.iter().enumerate().fold(key.len() as u32, |hash, (i, &byte)| {
+            hash ^ ZOBRIST_TABLE[i % ZOBRIST_LEN][byte as usize]
+        })
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(additive(b""), 0);
+        assert_eq!(additive(b"abc"), 297);
+        assert_eq!(additive(b"cab"), 297);
+        assert_eq!(rotating(b""), 0);
+        assert_eq!(rotating(b"abc"), 0x5743);
+        assert_eq!(bernstein(b""), 0);
+        assert_eq!(bernstein(b"abc"), 108966);
+        assert_eq!(zobrist(b""), 0);
+        assert_eq!(zobrist(b"abc"), 0x09573946);
+        assert_eq!(zobrist(b"Four score and seven years ago"), 0x5149f36d);
+        assert_eq!(pearson(b""), 0);
+        assert_eq!(pearson(b"abc"), 163);
+        assert_eq!(pearson(b"Four score and seven years ago"), 56);
+    }
+
+    #[test]
+    fn references() {
+        let data: Vec<u8> = (0..200u32).map(|i| (i * 71 + 5) as u8).collect();
+        for n in 0..data.len() {
+            let key = &data[..n];
+            assert_eq!(additive(key), additive_ref(key) as u64);
+            assert_eq!(rotating(key), rotating_ref(key) as u64);
+            assert_eq!(zobrist(key), zobrist_ref(key) as u64);
+        }
+    }
+
+    #[test]
+    fn pearson_permutation() {
+        let mut seen = [false; 256];
+        for &x in PEARSON_TABLE.iter() {
+            assert!(!seen[x as usize]);
+            seen[x as usize] = true;
+        }
+        // distinct one-byte keys give distinct hashes
+        let mut hashes: Vec<u64> = (0..=255u8).map(|b| pearson(&[b])).collect();
+        hashes.sort();
+        hashes.dedup();
+        assert_eq!(hashes.len(), 256);
+    }
+
+    #[test]
+    fn verification() {
+        let v = smhasher_verification(4, |key, seed| {
+            let mut hasher = BernsteinHasher::new(seed);
+            hasher.write(key);
+            (hasher.finish() as u32).to_le_bytes().to_vec()
+        });
+        assert_eq!(v, 0xBDB4B640);
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut add = AdditiveHasher::default();
+            let mut rot = RotatingHasher::default();
+            let mut bern = BernsteinHasher::default();
+            let mut zob = ZobristHasher::default();
+            let mut pear = PearsonHasher::default();
+            for chunk in data.chunks(split + 1) {
+                add.write(chunk);
+                rot.write(chunk);
+                bern.write(chunk);
+                zob.write(chunk);
+                pear.write(chunk);
+            }
+            assert_eq!(add.finish(), additive(&data));
+            assert_eq!(rot.finish(), rotating(&data));
+            assert_eq!(bern.finish(), bernstein(&data));
+            assert_eq!(zob.finish(), zobrist(&data));
+            assert_eq!(pear.finish(), pearson(&data));
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<AdditiveHasher>(true);
+        check_seedable::<RotatingHasher>(true);
+        check_seedable::<BernsteinHasher>(true);
+        check_seedable::<ZobristHasher>(true);
+        check_seedable::<PearsonHasher>(true);
+    }
+}
+
+// ================================
+// lookup2
+
+/// Bob Jenkins' lookup2, the "My Hash" of the 1997 article, which
+/// lookup3 replaced. It mixes the key twelve bytes at a time, and adds
+/// the length at the end, reserving the first byte of c for it.
+///
+/// See http://burtleburtle.net/bob/c/lookup2.c.
+pub struct Lookup2Hasher {
+    a: Wrapping<u32>,
+    b: Wrapping<u32>,
+    c: Wrapping<u32>,
+    // unhashed data, for partial blocks
+    buf: [u8; 12],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // total length of the input, which lookup2 adds to c at the end
+    total_len: Wrapping<u32>,
+}
+
+impl SeedableHasher for Lookup2Hasher {
+    /// The seed's low 32 bits are the initval.
+    fn with_seed(seed: u64) -> Lookup2Hasher {
+        Lookup2Hasher::new(seed as u32)
+    }
+}
+
+impl Lookup2Hasher {
+    /// Hash with the given initval; Default uses 0.
+    pub fn new(initval: u32) -> Lookup2Hasher {
+        Lookup2Hasher {
+            // > the golden ratio; an arbitrary value
+            a: Wrapping(0x9e3779b9),
+            b: Wrapping(0x9e3779b9),
+            c: Wrapping(initval),
+            buf: [0; 12],
+            buf_len: 0,
+            total_len: Wrapping(0),
+        }
+    }
+
+    #[inline]
+    fn block(&mut self, block: &[u8]) {
+        self.a += Wrapping(load_int_le!(block, 0, u32));
+        self.b += Wrapping(load_int_le!(block, 4, u32));
+        self.c += Wrapping(load_int_le!(block, 8, u32));
+        mix2(&mut self.a, &mut self.b, &mut self.c);
+    }
+}
+
+impl Default for Lookup2Hasher {
+    fn default() -> Lookup2Hasher {
+        Lookup2Hasher::new(0)
+    }
+}
+
+/// lookup2's mix, which, unlike lookup3's, also serves as the final mix.
+#[inline]
+fn mix2(a: &mut Wrapping<u32>, b: &mut Wrapping<u32>, c: &mut Wrapping<u32>) {
+    *a -= *b;
+    *a -= *c;
+    *a ^= *c >> 13;
+    *b -= *c;
+    *b -= *a;
+    *b ^= *a << 8;
+    *c -= *a;
+    *c -= *b;
+    *c ^= *b >> 13;
+    *a -= *b;
+    *a -= *c;
+    *a ^= *c >> 12;
+    *b -= *c;
+    *b -= *a;
+    *b ^= *a << 16;
+    *c -= *a;
+    *c -= *b;
+    *c ^= *b >> 5;
+    *a -= *b;
+    *a -= *c;
+    *a ^= *c >> 3;
+    *b -= *c;
+    *b -= *a;
+    *b ^= *a << 10;
+    *c -= *a;
+    *c -= *b;
+    *c ^= *b >> 15;
+}
+
+impl Hasher for Lookup2Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let (mut a, mut b, mut c) = (self.a, self.b, self.c);
+        let tail = &self.buf[..self.buf_len];
+        c += self.total_len;
+        a += shift_add(&tail[..tail.len().min(4)]);
+        if tail.len() > 4 {
+            b += shift_add(&tail[4..tail.len().min(8)]);
+        }
+        if tail.len() > 8 {
+            c += shift_add(&tail[8..]) << 8;
+        }
+        mix2(&mut a, &mut b, &mut c);
+        c.0 as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += Wrapping(bytes.len() as u32);
+        let mut bytes = bytes;
+        if self.buf_len > 0 {
+            let fill = (12 - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+            if self.buf_len < 12 {
+                return;
+            }
+            let block = self.buf;
+            self.block(&block);
+            self.buf_len = 0;
+        }
+        let mut blocks = bytes.chunks_exact(12);
+        for block in &mut blocks {
+            self.block(block);
+        }
+        let rest = blocks.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+}
+
+random_state!(RandomLookup2State, Lookup2Hasher);
+hash_collections!(Lookup2HashMap, Lookup2HashSet, Lookup2Hasher);
+hasher_to_fcn!(
+    /// Provide access to Lookup2Hasher in a single call.
+    lookup2,
+    Lookup2Hasher
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod lookup2_tests {
+    use super::*;
+    use check_seedable;
+
+    fn lookup2_with_initval(key: &[u8], initval: u32) -> u64 {
+        let mut hasher = Lookup2Hasher::new(initval);
+        hasher.write(key);
+        hasher.finish()
+    }
+
+    #[test]
+    fn basic() {
+        // Computed with lookup2.c's hash().
+        assert_eq!(lookup2(b""), 0xbd49d10d);
+        assert_eq!(lookup2(b"a"), 0x29eec818);
+        assert_eq!(lookup2(b"abcd"), 0x5ae61fa5);
+        assert_eq!(lookup2(b"abcdefg"), 0xb9e6762c);
+        assert_eq!(lookup2(b"abcdefghijkl"), 0x0b1b3ea5);
+        assert_eq!(lookup2(b"Four score and seven years ago"), 0x50f2424b);
+        assert_eq!(lookup2_with_initval(b"", 1), 0x6ddfb8c9);
+        assert_eq!(lookup2_with_initval(b"abcdefg", 1), 0xc1e53361);
+        assert_eq!(
+            lookup2_with_initval(b"Four score and seven years ago", 1),
+            0x89deae7e
+        );
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut hasher = Lookup2Hasher::default();
+            for chunk in data.chunks(split + 1) {
+                hasher.write(chunk);
+            }
+            assert_eq!(hasher.finish(), lookup2(&data));
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Lookup2Hasher>(true);
+    }
 }
 
 // ================================
@@ -129,7 +770,7 @@ mod oaat_tests {
 /// > beyond 263 keypairs, which is exactly what you'd expect from a
 /// > completely random mapping to 64-bit values.
 ///
-/// This structure implements hashlittle2:
+/// By default, this structure implements hashlittle2:
 ///
 /// > You probably want to use hashlittle(). hashlittle() and
 /// > hashbig() hash byte arrays. hashlittle() is is faster than
@@ -140,21 +781,60 @@ mod oaat_tests {
 /// > implement hashbig2() if you wanted but I haven't bothered
 /// > here.
 ///
+/// hashbig2 is here too, though: `Lookup3Hasher::new(Endian::Big)`. The
+/// two read the key as little- and big-endian words respectively, on any
+/// host, so a hash computed on one machine can be checked on another.
+///
+/// The length of the key seeds the initial state, so nothing can be
+/// mixed until the whole key is known: the Hasher keeps its input until
+/// `finish`, so that any sequence of writes hashes the same as a single
+/// call to hashlittle2 with the concatenated bytes. The input is kept in
+/// a `Vec`, so hashing any non-empty key costs a heap allocation; for
+/// short keys that is most of the time (see the `w32_1` benchmarks).
+///
 /// See http://www.burtleburtle.net/bob/c/lookup3.c.
+#[derive(Default)]
 pub struct Lookup3Hasher {
-    pc: Wrapping<u32>, // primary initval / primary hash
-    pb: Wrapping<u32>, // secondary initval / secondary hash
+    pc: u32, // primary initval
+    pb: u32, // secondary initval
+    endian: Endian,
+    // the input so far
+    buf: Vec<u8>,
+}
+
+impl Lookup3Hasher {
+    /// Hash with hashlittle2 or hashbig2, regardless of the host's byte
+    /// order.
+    pub fn new(endian: Endian) -> Lookup3Hasher {
+        Lookup3Hasher {
+            endian,
+            ..Lookup3Hasher::default()
+        }
+    }
 }
 
-impl Default for Lookup3Hasher {
-    fn default() -> Lookup3Hasher {
+impl SeedableHasher for Lookup3Hasher {
+    /// Hash with hashlittle2, with the seed's low and high 32 bits as
+    /// the primary and secondary initvals.
+    fn with_seed(seed: u64) -> Lookup3Hasher {
         Lookup3Hasher {
-            pc: Wrapping(0),
-            pb: Wrapping(0),
+            pc: seed as u32,
+            pb: (seed >> 32) as u32,
+            ..Lookup3Hasher::default()
         }
     }
 }
 
+/// The byte order lookup3 reads the key's words in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Endian {
+    /// hashlittle, the usual choice; faster on little-endian machines.
+    #[default]
+    Little,
+    /// hashbig; faster on big-endian machines.
+    Big,
+}
+
 #[inline]
 fn rot(x: Wrapping<u32>, k: usize) -> Wrapping<u32> {
     x << k | x >> (32 - k)
@@ -264,7 +944,7 @@ fn final_mix(a: &mut Wrapping<u32>, b: &mut Wrapping<u32>, c: &mut Wrapping<u32>
     *c -= rot(*b, 24);
 }
 
-/// Turn 0-4 bytes into an unsigned 32-bit number.
+/// Turn 0-4 bytes into an unsigned 32-bit number, little-endian.
 #[inline]
 fn shift_add(s: &[u8]) -> Wrapping<u32> {
     Wrapping(match s.len() {
@@ -272,110 +952,171 @@ fn shift_add(s: &[u8]) -> Wrapping<u32> {
         2 => (s[0] as u32) + ((s[1] as u32) << 8),
         3 => (s[0] as u32) + ((s[1] as u32) << 8) + ((s[2] as u32) << 16),
         4 => (s[0] as u32) + ((s[1] as u32) << 8) + ((s[2] as u32) << 16) + ((s[3] as u32) << 24),
-        _ => 0 as u32,
+        _ => 0,
     })
 }
 
+/// Turn 0-4 bytes into an unsigned 32-bit number, big-endian. Like
+/// hashbig, a partial word fills the high bytes.
+#[inline]
+fn shift_add_be(s: &[u8]) -> Wrapping<u32> {
+    Wrapping(
+        s.iter()
+            .enumerate()
+            .fold(0, |acc, (i, &byte)| acc + ((byte as u32) << (24 - 8 * i))),
+    )
+}
+
+/// The number of bytes from `ptr` to the next multiple of `align`; 0 if
+/// `ptr` is aligned.
 #[inline]
 fn offset_to_align<T>(ptr: *const T, align: usize) -> usize {
-    align - (ptr as usize & (align - 1))
+    (align - (ptr as usize & (align - 1))) & (align - 1)
+}
+
+/// Read two little-endian 16-bit halves as a 32-bit number.
+#[inline]
+fn halves_le(chunk: &[u8], i: usize) -> Wrapping<u32> {
+    let lo = load_int_le!(chunk, i, u16) as u32;
+    let hi = load_int_le!(chunk, i + 2, u16) as u32;
+    Wrapping(lo | (hi << 16))
+}
+
+/// hashlittle2 or hashbig2.
+fn hash_bytes(key: &[u8], pc: u32, pb: u32, endian: Endian) -> (u32, u32) {
+    let initial = Wrapping(0xdeadbeefu32) + Wrapping(key.len() as u32) + Wrapping(pc);
+    let mut a: Wrapping<u32> = initial;
+    let mut b: Wrapping<u32> = initial;
+    let mut c: Wrapping<u32> = initial;
+    c += Wrapping(pb);
+    if key.is_empty() {
+        // > zero length strings require no mixing
+        return (c.0, b.0);
+    }
+
+    // All but the last block are mixed; the last 1-12 bytes go to the
+    // final mix.
+    let (body, tail) = key.split_at((key.len() - 1) / 12 * 12);
+    let word = match endian {
+        Endian::Little => shift_add,
+        Endian::Big => shift_add_be,
+    };
+    // As in lookup3.c, read whole words, halves or bytes depending on the
+    // alignment of the key; all three read the same numbers.
+    let little = endian == Endian::Little && cfg!(target_endian = "little");
+    if little && offset_to_align(body.as_ptr(), 4) == 0 {
+        for chunk in body.chunks(12) {
+            a += Wrapping(load_int_le!(chunk, 0, u32));
+            b += Wrapping(load_int_le!(chunk, 4, u32));
+            c += Wrapping(load_int_le!(chunk, 8, u32));
+            mix(&mut a, &mut b, &mut c);
+        }
+    } else if little && offset_to_align(body.as_ptr(), 2) == 0 {
+        for chunk in body.chunks(12) {
+            a += halves_le(chunk, 0);
+            b += halves_le(chunk, 4);
+            c += halves_le(chunk, 8);
+            mix(&mut a, &mut b, &mut c);
+        }
+    } else {
+        // For hashbig, big endian machines and unaligned slices: hash
+        // bytes.
+        for chunk in body.chunks(12) {
+            a += word(&chunk[..4]);
+            b += word(&chunk[4..8]);
+            c += word(&chunk[8..]);
+            mix(&mut a, &mut b, &mut c);
+        }
+    }
+    a += word(&tail[..tail.len().min(4)]);
+    if tail.len() > 4 {
+        b += word(&tail[4..tail.len().min(8)]);
+    }
+    if tail.len() > 8 {
+        c += word(&tail[8..]);
+    }
+    final_mix(&mut a, &mut b, &mut c);
+    (c.0, b.0)
+}
+
+/// lookup3's hashlittle: hash `key`, read as little-endian words, with
+/// `initval`.
+pub fn hashlittle(key: &[u8], initval: u32) -> u32 {
+    hash_bytes(key, initval, 0, Endian::Little).0
+}
+
+/// lookup3's hashlittle2: hash `key` with the initvals `pc` and `pb`,
+/// returning the primary and secondary hashes `(c, b)`. The primary
+/// hash is hashlittle's when `pb` is 0.
+pub fn hashlittle2(key: &[u8], pc: u32, pb: u32) -> (u32, u32) {
+    hash_bytes(key, pc, pb, Endian::Little)
+}
+
+/// lookup3's hashbig: hash `key`, read as big-endian words, with
+/// `initval`.
+pub fn hashbig(key: &[u8], initval: u32) -> u32 {
+    hash_bytes(key, initval, 0, Endian::Big).0
+}
+
+/// hashbig2, the big-endian hashlittle2, which lookup3.c leaves as an
+/// exercise.
+pub fn hashbig2(key: &[u8], pc: u32, pb: u32) -> (u32, u32) {
+    hash_bytes(key, pc, pb, Endian::Big)
+}
+
+/// lookup3's hashword: hash an array of words with `initval`. This is
+/// hashlittle of the words' little-endian bytes, and hashbig of their
+/// big-endian bytes, without the byte shuffling.
+pub fn hashword(key: &[u32], initval: u32) -> u32 {
+    hashword2(key, initval, 0).0
+}
+
+/// lookup3's hashword2: hashword, with two initvals `pc` and `pb`,
+/// returning the primary and secondary hashes `(c, b)`.
+pub fn hashword2(key: &[u32], pc: u32, pb: u32) -> (u32, u32) {
+    // The length is in bytes here, too.
+    let initial = Wrapping(0xdeadbeefu32) + Wrapping((key.len() as u32) << 2) + Wrapping(pc);
+    let mut a: Wrapping<u32> = initial;
+    let mut b: Wrapping<u32> = initial;
+    let mut c: Wrapping<u32> = initial;
+    c += Wrapping(pb);
+    if key.is_empty() {
+        return (c.0, b.0);
+    }
+
+    let (body, tail) = key.split_at((key.len() - 1) / 3 * 3);
+    for chunk in body.chunks(3) {
+        a += Wrapping(chunk[0]);
+        b += Wrapping(chunk[1]);
+        c += Wrapping(chunk[2]);
+        mix(&mut a, &mut b, &mut c);
+    }
+    a += Wrapping(tail[0]);
+    if tail.len() > 1 {
+        b += Wrapping(tail[1]);
+    }
+    if tail.len() > 2 {
+        c += Wrapping(tail[2]);
+    }
+    final_mix(&mut a, &mut b, &mut c);
+    (c.0, b.0)
 }
 
 impl Hasher for Lookup3Hasher {
     #[inline]
     fn finish(&self) -> u64 {
-        (self.pc.0 as u64) + ((self.pb.0 as u64) << 32)
+        let (c, b) = hash_bytes(&self.buf, self.pc, self.pb, self.endian);
+        (c as u64) + ((b as u64) << 32)
     }
 
     #[inline]
     fn write(&mut self, bytes: &[u8]) {
-        if bytes.len() == 0 {
-            return;
-        }
-        let initial = Wrapping(0xdeadbeefu32) + Wrapping(bytes.len() as u32) + self.pc;
-        let mut a: Wrapping<u32> = initial;
-        let mut b: Wrapping<u32> = initial;
-        let mut c: Wrapping<u32> = initial;
-        c += self.pb;
-
-        if cfg!(target_endian = "little") && offset_to_align(bytes.as_ptr(), 4) == 0 {
-            for chunk in bytes.chunks(12) {
-                if chunk.len() == 12 {
-                    // true for all chunks except the last
-                    a += Wrapping(load_int_le!(chunk, 0, u32));
-                    b += Wrapping(load_int_le!(chunk, 4, u32));
-                    c += Wrapping(load_int_le!(chunk, 8, u32));
-                    mix(&mut a, &mut b, &mut c);
-                } else if chunk.len() >= 8 {
-                    a += Wrapping(load_int_le!(chunk, 0, u32));
-                    b += Wrapping(load_int_le!(chunk, 4, u32));
-                    c += shift_add(&chunk[8..]);
-                } else if chunk.len() >= 4 {
-                    a += Wrapping(load_int_le!(chunk, 0, u32));
-                    b += shift_add(&chunk[4..]);
-                } else {
-                    a += shift_add(chunk);
-                }
-            }
-        } else if cfg!(target_endian = "little") && offset_to_align(bytes.as_ptr(), 2) == 0 {
-            for chunk in bytes.chunks(12) {
-                if chunk.len() == 12 {
-                    // true for all chunks except the last
-                    a += Wrapping(load_int_le!(chunk, 0, u16) as u32)
-                        + Wrapping(load_int_le!(chunk, 0, u16) as u32)
-                        << 16;
-                    b += Wrapping(load_int_le!(chunk, 4, u16) as u32)
-                        + Wrapping(load_int_le!(chunk, 6, u16) as u32)
-                        << 16;
-                    c += Wrapping(load_int_le!(chunk, 8, u16) as u32)
-                        + Wrapping(load_int_le!(chunk, 10, u16) as u32)
-                        << 16;
-                    mix(&mut a, &mut b, &mut c);
-                } else if chunk.len() >= 8 {
-                    a += Wrapping(load_int_le!(chunk, 0, u16) as u32)
-                        + Wrapping(load_int_le!(chunk, 0, u16) as u32)
-                        << 16;
-                    b += Wrapping(load_int_le!(chunk, 4, u16) as u32)
-                        + Wrapping(load_int_le!(chunk, 6, u16) as u32)
-                        << 16;
-                    c += shift_add(&chunk[8..]);
-                } else if chunk.len() >= 4 {
-                    a += Wrapping(load_int_le!(chunk, 0, u16) as u32)
-                        + Wrapping(load_int_le!(chunk, 0, u16) as u32)
-                        << 16;
-                    b += shift_add(&chunk[4..]);
-                } else {
-                    a += shift_add(chunk);
-                }
-            }
-        } else {
-            // For big endian machines and unaligned slices: hash bytes.
-            // "You could implement hashbig2() if you wanted but I
-            // haven't bothered here."
-            for chunk in bytes.chunks(12) {
-                if chunk.len() == 12 {
-                    a += shift_add(&chunk[..4]);
-                    b += shift_add(&chunk[4..8]);
-                    c += shift_add(&chunk[8..]);
-                    mix(&mut a, &mut b, &mut c);
-                } else if chunk.len() >= 8 {
-                    a += shift_add(&chunk[..4]);
-                    b += shift_add(&chunk[4..8]);
-                    c += shift_add(&chunk[8..]);
-                } else if chunk.len() >= 4 {
-                    a += shift_add(&chunk[..4]);
-                    b += shift_add(&chunk[4..]);
-                } else {
-                    a += shift_add(chunk);
-                }
-            }
-        }
-        final_mix(&mut a, &mut b, &mut c);
-        self.pb = b;
-        self.pc = c;
+        self.buf.extend_from_slice(bytes);
     }
 }
 
+random_state!(RandomLookup3State, Lookup3Hasher);
+hash_collections!(Lookup3HashMap, Lookup3HashSet, Lookup3Hasher);
 hasher_to_fcn!(
     /// Provide access to Lookup3Hasher in a single call.
     lookup3,
@@ -387,14 +1128,128 @@ hasher_to_fcn!(
 #[cfg(test)]
 mod lookup3_tests {
     use super::*;
+    use check_seedable;
+    use smhasher_verification;
 
     #[test]
     fn basic() {
-        assert_eq!(lookup3(b""), 0);
+        assert_eq!(lookup3(b""), 0xdeadbeefdeadbeef);
         assert_eq!(lookup3(b"a"), 6351843130003064584);
         assert_eq!(lookup3(b"b"), 5351957087540069269);
         assert_eq!(lookup3(b"ab"), 7744397999705663711);
         assert_eq!(lookup3(b"abcd"), 16288908501016938652);
         assert_eq!(lookup3(b"abcdefg"), 6461572128488215717);
+        // a whole last block goes to the final mix
+        assert_eq!(lookup3(b"abcdefghijkl"), 8481701692093692027);
+        assert_eq!(lookup3(b"abcdefghijklmnopqrstuvwxyz"), 9074047459142448573);
+    }
+
+    #[test]
+    fn driver5() {
+        // From lookup3.c's driver5.
+        assert_eq!(hashlittle2(b"", 0, 0), (0xdeadbeef, 0xdeadbeef));
+        assert_eq!(hashlittle2(b"", 0, 0xdeadbeef), (0xbd5b7dde, 0xdeadbeef));
+        assert_eq!(
+            hashlittle2(b"", 0xdeadbeef, 0xdeadbeef),
+            (0x9c093ccd, 0xbd5b7dde)
+        );
+        let four = b"Four score and seven years ago";
+        assert_eq!(hashlittle2(four, 0, 0).0, 0x17770551);
+        assert_eq!(hashlittle2(four, 1, 0).0, 0xcd628161);
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut hasher = Lookup3Hasher::default();
+            for chunk in data.chunks(split + 1) {
+                hasher.write(chunk);
+            }
+            assert_eq!(hasher.finish(), lookup3(&data));
+        }
+        let mut hasher = Lookup3Hasher::default();
+        hasher.write_u32(0x64636261);
+        hasher.write(b"efg");
+        assert_eq!(hasher.finish(), lookup3(b"abcdefg"));
+    }
+
+    #[test]
+    fn big() {
+        // Computed with lookup3.c's hashlittle2 code reading big-endian
+        // words, as hashbig does.
+        assert_eq!(hashbig2(b"", 0, 0), (0xdeadbeef, 0xdeadbeef));
+        assert_eq!(hashbig2(b"a", 0, 0), (0xe4ecaa40, 0xf682e0a7));
+        assert_eq!(hashbig2(b"abcd", 0, 0), (0x9fd986e3, 0x299558db));
+        assert_eq!(hashbig2(b"abcdefg", 0, 0), (0xb3b8d0f3, 0x3f727bab));
+        assert_eq!(hashbig2(b"abcdefghijkl", 0, 0), (0xfa89d110, 0xddf73144));
+        let four = b"Four score and seven years ago";
+        assert_eq!(hashbig2(four, 0, 0), (0x65e759cb, 0xa420682e));
+        assert_eq!(hashbig(four, 1), 0x68acf242);
+        assert_eq!(hashbig(b"abcdefg", 1), 0xb6cfed33);
+
+        let mut hasher = Lookup3Hasher::new(Endian::Big);
+        hasher.write(b"Four score ");
+        hasher.write(b"and seven years ago");
+        assert_eq!(hasher.finish(), 0xa420682e65e759cb);
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(hashword2(&[1, 2, 3, 4, 5], 0, 0), (0x629129c0, 0x07a14812));
+        assert_eq!(hashword(&[], 0), 0xdeadbeef);
+        for n in 0..20 {
+            let words: Vec<u32> = (0..n).map(|i: u32| i.wrapping_mul(0x9e3779b9) + 1).collect();
+            let mut le = Vec::new();
+            let mut be = Vec::new();
+            for word in &words {
+                le.extend_from_slice(&word.to_le_bytes());
+                be.extend_from_slice(&word.to_be_bytes());
+            }
+            assert_eq!(hashword2(&words, n, 7), hashlittle2(&le, n, 7));
+            assert_eq!(hashword2(&words, n, 7), hashbig2(&be, n, 7));
+            assert_eq!(hashword(&words, n), hashlittle(&le, n));
+        }
+    }
+
+    #[test]
+    fn verification() {
+        let v = smhasher_verification(4, |key, seed| hashlittle(key, seed).to_le_bytes().to_vec());
+        assert_eq!(v, 0x3D83917A);
+    }
+
+    #[test]
+    fn alignment() {
+        // Hash the same bytes starting at every offset from an 8-byte
+        // boundary, to take each of the word, half-word and byte paths.
+        let data: Vec<u8> = (0..100u32).map(|i| (i * 101 + 7) as u8).collect();
+        let mut buf = vec![0u8; data.len() + 16];
+        let base = offset_to_align(buf.as_ptr(), 8);
+        for n in 0..data.len() {
+            let expected = hashlittle2(&data[..n], 1, 2);
+            for offset in 0..8 {
+                let start = base + offset;
+                buf[start..start + n].copy_from_slice(&data[..n]);
+                let key = &buf[start..start + n];
+                assert_eq!(hashlittle2(key, 1, 2), expected, "{} bytes at +{}", n, offset);
+                assert_eq!(hashbig2(key, 1, 2), hashbig2(&data[..n], 1, 2));
+            }
+        }
+        // and the paths agree with lookup3.c
+        let four = b"Four score and seven years ago";
+        for offset in 0..8 {
+            let start = base + offset;
+            buf[start..start + four.len()].copy_from_slice(four);
+            assert_eq!(hashlittle(&buf[start..start + four.len()], 0), 0x17770551);
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Lookup3Hasher>(true);
+        let mut hasher = Lookup3Hasher::with_seed(0xdeadbeef_00000001);
+        hasher.write(b"Four score and seven years ago");
+        let (c, b) = hashlittle2(b"Four score and seven years ago", 1, 0xdeadbeef);
+        assert_eq!(hasher.finish(), (c as u64) + ((b as u64) << 32));
     }
 }
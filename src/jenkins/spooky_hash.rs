@@ -2,11 +2,19 @@
 //!
 //! Quoted comments are from http://burtleburtle.net/bob/c/SpookyV2.h or
 //! http://burtleburtle.net/bob/c/SpookyV2.cpp
+//!
+//! SpookyHasher computes SpookyHash V2 by default. V1, the original
+//! release, differs in the short hash, which replaced its running state
+//! with the length before the last bytes, and in the final mixing of
+//! the long hash; `SpookyHasher::with_version` selects it, for
+//! fingerprints computed with V1. The one-shot functions mirror the C++
+//! `SpookyHash::Hash32`, `Hash64` and `Hash128`.
 
 use std::hash::Hasher;
-use std::mem;
 use std::num::Wrapping;
-use std::ptr;
+use std::{mem, ptr};
+
+use {SeedableHasher, SeedableHasher128};
 
 /// number of uint64's in internal state
 const SC_NUM_VARS: usize = 12;
@@ -21,11 +29,6 @@ const SC_BUF_SIZE: usize = 2 * SC_BLOCK_SIZE; // 192
 /// >  * does not need any other special mathematical properties
 const SC_CONST: u64 = 0xdeadbeefdeadbeefu64;
 
-#[inline]
-fn offset_to_align<T>(ptr: *const T, align: usize) -> usize {
-    align - (ptr as usize & (align - 1))
-}
-
 #[inline]
 fn rot64(x: Wrapping<u64>, k: usize) -> Wrapping<u64> {
     x << k | x >> (64 - k)
@@ -43,11 +46,8 @@ fn rot64(x: Wrapping<u64>, k: usize) -> Wrapping<u64> {
 /// >   When run forward or backwards one Mix
 /// > I tried 3 pairs of each; they all differed by at least 212 bits.
 ///
-/// data indices: 0..11
-/// state indices: 0..11
 #[inline]
-fn mix(data: &[Wrapping<u64>], state: &mut [Wrapping<u64>; 12]) {
-    debug_assert!(data.len() >= 12);
+fn mix(data: &[Wrapping<u64>; 12], state: &mut [Wrapping<u64>; 12]) {
     state[0] += data[0];
     state[2] ^= state[10];
     state[11] ^= state[0];
@@ -164,8 +164,25 @@ fn end_partial(state: &mut [Wrapping<u64>; 12]) {
     state[0] = rot64(state[0], 54);
 }
 
+/// The SpookyHash release to compute.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Version {
+    /// SpookyV1, from 2011.
+    V1,
+    /// SpookyV2, from 2012.
+    #[default]
+    V2,
+}
+
 #[inline]
-fn end(data: &[Wrapping<u64>; 12], state: &mut [Wrapping<u64>; 12]) {
+fn end(data: &[Wrapping<u64>; 12], state: &mut [Wrapping<u64>; 12], version: Version) {
+    if version == Version::V1 {
+        mix(data, state);
+        end_partial(state);
+        end_partial(state);
+        end_partial(state);
+        return;
+    }
     state[0] += data[0];
     state[1] += data[1];
     state[2] += data[2];
@@ -196,7 +213,6 @@ fn end(data: &[Wrapping<u64>; 12], state: &mut [Wrapping<u64>; 12]) {
 /// > diffs with diffs defined by either xor or subtraction with
 /// > a base of all zeros plus a counter, or plus another bit,
 /// > or random
-
 #[inline]
 fn short_mix(h: &mut [Wrapping<u64>; 4]) {
     h[2] = rot64(h[2], 50);
@@ -247,7 +263,6 @@ fn short_mix(h: &mut [Wrapping<u64>; 4]) {
 /// > with probability 50 +- .3% (it is probably better than that)
 /// > For every pair of input bits,
 /// > with probability 50 +- .75% (the worst case is approximately that)
-
 #[inline]
 fn short_end(h: &mut [Wrapping<u64>; 4]) {
     h[3] ^= h[2];
@@ -285,105 +300,65 @@ fn short_end(h: &mut [Wrapping<u64>; 4]) {
     h[1] += h[0];
 }
 
+/// Read a whole block of little-endian words.
+#[inline]
+fn load_block(bytes: &[u8]) -> [Wrapping<u64>; SC_NUM_VARS] {
+    debug_assert!(bytes.len() >= SC_BLOCK_SIZE);
+    let mut block = [Wrapping(0u64); SC_NUM_VARS];
+    for (i, word) in block.iter_mut().enumerate() {
+        *word = Wrapping(load_int_le!(bytes, 8 * i, u64));
+    }
+    block
+}
+
+/// Read 0-8 bytes as a little-endian number.
+#[inline]
+fn load_partial(bytes: &[u8]) -> Wrapping<u64> {
+    Wrapping(bytes.iter().rev().fold(0, |acc, &byte| (acc << 8) | byte as u64))
+}
+
 /// > Short is used for messages under 192 bytes in length. Short
 /// > has a low startup cost, the normal mode is good for long
 /// > keys, the cost crossover is at about 192 bytes. The two modes
 /// > were held to the same quality bar.
-fn short(message: &[u8], length: usize, hash1: &mut Wrapping<u64>, hash2: &mut Wrapping<u64>) {
-    debug_assert!(length <= SC_BUF_SIZE);
-    let mut h: [Wrapping<u64>; 4] = [*hash1, *hash2, Wrapping(SC_CONST), Wrapping(SC_CONST)];
-    for chunk in message.chunks(4 * mem::size_of::<u64>()) {
-        if chunk.len() == 4 * mem::size_of::<u64>() {
-            let mut buf = [Wrapping(0u64); 4 * mem::size_of::<u64>()];
-            let words: &[Wrapping<u64>];
-            if offset_to_align(chunk.as_ptr(), 4) == 0 {
-                words = unsafe { mem::transmute(chunk) };
-            } else {
-                unsafe {
-                    ptr::copy_nonoverlapping(
-                        chunk.as_ptr(),
-                        &mut buf as *mut _ as *mut u8,
-                        4 * mem::size_of::<u64>(),
-                    );
-                }
-                words = &buf;
-            }
-            h[2] += words[0];
-            h[3] += words[1];
+fn short(message: &[u8], seed1: u64, seed2: u64, version: Version) -> (u64, u64) {
+    debug_assert!(message.len() < SC_BUF_SIZE);
+    let mut h = [Wrapping(seed1), Wrapping(seed2), Wrapping(SC_CONST), Wrapping(SC_CONST)];
+    let mut rest = message;
+    if message.len() > 15 {
+        // handle all complete sets of 32 bytes
+        let mut chunks = message.chunks_exact(32);
+        for chunk in &mut chunks {
+            h[2] += Wrapping(load_int_le!(chunk, 0, u64));
+            h[3] += Wrapping(load_int_le!(chunk, 8, u64));
             short_mix(&mut h);
-            h[0] += words[2];
-            h[1] += words[3];
-        } else if chunk.len() >= 2 * mem::size_of::<u64>() {
-            let mut buf = [Wrapping(0u64); 2 * mem::size_of::<u64>()];
-            let words: &[Wrapping<u64>];
-            if offset_to_align(message.as_ptr(), 4) == 0 {
-                words = unsafe { mem::transmute(message) };
-            } else {
-                unsafe {
-                    ptr::copy_nonoverlapping(
-                        chunk.as_ptr(),
-                        &mut buf as *mut _ as *mut u8,
-                        2 * mem::size_of::<u64>(),
-                    );
-                }
-                words = &buf;
-            }
-            h[2] += words[0];
-            h[3] += words[1];
+            h[0] += Wrapping(load_int_le!(chunk, 16, u64));
+            h[1] += Wrapping(load_int_le!(chunk, 24, u64));
+        }
+        rest = chunks.remainder();
+        // handle the case of 16+ remaining bytes
+        if rest.len() >= 16 {
+            h[2] += Wrapping(load_int_le!(rest, 0, u64));
+            h[3] += Wrapping(load_int_le!(rest, 8, u64));
             short_mix(&mut h);
-        } else {
-            h[3] += Wrapping(length as u64) << 56;
-            if chunk.len() >= 12 {
-                if chunk.len() > 14 {
-                    h[3] += Wrapping(chunk[14] as u64) << 48;
-                }
-                if chunk.len() > 13 {
-                    h[3] += Wrapping(chunk[13] as u64) << 40;
-                }
-                if chunk.len() > 12 {
-                    h[3] += Wrapping(chunk[12] as u64) << 32;
-                }
-                h[2] += Wrapping(load_int_le!(chunk, 0, u64));
-                h[3] += Wrapping(load_int_le!(chunk, 8, u32) as u64);
-            } else if chunk.len() >= 8 {
-                if chunk.len() > 10 {
-                    h[3] += Wrapping(chunk[10] as u64) << 16;
-                }
-                if chunk.len() > 9 {
-                    h[3] += Wrapping(chunk[9] as u64) << 8;
-                }
-                if chunk.len() > 8 {
-                    h[3] += Wrapping(chunk[8] as u64);
-                }
-                h[2] += Wrapping(load_int_le!(chunk, 0, u64));
-            } else if chunk.len() >= 4 {
-                if chunk.len() > 6 {
-                    h[2] += Wrapping(chunk[6] as u64) << 48;
-                }
-                if chunk.len() > 5 {
-                    h[2] += Wrapping(chunk[5] as u64) << 40;
-                }
-                if chunk.len() > 4 {
-                    h[2] += Wrapping(chunk[4] as u64) << 32;
-                }
-                h[2] += Wrapping(load_int_le!(chunk, 0, u32) as u64);
-            } else if chunk.len() >= 1 {
-                if chunk.len() > 2 {
-                    h[2] += Wrapping(chunk[2] as u64) << 16;
-                }
-                if chunk.len() > 1 {
-                    h[2] += Wrapping(chunk[1] as u64) << 8;
-                }
-                h[2] += Wrapping(chunk[0] as u64);
-            } else {
-                h[2] += Wrapping(SC_CONST);
-                h[3] += Wrapping(SC_CONST);
-            }
+            rest = &rest[16..];
         }
     }
+    // handle the last 0..15 bytes, and the length
+    match version {
+        Version::V1 => h[3] = Wrapping(message.len() as u64) << 56,
+        Version::V2 => h[3] += Wrapping(message.len() as u64) << 56,
+    }
+    if rest.is_empty() {
+        h[2] += Wrapping(SC_CONST);
+        h[3] += Wrapping(SC_CONST);
+    } else {
+        let (low, high) = rest.split_at(rest.len().min(8));
+        h[2] += load_partial(low);
+        h[3] += load_partial(high);
+    }
     short_end(&mut h);
-    *hash1 = h[0];
-    *hash2 = h[1];
+    (h[0].0, h[1].0)
 }
 
 /// From http://burtleburtle.net/bob/hash/spooky.html
@@ -394,70 +369,59 @@ fn short(message: &[u8], length: usize, hash1: &mut Wrapping<u64>, hash2: &mut W
 /// > keys hash in 3 bytes per cycle, short keys take about 1 byte per cycle, and there is a 30 cycle
 /// > startup cost. Keys can be supplied in fragments. The function allows a 128-bit seed. It's named
 /// > SpookyHash because it was released on Halloween.
+#[derive(Clone)]
 pub struct SpookyHasher {
-    // unhashed data, for partial messages; 2 * m_state, in bytes
-    pub m_data: [u8; SC_BUF_SIZE],
+    // unhashed data, for partial messages; 2 * state, in bytes
+    data: [u8; SC_BUF_SIZE],
     // internal state of the hash
-    pub m_state: [Wrapping<u64>; SC_NUM_VARS],
+    state: [Wrapping<u64>; SC_NUM_VARS],
     // total length of the input so far
-    pub m_length: usize,
-    // length of unhashed data stashed in m_data
-    pub m_remainder: usize,
+    length: usize,
+    // length of unhashed data stashed in data
+    remainder: usize,
+    version: Version,
 }
 
 impl SpookyHasher {
+    /// Hash with the 128-bit seed (seed1, seed2).
     pub fn new(seed1: u64, seed2: u64) -> SpookyHasher {
-        let mut sh = SpookyHasher {
-            m_data: [0; SC_BUF_SIZE],
-            m_state: [Wrapping(0u64); SC_NUM_VARS],
-            m_length: 0,
-            m_remainder: 0,
-        };
-        sh.m_state[0] = Wrapping(seed1);
-        sh.m_state[3] = Wrapping(seed1);
-        sh.m_state[6] = Wrapping(seed1);
-        sh.m_state[9] = Wrapping(seed1);
-        sh.m_state[1] = Wrapping(seed2);
-        sh.m_state[4] = Wrapping(seed2);
-        sh.m_state[7] = Wrapping(seed2);
-        sh.m_state[10] = Wrapping(seed2);
-        sh.m_state[2] = Wrapping(SC_CONST);
-        sh.m_state[5] = Wrapping(SC_CONST);
-        sh.m_state[8] = Wrapping(SC_CONST);
-        sh.m_state[11] = Wrapping(SC_CONST);
-        sh
+        SpookyHasher::with_version(seed1, seed2, Version::V2)
     }
 
-    pub fn finish128(&self) -> (u64, u64) {
-        if self.m_length < SC_BUF_SIZE {
-            let mut hash1 = self.m_state[0];
-            let mut hash2 = self.m_state[1];
-            short(&self.m_data, self.m_length, &mut hash1, &mut hash2);
-            return (hash1.0, hash2.0);
+    /// Hash with the 128-bit seed (seed1, seed2), computing the given
+    /// version of SpookyHash.
+    pub fn with_version(seed1: u64, seed2: u64, version: Version) -> SpookyHasher {
+        let (seed1, seed2, sc_const) = (Wrapping(seed1), Wrapping(seed2), Wrapping(SC_CONST));
+        SpookyHasher {
+            data: [0; SC_BUF_SIZE],
+            state: [
+                seed1, seed2, sc_const, seed1, seed2, sc_const, seed1, seed2, sc_const, seed1,
+                seed2, sc_const,
+            ],
+            length: 0,
+            remainder: 0,
+            version,
         }
-        let mut state = self.m_state;
-        let mut remainder = self.m_remainder;
-        let mut processed = 0;
-        if self.m_remainder >= SC_BLOCK_SIZE {
-            let data = unsafe { mem::transmute::<&[u8], &[Wrapping<u64>]>(&self.m_data) };
-            mix(data, &mut state);
-            processed = SC_BLOCK_SIZE;
-            remainder -= SC_BLOCK_SIZE;
+    }
+
+    /// The 128-bit hash of the input so far, as (hash1, hash2).
+    pub fn finish128(&self) -> (u64, u64) {
+        if self.length < SC_BUF_SIZE {
+            let (seed1, seed2) = (self.state[0].0, self.state[1].0);
+            return short(&self.data[..self.length], seed1, seed2, self.version);
         }
-        let mut data = [Wrapping(0u64); SC_NUM_VARS];
-        unsafe {
-            ptr::copy_nonoverlapping::<u8>(
-                (&self.m_data as &_ as *const u8).offset(processed as isize),
-                data.as_mut_ptr() as *mut u8,
-                remainder,
-            );
-            ptr::write_bytes(
-                (data.as_mut_ptr() as *mut u8).offset((SC_BLOCK_SIZE - 1) as isize),
-                remainder as u8,
-                1,
-            );
+        let mut state = self.state;
+        let mut data = &self.data[..self.remainder];
+        if data.len() >= SC_BLOCK_SIZE {
+            // data can contain two blocks; handle any whole first block
+            mix(&load_block(data), &mut state);
+            data = &data[SC_BLOCK_SIZE..];
         }
-        end(&data, &mut state);
+        // mix in the last partial block, and the length mod SC_BLOCK_SIZE
+        let mut last = [0u8; SC_BLOCK_SIZE];
+        last[..data.len()].copy_from_slice(data);
+        last[SC_BLOCK_SIZE - 1] = data.len() as u8;
+        end(&load_block(&last), &mut state, self.version);
         (state[0].0, state[1].0)
     }
 }
@@ -468,6 +432,19 @@ impl Default for SpookyHasher {
     }
 }
 
+impl SeedableHasher for SpookyHasher {
+    /// Like `hash64`, use the seed for both halves of the 128-bit seed.
+    fn with_seed(seed: u64) -> SpookyHasher {
+        SpookyHasher::new(seed, seed)
+    }
+}
+
+impl SeedableHasher128 for SpookyHasher {
+    fn with_seed128(seed: (u64, u64)) -> SpookyHasher {
+        SpookyHasher::new(seed.0, seed.1)
+    }
+}
+
 impl Hasher for SpookyHasher {
     #[inline]
     fn finish(&self) -> u64 {
@@ -476,94 +453,239 @@ impl Hasher for SpookyHasher {
 
     #[inline]
     fn write(&mut self, bytes: &[u8]) {
-        let new_length = self.m_remainder + bytes.len();
+        let new_length = self.remainder + bytes.len();
+        self.length += bytes.len();
         // if the fragment is too short, store it for later
         if new_length < SC_BUF_SIZE {
-            unsafe {
-                ptr::copy_nonoverlapping(
-                    bytes.as_ptr(),
-                    (self.m_data.as_mut_ptr() as *mut u8).offset(self.m_remainder as isize),
-                    bytes.len(),
-                );
-            }
-            self.m_length += bytes.len();
-            self.m_remainder = new_length;
+            self.data[self.remainder..new_length].copy_from_slice(bytes);
+            self.remainder = new_length;
             return;
         }
-        self.m_length += bytes.len();
-        let mut processed = 0;
+        let mut bytes = bytes;
         // if we've got anything stuffed away, use it now
-        if self.m_remainder > 0 {
-            // add the prefix of bytes to m_data
-            processed = SC_BUF_SIZE - self.m_remainder;
-            println!("{}", processed);
-            unsafe {
-                ptr::copy_nonoverlapping(
-                    bytes.as_ptr(),
-                    (self.m_data.as_mut_ptr() as *mut u8).offset(self.m_remainder as isize),
-                    processed,
-                );
-            }
-            let data: &[Wrapping<u64>] =
-                unsafe { mem::transmute::<&[u8], &[Wrapping<u64>]>(&self.m_data) };
-            println!("m_data {} data {}", self.m_data.len(), data.len());
-            mix(&data, &mut self.m_state);
-            mix(&data[SC_NUM_VARS..], &mut self.m_state);
-            self.m_remainder = 0;
+        if self.remainder > 0 {
+            let prefix = SC_BUF_SIZE - self.remainder;
+            self.data[self.remainder..].copy_from_slice(&bytes[..prefix]);
+            mix(&load_block(&self.data), &mut self.state);
+            mix(&load_block(&self.data[SC_BLOCK_SIZE..]), &mut self.state);
+            bytes = &bytes[prefix..];
         }
-        // process the rest of the bytes
-        for chunk in bytes[processed..].chunks(SC_BLOCK_SIZE) {
-            if chunk.len() == SC_BLOCK_SIZE {
-                // handle whole blocks of SC_BLOCK_SIZE bytes
-                if offset_to_align(&chunk, 8) == 0 {
-                    let mut data: &[Wrapping<u64>] =
-                        unsafe { mem::transmute::<&[u8], &[Wrapping<u64>]>(chunk) };
-                    mix(&data, &mut self.m_state);
-                } else {
-                    unsafe {
-                        ptr::copy_nonoverlapping(
-                            chunk.as_ptr(),
-                            self.m_data.as_mut_ptr() as *mut u8,
-                            SC_BLOCK_SIZE,
-                        );
-                    }
-                    let mut data: &[Wrapping<u64>] =
-                        unsafe { mem::transmute::<&[u8], &[Wrapping<u64>]>(&self.m_data) };
-                    mix(&data, &mut self.m_state);
-                }
-            } else {
-                // stuff away the last few bytes
-                unsafe {
-                    ptr::copy_nonoverlapping(
-                        chunk.as_ptr(),
-                        self.m_data.as_mut_ptr() as *mut u8,
-                        chunk.len(),
-                    );
-                }
-                self.m_remainder = chunk.len();
-            }
+        // handle all whole blocks of SC_BLOCK_SIZE bytes
+        let mut blocks = bytes.chunks_exact(SC_BLOCK_SIZE);
+        for block in &mut blocks {
+            mix(&load_block(block), &mut self.state);
         }
+        // stuff away the last few bytes
+        let rest = blocks.remainder();
+        self.data[..rest.len()].copy_from_slice(rest);
+        self.remainder = rest.len();
     }
 }
 
+random_state!(RandomSpookyState, SpookyHasher, 128);
+hash_collections!(SpookyHashMap, SpookyHashSet, SpookyHasher);
 hasher_to_fcn!(
-    /// Provide access to Lookup3Hasher in a single call.
+    /// Provide access to SpookyHasher in a single call.
     spooky,
     SpookyHasher
 );
 
+/// SpookyHash V2 of `message` with the 128-bit seed (seed1, seed2), as
+/// (hash1, hash2).
+pub fn hash128(message: &[u8], seed1: u64, seed2: u64) -> (u64, u64) {
+    hash128_with_version(message, seed1, seed2, Version::V2)
+}
+
+/// SpookyHash V2 of `message`, truncated to 64 bits, with `seed` as both
+/// halves of the seed.
+pub fn hash64(message: &[u8], seed: u64) -> u64 {
+    hash128(message, seed, seed).0
+}
+
+/// SpookyHash V2 of `message`, truncated to 32 bits, with `seed` as both
+/// halves of the seed.
+pub fn hash32(message: &[u8], seed: u32) -> u32 {
+    hash128(message, seed as u64, seed as u64).0 as u32
+}
+
+/// As hash128, for SpookyHash V1.
+pub fn hash128_v1(message: &[u8], seed1: u64, seed2: u64) -> (u64, u64) {
+    hash128_with_version(message, seed1, seed2, Version::V1)
+}
+
+/// As hash64, for SpookyHash V1.
+pub fn hash64_v1(message: &[u8], seed: u64) -> u64 {
+    hash128_v1(message, seed, seed).0
+}
+
+/// As hash32, for SpookyHash V1.
+pub fn hash32_v1(message: &[u8], seed: u32) -> u32 {
+    hash128_v1(message, seed as u64, seed as u64).0 as u32
+}
+
+fn hash128_with_version(message: &[u8], seed1: u64, seed2: u64, version: Version) -> (u64, u64) {
+    let mut hasher = SpookyHasher::with_version(seed1, seed2, version);
+    hasher.write(message);
+    hasher.finish128()
+}
+
 #[cfg(test)]
 mod spookyhash_test {
     use super::*;
+    use {check_seedable, check_seedable128, smhasher_verification};
+
+    // 0, 3, 10, 17, ... as bytes
+    fn data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 7 + 3) as u8).collect()
+    }
+
+    fn spooky128(key: &[u8], seed1: u64, seed2: u64) -> (u64, u64) {
+        hash128(key, seed1, seed2)
+    }
 
     #[test]
     fn basic() {
-        assert_eq!(spooky(b""), 13905049616862401802);
-        assert_eq!(spooky(b"a"), 16932945748884078726);
-        assert_eq!(spooky(b"b"), 5781063613870495197);
-        assert_eq!(spooky(b"ab"), 13849109452443161137);
-        assert_eq!(spooky(b"abcd"), 4142038200967391753);
-        assert_eq!(spooky(b"abcdefg"), 2761526316938866980);
-        assert_eq!(spooky(b"abcdefghijklmnopqrstuvwxyz"), 16192181224158463141);
+        // Computed with SpookyV2.cpp.
+        assert_eq!(spooky(b""), 2533000996631939353);
+        assert_eq!(spooky(b"a"), 1878143507080399293);
+        assert_eq!(spooky(b"b"), 13471851842775403689);
+        assert_eq!(spooky(b"ab"), 18004184790028912911);
+        assert_eq!(spooky(b"abcd"), 6660178300034425268);
+        assert_eq!(spooky(b"abcdefg"), 16227871758974952830);
+        assert_eq!(spooky(b"abcdefghijklmnopqrstuvwxyz"), 12198529059602199121);
+        assert_eq!(
+            spooky128(b"abcdefghijklmnopqrstuvwxyz", 0, 0),
+            (12198529059602199121, 18342904837618158991)
+        );
+    }
+
+    #[test]
+    fn long() {
+        // Around the short and long hashes' block sizes.
+        let expected = [
+            (191, 0x5a8b556721e70004, 0xbfbe8ba61a7acb43),
+            (192, 0x4bd5231fc1b3b9cf, 0xf70d440762d6f476),
+            (193, 0x898f9ebe5674b3c2, 0xe9529fbaf0941aa2),
+            (200, 0x46897e1d30e09f68, 0x0255641f19d14a6a),
+            (287, 0xef60b12696bcb0ec, 0x4b891c4440f99a7c),
+            (288, 0x70a2ef669478aefb, 0x338f2a05aa705160),
+            (300, 0x5fed2a2acbdfb6a4, 0x11142dfa3d3e2a8c),
+            (383, 0x47ee214bcee6a16d, 0xe779095b19912d2e),
+            (384, 0x6ae62d3d99a56f77, 0xff469e686f266026),
+            (1000, 0x85072d3218a51b05, 0x2412672ca9b5bf48),
+            (4096, 0x9b507eb8b115bb10, 0x1bd843edaa6e2e19),
+        ];
+        let data = data(4096);
+        for &(len, hash1, hash2) in expected.iter() {
+            assert_eq!(spooky128(&data[..len], 0, 0), (hash1, hash2), "{} bytes", len);
+        }
+        assert_eq!(
+            spooky128(&data[..1000], 1, 2),
+            (0xa524941a45826695, 0xf3e6c50d7af3486f)
+        );
+    }
+
+    #[test]
+    fn v1() {
+        // Computed with SpookyV1 (Spooky.cpp in SMHasher).
+        assert_eq!(hash128_v1(b"", 0, 0), (8819735582944171500, 15538147095851851855));
+        assert_eq!(hash64_v1(b"a", 0), 15827421057535796977);
+        assert_eq!(hash64_v1(b"abcdefg", 0), 1147919704542617658);
+        assert_eq!(
+            hash128_v1(b"abcdefghijklmnopqrstuvwxyz", 0, 0),
+            (2442917713395211452, 2325071115118013080)
+        );
+        let expected = [
+            (191, 0xce41b18e414591ef, 0xb9663d1568aa23bd),
+            (192, 0x914880cac51c3492, 0x219c469bc7e32e4a),
+            (193, 0xcb8591f5d03cf52d, 0x87ddafd4aecd0564),
+            (287, 0x1ee486da42b89301, 0xec12ac0c45dc152f),
+            (288, 0xde812664ae59c96f, 0x316a0f311bca6a86),
+            (384, 0xad6f191c7354923f, 0x3c9c8322cfa9d607),
+            (4096, 0x6eccdc7da02b7159, 0x6f42bd8c37d49e7c),
+        ];
+        let data = data(4096);
+        for &(len, hash1, hash2) in expected.iter() {
+            assert_eq!(hash128_v1(&data[..len], 0, 0), (hash1, hash2), "{} bytes", len);
+        }
+        assert_eq!(
+            hash128_v1(&data[..1000], 1, 2),
+            (0x83cc95cb2d1b267e, 0x77d991ed17794fe6)
+        );
+    }
+
+    fn bytes128((hash1, hash2): (u64, u64)) -> Vec<u8> {
+        let mut bytes = hash1.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&hash2.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn verification() {
+        let v32 = smhasher_verification(4, |key, seed| hash32(key, seed).to_le_bytes().to_vec());
+        assert_eq!(v32, 0xA48BE265);
+        let v64 = smhasher_verification(8, |key, seed| {
+            hash64(key, seed as u64).to_le_bytes().to_vec()
+        });
+        assert_eq!(v64, 0x972C4BDC);
+        let v128 = smhasher_verification(16, |key, seed| {
+            bytes128(hash128(key, seed as u64, seed as u64))
+        });
+        assert_eq!(v128, 0x893CFCBE);
+    }
+
+    #[test]
+    fn verification_v1() {
+        let v32 = smhasher_verification(4, |key, seed| hash32_v1(key, seed).to_le_bytes().to_vec());
+        assert_eq!(v32, 0x3F798BBB);
+        let v64 = smhasher_verification(8, |key, seed| {
+            hash64_v1(key, seed as u64).to_le_bytes().to_vec()
+        });
+        assert_eq!(v64, 0xA7F955F1);
+        let v128 = smhasher_verification(16, |key, seed| {
+            bytes128(hash128_v1(key, seed as u64, seed as u64))
+        });
+        assert_eq!(v128, 0x8D263080);
+    }
+
+    #[test]
+    fn fragments() {
+        let data = data(1000);
+        for &len in [0, 1, 31, 191, 192, 193, 383, 384, 385, 1000].iter() {
+            let whole = spooky128(&data[..len], 1, 2);
+            let whole_v1 = hash128_v1(&data[..len], 1, 2);
+            for size in 1..=200 {
+                let mut hasher = SpookyHasher::new(1, 2);
+                let mut hasher_v1 = SpookyHasher::with_version(1, 2, Version::V1);
+                for chunk in data[..len].chunks(size) {
+                    hasher.write(chunk);
+                    hasher_v1.write(chunk);
+                }
+                assert_eq!(hasher.finish128(), whole, "{} bytes in {}s", len, size);
+                assert_eq!(hasher_v1.finish128(), whole_v1, "{} bytes in {}s", len, size);
+            }
+        }
+    }
+
+    #[test]
+    fn clone() {
+        let data = data(500);
+        let mut hasher = SpookyHasher::default();
+        hasher.write(&data[..250]);
+        let mut copy = hasher.clone();
+        hasher.write(&data[250..]);
+        copy.write(&data[250..]);
+        assert_eq!(hasher.finish128(), copy.finish128());
+        assert_eq!(hasher.finish(), spooky(&data));
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<SpookyHasher>(true);
+        check_seedable128::<SpookyHasher>();
+        let data = data(100);
+        let mut hasher = SpookyHasher::with_seed(42);
+        hasher.write(&data);
+        assert_eq!(hasher.finish(), hash64(&data, 42));
     }
 }
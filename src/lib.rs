@@ -73,7 +73,8 @@
 //! - `std::hash::BuildHasherDefault`, which can create instances of any Hasher implementation that
 //!   also implements the Default trait.
 //!
-//! All of the Hashers in this collection also implement Default.
+//! All of the Hashers in this collection also implement Default, and
+//! `SeedableHasher`, whose `with_seed` creates a Hasher from a 64-bit seed.
 //!
 //! ```rust
 //! use std::collections::HashMap;
@@ -89,6 +90,24 @@
 //! assert_eq!(map.get(&1), Some(&2));
 //! ```
 //!
+//! ## Random seeds
+//!
+//! BuildHasherDefault gives every map the same hash function. `RandomSeedState`, like
+//! RandomState, instead seeds the Hashers it builds with a random seed drawn when it is created,
+//! which makes it much harder to pick keys that collide. Each module names the states for its
+//! Hashers, such as `jenkins::spooky_hash::RandomSpookyState` or `fnv::RandomFNV1aState64`.
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! use hashers::jenkins::spooky_hash::RandomSpookyState;
+//!
+//! let mut map = HashMap::with_hasher(RandomSpookyState::new());
+//!
+//! map.insert(1, 2);
+//! assert_eq!(map.get(&1), Some(&2));
+//! ```
+//!
 //! ## Using types to specify what Hasher to use
 //!
 //! As an alternative, HashMap has three type-level parameters: the type of keys, the type of
@@ -114,6 +133,21 @@
 //! assert_eq!(map.get(&1), Some(&2));
 //! ```
 //!
+//! Each module also names a HashMap and a HashSet for each of its Hashers, so that the map
+//! above is `fnv::FNV1aHashMap64<i32, i32>` (or `fnv::FnvHashMap<i32, i32>`). With the
+//! `HashMapExt` and `HashSetExt` traits in scope, those types have `new` and `with_capacity`
+//! constructors.
+//!
+//! ```rust
+//! use hashers::HashMapExt;
+//! use hashers::jenkins::SpookyHashMap;
+//!
+//! let mut map = SpookyHashMap::new();
+//!
+//! map.insert(1, 2);
+//! assert_eq!(map.get(&1), Some(&2));
+//! ```
+//!
 //! A more complicated example is the anagrams-hashmap.rs example program included with this
 //! module.
 //!
@@ -127,7 +161,26 @@
 //! - Rust's builtin DefaultHasher (SIP 1-3?) (default)
 //! - https://github.com/cbreeden/fxhash A fast, non-secure, hashing algorithm derived from an
 //!   internal hasher in FireFox. (fx_hash)
-//! - http://www.isthe.com/chongo/tech/comp/fnv/ The Fowler/Noll/Vo hash algorithm. (fnv)
+//! - https://github.com/google/highwayhash Google's keyed HighwayHash (portable version), with 64-,
+//!   128- and 256-bit results. (highway)
+//! - http://www.isthe.com/chongo/tech/comp/fnv/ The Fowler/Noll/Vo hash algorithm: FNV-1a and FNV-1
+//!   from 32 to 1024 bits, and the historic FNV-0. (fnv)
+//! - https://github.com/aappleby/smhasher Austin Appleby's MurmurHash3, x86_32, x86_128 and
+//!   x64_128, and the legacy MurmurHash2, MurmurHash64A and MurmurHash64B. (murmur)
+//! - The CRC-32 (IEEE), CRC-32C (Castagnoli) and CRC-64 (ECMA-182/XZ) checksums, which are not
+//!   good hash table hashes. (crc)
+//! - https://tools.ietf.org/html/rfc1950 The Adler-32 checksum of zlib, and the Fletcher-16, -32 and
+//!   -64 checksums. (checksum)
+//! - https://github.com/google/cityhash and https://github.com/google/farmhash Google's CityHash64
+//!   and CityHash128 (v1.1), and FarmHash's Hash64 (farmhashxo), Hash64WithSeed (farmhashna's, as
+//!   in the C++ and Go libraries), Fingerprint64 and Fingerprint128. (google)
+//! - https://github.com/veorq/SipHash Aumasson and Bernstein's keyed SipHash-2-4 and SipHash-1-3,
+//!   with 64- and 128-bit outputs, and HalfSipHash. (sip)
+//! - https://github.com/wangyi-fudan/wyhash and https://github.com/Nicoshev/rapidhash wyhash
+//!   (final4.2) and rapidhash (V3), with the wyrand generator. (wy)
+//! - https://github.com/Cyan4973/xxHash Yann Collet's xxHash, 32- and 64-bit. (xxhash)
+//! - https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md XXH3, with 64- and 128-bit
+//!   results. (xxhash::xxh3)
 //! - Two "null" hashers: NullHasher returns 0 for all inputs and PassThroughHasher returns the
 //!   last 8 bytes of the data.
 //!
@@ -187,6 +240,12 @@
 
 extern crate fxhash;
 
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::marker::PhantomData;
+
 // ====================================
 // Utilities
 
@@ -244,6 +303,37 @@ macro_rules! default_for_constant {
 
 }
 
+// Create an implementation of SeedableHasher for a simple type whose
+// initial value is computed from the seed.
+macro_rules! seedable_for_constant {
+
+    ($name:ident, $seed:ident => $init:expr) => {
+        impl ::SeedableHasher for $name {
+            #[inline]
+            fn with_seed($seed: u64) -> $name {
+                $name($init)
+            }
+        }
+    };
+
+}
+
+// Name a RandomSeedState (or RandomSeedState128, given "128") for a
+// SeedableHasher.
+macro_rules! random_state {
+
+    ($name:ident, $hasher:ident) => {
+        #[doc = concat!("A `RandomSeedState` building `", stringify!($hasher), "`s.")]
+        pub type $name = ::RandomSeedState<$hasher>;
+    };
+
+    ($name:ident, $hasher:ident, 128) => {
+        #[doc = concat!("A `RandomSeedState128` building `", stringify!($hasher), "`s.")]
+        pub type $name = ::RandomSeedState128<$hasher>;
+    };
+
+}
+
 // Given a Hasher, create a single-use hash function.
 macro_rules! hasher_to_fcn {
 
@@ -259,27 +349,455 @@ macro_rules! hasher_to_fcn {
 
 }
 
+// Name a HashMap and a HashSet using a Hasher, built by BuildHasherDefault.
+macro_rules! hash_collections {
+
+    ($map:ident, $set:ident, $hasher:ident) => {
+        #[doc = concat!("A `HashMap` using `", stringify!($hasher), "`.")]
+        pub type $map<K, V> =
+            ::std::collections::HashMap<K, V, ::std::hash::BuildHasherDefault<$hasher>>;
+        #[doc = concat!("A `HashSet` using `", stringify!($hasher), "`.")]
+        pub type $set<T> = ::std::collections::HashSet<T, ::std::hash::BuildHasherDefault<$hasher>>;
+    };
+
+}
+
+/// Compute the SMHasher verification code for a hash function.
+///
+/// Keys of the form {}, {0}, {0, 1}, ... {0, ..., 254} are hashed using 256 - n as the seed, the
+/// results are concatenated and hashed again with a seed of 0, and the first four bytes of that
+/// are read as a little-endian integer. The expected values for many hash functions are listed in
+/// https://github.com/aappleby/smhasher/blob/master/src/main.cpp.
+///
+/// `hash` takes a key and a seed and returns the hash as bytes, in the order the reference
+/// implementation writes them.
+#[cfg(test)]
+fn smhasher_verification<F>(hashbytes: usize, hash: F) -> u32
+where
+    F: Fn(&[u8], u32) -> Vec<u8>,
+{
+    let key: Vec<u8> = (0..256).map(|i| i as u8).collect();
+    let mut hashes = Vec::with_capacity(hashbytes * 256);
+    for i in 0..256 {
+        let h = hash(&key[..i], 256 - i as u32);
+        assert_eq!(h.len(), hashbytes);
+        hashes.extend_from_slice(&h);
+    }
+    let last = hash(&hashes, 0);
+    (last[0] as u32) | (last[1] as u32) << 8 | (last[2] as u32) << 16 | (last[3] as u32) << 24
+}
+
+// ====================================
+// Seeding
+
+/// A Hasher that can be created from a seed.
+///
+/// Each seed gives a different hash function, which is what Bloom
+/// filters, cuckoo hashing and rehashing after too many collisions need.
+/// Where an algorithm defines a seed, `with_seed` passes it through,
+/// truncated to the algorithm's seed size if that is smaller. Otherwise
+/// the seed is mixed into the Hasher's initial state, and `with_seed(0)`
+/// gives the same Hasher as `Default`. Each type's documentation says
+/// which it does.
+///
+/// A seed does not make a weak hash function any stronger: the
+/// functions for different seeds are distinct, but not necessarily
+/// independent.
+///
+/// ```rust
+/// use std::hash::Hasher;
+///
+/// use hashers::SeedableHasher;
+/// use hashers::fnv::FNV1aHasher64;
+///
+/// let mut h1 = FNV1aHasher64::with_seed(1);
+/// let mut h2 = FNV1aHasher64::with_seed(2);
+/// h1.write(b"hello");
+/// h2.write(b"hello");
+/// assert_ne!(h1.finish(), h2.finish());
+/// ```
+pub trait SeedableHasher: Hasher {
+    /// Create a Hasher from a 64-bit seed.
+    fn with_seed(seed: u64) -> Self;
+}
+
+/// A SeedableHasher whose algorithm takes a seed (or key) of 128 bits
+/// or more.
+pub trait SeedableHasher128: SeedableHasher {
+    /// Create a Hasher from a 128-bit seed, as (low, high) halves.
+    fn with_seed128(seed: (u64, u64)) -> Self;
+}
+
+/// Check that a SeedableHasher's seeds give different hashes and, if
+/// `zero_is_default`, that `with_seed(0)` is the Default.
+#[cfg(test)]
+fn check_seedable<H: SeedableHasher + Default>(zero_is_default: bool) {
+    fn hash<H: Hasher>(mut hasher: H) -> u64 {
+        hasher.write(b"The quick brown fox jumps over the lazy dog");
+        hasher.finish()
+    }
+    if zero_is_default {
+        assert_eq!(hash(H::with_seed(0)), hash(H::default()));
+    }
+    let seeds = [0, 1, 2, 0x1234];
+    let hashes: Vec<u64> = seeds.iter().map(|&s| hash(H::with_seed(s))).collect();
+    for i in 0..seeds.len() {
+        for j in 0..i {
+            assert_ne!(hashes[i], hashes[j], "seeds {} and {}", seeds[i], seeds[j]);
+        }
+    }
+}
+
+/// Check that both halves of a SeedableHasher128's seed matter.
+#[cfg(test)]
+fn check_seedable128<H: SeedableHasher128>() {
+    fn hash<H: Hasher>(mut hasher: H) -> u64 {
+        hasher.write(b"The quick brown fox jumps over the lazy dog");
+        hasher.finish()
+    }
+    let a = hash(H::with_seed128((1, 0)));
+    assert_ne!(a, hash(H::with_seed128((1, 1))));
+    assert_ne!(a, hash(H::with_seed128((2, 0))));
+}
+
+/// Draw a random 64-bit value from std's `RandomState`.
+///
+/// Each `RandomState` has fresh SipHash keys (random per thread, then
+/// incremented), so the hashes of a fixed input under successive states
+/// are unpredictable and distinct.
+fn random_u64(state: &RandomState, word: u8) -> u64 {
+    let mut hasher = state.build_hasher();
+    hasher.write_u8(word);
+    hasher.finish()
+}
+
+/// A BuildHasher that builds SeedableHashers from a random seed.
+///
+/// This is `std::collections::hash_map::RandomState` for the Hashers in
+/// this crate: each `RandomSeedState::new()` draws its own seed, so every
+/// map gets a different hash function but a single map (and its clones)
+/// stays consistent. For a seed shared across the whole process, create
+/// one state and clone it.
+///
+/// The seed makes it harder to pick keys that collide, which is cheap
+/// hash-flooding mitigation for functions much faster than SipHash. It is
+/// only as good as the function's seeding, though: for the simpler
+/// functions, collisions that do not depend on the seed remain. The seed
+/// comes from std's `RandomState`, so no other entropy source is needed.
+///
+/// Each module names the states for its Hashers, as in
+/// `jenkins::spooky_hash::RandomSpookyState`. Hashers taking a 128-bit
+/// seed use `RandomSeedState128`.
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use hashers::jenkins::RandomLookup3State;
+///
+/// let mut map = HashMap::with_hasher(RandomLookup3State::new());
+///
+/// map.insert(1, 2);
+/// assert_eq!(map.get(&1), Some(&2));
+/// ```
+pub struct RandomSeedState<H> {
+    seed: u64,
+    hasher: PhantomData<fn() -> H>,
+}
+
+impl<H> RandomSeedState<H> {
+    /// Create a state with a new random seed.
+    pub fn new() -> RandomSeedState<H> {
+        RandomSeedState::with_seed(random_u64(&RandomState::new(), 0))
+    }
+
+    /// Create a state with a fixed seed.
+    pub fn with_seed(seed: u64) -> RandomSeedState<H> {
+        RandomSeedState {
+            seed,
+            hasher: PhantomData,
+        }
+    }
+
+    /// The seed given to each Hasher.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl<H: SeedableHasher> BuildHasher for RandomSeedState<H> {
+    type Hasher = H;
+
+    #[inline]
+    fn build_hasher(&self) -> H {
+        H::with_seed(self.seed)
+    }
+}
+
+impl<H> Default for RandomSeedState<H> {
+    fn default() -> RandomSeedState<H> {
+        RandomSeedState::new()
+    }
+}
+
+impl<H> Clone for RandomSeedState<H> {
+    fn clone(&self) -> RandomSeedState<H> {
+        RandomSeedState::with_seed(self.seed)
+    }
+}
+
+impl<H> fmt::Debug for RandomSeedState<H> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RandomSeedState").finish()
+    }
+}
+
+/// A RandomSeedState for SeedableHasher128s, drawing a 128-bit seed.
+pub struct RandomSeedState128<H> {
+    seed: (u64, u64),
+    hasher: PhantomData<fn() -> H>,
+}
+
+impl<H> RandomSeedState128<H> {
+    /// Create a state with a new random seed.
+    pub fn new() -> RandomSeedState128<H> {
+        let state = RandomState::new();
+        RandomSeedState128::with_seed((random_u64(&state, 0), random_u64(&state, 1)))
+    }
+
+    /// Create a state with a fixed seed, as (low, high) halves.
+    pub fn with_seed(seed: (u64, u64)) -> RandomSeedState128<H> {
+        RandomSeedState128 {
+            seed,
+            hasher: PhantomData,
+        }
+    }
+
+    /// The seed given to each Hasher.
+    pub fn seed(&self) -> (u64, u64) {
+        self.seed
+    }
+}
+
+impl<H: SeedableHasher128> BuildHasher for RandomSeedState128<H> {
+    type Hasher = H;
+
+    #[inline]
+    fn build_hasher(&self) -> H {
+        H::with_seed128(self.seed)
+    }
+}
+
+impl<H> Default for RandomSeedState128<H> {
+    fn default() -> RandomSeedState128<H> {
+        RandomSeedState128::new()
+    }
+}
+
+impl<H> Clone for RandomSeedState128<H> {
+    fn clone(&self) -> RandomSeedState128<H> {
+        RandomSeedState128::with_seed(self.seed)
+    }
+}
+
+impl<H> fmt::Debug for RandomSeedState128<H> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RandomSeedState128").finish()
+    }
+}
+
+#[cfg(test)]
+mod random_state_tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::hash::BuildHasherDefault;
+
+    use fnv::FNV1aHasher64;
+    use jenkins::spooky_hash::SpookyHasher;
+
+    fn hash<B: BuildHasher>(state: &B) -> u64 {
+        let mut hasher = state.build_hasher();
+        hasher.write(b"The quick brown fox jumps over the lazy dog");
+        hasher.finish()
+    }
+
+    #[test]
+    fn seeds_differ() {
+        let seeds: HashSet<u64> = (0..16)
+            .map(|_| RandomSeedState::<FNV1aHasher64>::new().seed())
+            .collect();
+        assert_eq!(seeds.len(), 16);
+        let seeds: HashSet<(u64, u64)> = (0..16)
+            .map(|_| RandomSeedState128::<SpookyHasher>::new().seed())
+            .collect();
+        assert_eq!(seeds.len(), 16);
+    }
+
+    #[test]
+    fn consistent() {
+        let state = RandomSeedState::<FNV1aHasher64>::new();
+        assert_eq!(hash(&state), hash(&state));
+        assert_eq!(hash(&state), hash(&state.clone()));
+    }
+
+    #[test]
+    fn fixed_seed() {
+        assert_eq!(
+            hash(&RandomSeedState::<FNV1aHasher64>::with_seed(0)),
+            hash(&BuildHasherDefault::<FNV1aHasher64>::default())
+        );
+        let state = RandomSeedState128::<SpookyHasher>::with_seed((1, 2));
+        let mut hasher = SpookyHasher::with_seed128((1, 2));
+        hasher.write(b"The quick brown fox jumps over the lazy dog");
+        assert_eq!(hash(&state), hasher.finish());
+    }
+}
+
+// ====================================
+// Collections
+
+/// `new` and `with_capacity` for any HashMap whose BuildHasher is Default.
+///
+/// The standard `HashMap::new` and `HashMap::with_capacity` only build
+/// maps using `RandomState`. With this trait in scope, the same calls work
+/// for the maps named by each module, such as `fnv::FnvHashMap`, and for
+/// maps using a `RandomSeedState`. (A bare `HashMap::new()` still means the
+/// standard one; name the map's type, as in `HashMap::<K, V, S>::new()`.)
+///
+/// ```rust
+/// use hashers::HashMapExt;
+/// use hashers::fnv::FnvHashMap;
+///
+/// let mut map = FnvHashMap::with_capacity(10);
+///
+/// map.insert(1, 2);
+/// assert_eq!(map.get(&1), Some(&2));
+/// ```
+pub trait HashMapExt {
+    /// Create an empty HashMap.
+    fn new() -> Self;
+
+    /// Create an empty HashMap with room for at least `capacity` entries.
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+impl<K, V, S: BuildHasher + Default> HashMapExt for HashMap<K, V, S> {
+    #[inline]
+    fn new() -> Self {
+        HashMap::with_hasher(S::default())
+    }
+
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self {
+        HashMap::with_capacity_and_hasher(capacity, S::default())
+    }
+}
+
+/// `new` and `with_capacity` for any HashSet whose BuildHasher is Default.
+///
+/// The HashSet counterpart of `HashMapExt`.
+pub trait HashSetExt {
+    /// Create an empty HashSet.
+    fn new() -> Self;
+
+    /// Create an empty HashSet with room for at least `capacity` elements.
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+impl<T, S: BuildHasher + Default> HashSetExt for HashSet<T, S> {
+    #[inline]
+    fn new() -> Self {
+        HashSet::with_hasher(S::default())
+    }
+
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self {
+        HashSet::with_capacity_and_hasher(capacity, S::default())
+    }
+}
+
+#[cfg(test)]
+mod collections_tests {
+    use super::*;
+
+    use fnv::{FnvHashMap, FnvHashSet, RandomFNV1aState64};
+    use jenkins::{SpookyHashMap, SpookyHashSet};
+
+    #[test]
+    fn map() {
+        let mut map: FnvHashMap<&str, i32> = FnvHashMap::new();
+        map.insert("one", 1);
+        assert_eq!(map.get("one"), Some(&1));
+        let map: SpookyHashMap<i32, i32> = SpookyHashMap::with_capacity(100);
+        assert!(map.capacity() >= 100);
+        let mut map = HashMap::<i32, i32, RandomFNV1aState64>::new();
+        map.insert(1, 2);
+        assert_eq!(map.get(&1), Some(&2));
+    }
+
+    #[test]
+    fn set() {
+        let mut set: FnvHashSet<i32> = FnvHashSet::new();
+        assert!(set.insert(1));
+        assert!(!set.insert(1));
+        let set: SpookyHashSet<i32> = SpookyHashSet::with_capacity(100);
+        assert!(set.capacity() >= 100);
+    }
+}
+
 // ====================================
 // Hashing modules
 
+pub mod checksum;
+pub mod crc;
+pub mod fnv;
+pub mod google;
+pub mod highway;
 pub mod jenkins;
+pub mod murmur;
 pub mod pigeon;
 pub mod oz;
+pub mod sip;
+pub mod wy;
+pub mod xxhash;
 
 /// For easy access, reexport the built-in hash map's DefaultHasher,
 /// including a matching one-stop function.
 ///
 /// See std::collections::hash_map::DefaultHasher.
+///
+/// DefaultHasher's keys cannot be set, so its SeedableHasher impl hashes
+/// the seed ahead of the input.
 pub mod builtin {
     use std::hash::Hasher;
 
     pub use std::collections::hash_map::DefaultHasher;
 
+    use SeedableHasher;
+
     hasher_to_fcn!(
         /// Provide access to the DefaultHasher in a single function.
         default,
         DefaultHasher
     );
+
+    impl SeedableHasher for DefaultHasher {
+        fn with_seed(seed: u64) -> DefaultHasher {
+            let mut hasher = DefaultHasher::new();
+            hasher.write_u64(seed);
+            hasher
+        }
+    }
+
+    #[cfg(test)]
+    mod builtin_tests {
+        use super::*;
+        use check_seedable;
+
+        #[test]
+        fn seedable() {
+            check_seedable::<DefaultHasher>(false);
+        }
+    }
 }
 
 /// From https://github.com/cbreeden/fxhash
@@ -317,13 +835,55 @@ pub mod builtin {
 ///
 /// The fxhash crate provides both 32- and 64-bit versions, as well as FxHasher, which uses the
 /// system bit-width.
+///
+/// The fxhash Hashers' state is private, so their SeedableHasher impls hash the seed ahead of the
+/// input. Hashing a zero word leaves the state at zero, so `with_seed(0)` is the Default.
 pub mod fx_hash {
     pub use fxhash::{FxHasher, FxHasher32, FxHasher64};
     use std::hash::Hasher;
 
+    use SeedableHasher;
+
     hasher_to_fcn!(fxhash, FxHasher);
     hasher_to_fcn!(fxhash32, FxHasher32);
     hasher_to_fcn!(fxhash64, FxHasher64);
+
+    macro_rules! seed_by_prefix {
+        ($name:ident) => {
+            impl SeedableHasher for $name {
+                fn with_seed(seed: u64) -> $name {
+                    let mut hasher = $name::default();
+                    hasher.write_u64(seed);
+                    hasher
+                }
+            }
+        };
+    }
+
+    seed_by_prefix!(FxHasher);
+    seed_by_prefix!(FxHasher32);
+    seed_by_prefix!(FxHasher64);
+
+    random_state!(RandomFxState, FxHasher);
+    random_state!(RandomFxState32, FxHasher32);
+    random_state!(RandomFxState64, FxHasher64);
+
+    hash_collections!(FxHashMap, FxHashSet, FxHasher);
+    hash_collections!(FxHashMap32, FxHashSet32, FxHasher32);
+    hash_collections!(FxHashMap64, FxHashSet64, FxHasher64);
+
+    #[cfg(test)]
+    mod fx_hash_tests {
+        use super::*;
+        use check_seedable;
+
+        #[test]
+        fn seedable() {
+            check_seedable::<FxHasher>(true);
+            check_seedable::<FxHasher32>(true);
+            check_seedable::<FxHasher64>(true);
+        }
+    }
 }
 
 /// Poor Hashers used for testing purposes.
@@ -332,6 +892,8 @@ pub mod fx_hash {
 pub mod null {
     use std::hash::Hasher;
 
+    use SeedableHasher;
+
     /// Always returns 0.
     pub struct NullHasher;
 
@@ -353,6 +915,13 @@ pub mod null {
         }
     }
 
+    /// Every seed gives the same, constant, function.
+    impl SeedableHasher for NullHasher {
+        fn with_seed(_seed: u64) -> NullHasher {
+            NullHasher
+        }
+    }
+
     hasher_to_fcn!(
         /// Provide access to NullHasher in a single call.
         null,
@@ -361,7 +930,8 @@ pub mod null {
 
     // --------------------------------
 
-    /// Returns the last 8 bytes of the data, as a u64.
+    /// Returns the last 8 bytes of the data, as a u64. A seed is the
+    /// bytes before the data.
     pub struct PassThroughHasher(u64);
 
     impl Hasher for PassThroughHasher {
@@ -380,85 +950,29 @@ pub mod null {
 
     /// Provide a default PassThroughHasher initialized to 0.
     default_for_constant!(PassThroughHasher, 0);
+    seedable_for_constant!(PassThroughHasher, seed => seed);
 
     hasher_to_fcn!(
         /// Provide access to PassThroughHasher in a single call.
         passthrough,
         PassThroughHasher
     );
-}
-
-// ====================================
-// FNV-1a (64-bit)
-
-/// The [Fowler–Noll–Vo hash function](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function).
-///
-/// http://www.isthe.com/chongo/tech/comp/fnv/
-///
-/// > The basis of the FNV hash algorithm was taken from an idea sent as reviewer comments to the
-/// > IEEE POSIX P1003.2 committee by Glenn Fowler and Phong Vo back in 1991. In a subsequent ballot
-/// > round: Landon Curt Noll improved on their algorithm. Some people tried this hash and found that
-/// > it worked rather well. In an EMail message to Landon, they named it the ``Fowler/Noll/Vo'' or
-/// > FNV hash.
-/// >
-/// > FNV hashes are designed to be fast while maintaining a low collision rate. The FNV speed allows
-/// > one to quickly hash lots of data while maintaining a reasonable collision rate. The high
-/// > dispersion of the FNV hashes makes them well suited for hashing nearly identical strings such
-/// > as URLs, hostnames, filenames, text, IP addresses, etc.
-/// >
-/// > The IETF has an informational draft on The FNV Non-Cryptographic Hash Algorithm 
-///
-/// This module provides both 32- and 64-bit versions of FNV-1a.
-pub mod fnv {
-    use std::hash::Hasher;
-
-    macro_rules! fnv1a {
-        ($name:ident, $size:ty, $fnv_prime:expr, $offset_basis:expr) => {
-            pub struct $name($size);
-            impl Hasher for $name {
-                #[inline]
-                fn finish(&self) -> u64 {
-                    self.0 as u64
-                }
-                #[inline]
-                fn write(&mut self, bytes: &[u8]) {
-                    for byte in bytes.iter() {
-                        self.0 = self.0 ^ (*byte as $size);
-                        self.0 = self.0.wrapping_mul($fnv_prime);
-                    }
-                }
-            }
-            default_for_constant!($name, $offset_basis);
-        };
-    }
-
-    fnv1a!(FNV1aHasher32, u32, 16777619, 0x811c9dc5);
-    fnv1a!(FNV1aHasher64, u64, 1099511628211, 0xcbf29ce484222325);
-
-    hasher_to_fcn!(
-        /// Provide access to FNV1aHasher32 in a single call.
-        fnv1a32,
-        FNV1aHasher32
-    );
-
-    hasher_to_fcn!(
-        /// Provide access to FNV1aHasher64 in a single call.
-        fnv1a64,
-        FNV1aHasher64
-    );
 
     #[cfg(test)]
-    mod fnv1a_tests {
+    mod null_tests {
         use super::*;
 
         #[test]
-        fn basic() {
-            assert_eq!(fnv1a64(b""), 14695981039346656037);
-            assert_eq!(fnv1a64(b"a"), 12638187200555641996);
-            assert_eq!(fnv1a64(b"b"), 12638190499090526629);
-            assert_eq!(fnv1a64(b"ab"), 620445648566982762);
-            assert_eq!(fnv1a64(b"abcd"), 18165163011005162717);
-            assert_eq!(fnv1a64(b"abcdefg"), 4642726675185563447);
+        fn seedable() {
+            let mut hasher = NullHasher::with_seed(1);
+            hasher.write(b"abc");
+            assert_eq!(hasher.finish(), 0);
+            // The seed is shifted out after eight bytes.
+            let mut hasher = PassThroughHasher::with_seed(1);
+            hasher.write(b"abc");
+            assert_eq!(hasher.finish(), 0x01616263);
+            hasher.write(b"defgh");
+            assert_eq!(hasher.finish(), passthrough(b"abcdefgh"));
         }
     }
 }
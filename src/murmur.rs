@@ -0,0 +1,884 @@
+//! From https://github.com/aappleby/smhasher.
+//!
+//! Austin Appleby's MurmurHash family. Quoted comments are from
+//! https://github.com/aappleby/smhasher/blob/master/src/MurmurHash3.cpp:
+//!
+//! > MurmurHash3 was written by Austin Appleby, and is placed in the
+//! > public domain. The author hereby disclaims copyright to this
+//! > source code.
+//! >
+//! > Note - The x86 and x64 versions do _not_ produce the same results,
+//! > as the algorithms are optimized for their respective platforms.
+//! > You can still compile and run any of them on any platform, but
+//! > your performance with the non-native version will be less than
+//! > optimal.
+//!
+//! The older MurmurHash2 family (MurmurHash2, MurmurHash64A and
+//! MurmurHash64B) is here too, for compatibility with data hashed by
+//! it. From
+//! https://github.com/aappleby/smhasher/blob/master/src/MurmurHash2.cpp:
+//!
+//! > Note - This code makes a few assumptions about how your machine behaves -
+//! >
+//! > 1. We can read a 4-byte value from any address without crashing
+//! > 2. sizeof(int) == 4
+//! >
+//! > And it has a few limitations -
+//! >
+//! > 1. It will not work incrementally.
+//! > 2. It will not produce the same results on little-endian and big-endian
+//! >    machines.
+//!
+//! All of the Hashers here read their input as little-endian words,
+//! whatever the platform, so their results match the reference
+//! implementations running on x86 and x86-64. (For MurmurHash2, that
+//! is also what the reference's endian-neutral variant,
+//! MurmurHashNeutral2, computes everywhere.) The odd trailing bytes
+//! are assembled byte-by-byte, exactly as the reference does.
+//!
+//! MurmurHash3 and MurmurHash2 take 32-bit seeds; as SeedableHashers,
+//! they use the low 32 bits of the seed.
+
+use std::hash::Hasher;
+use std::{mem, ptr};
+
+use SeedableHasher;
+
+/// Feed `bytes` through `buf`, calling `block` on each complete
+/// `buf.len()`-byte block and leaving any partial block in `buf`.
+#[inline]
+fn write_blocks<F>(buf: &mut [u8], buf_len: &mut usize, bytes: &[u8], mut block: F)
+where
+    F: FnMut(&[u8]),
+{
+    let size = buf.len();
+    let mut bytes = bytes;
+    // if we've got anything stuffed away, top it up first
+    if *buf_len > 0 {
+        let fill = (size - *buf_len).min(bytes.len());
+        buf[*buf_len..*buf_len + fill].copy_from_slice(&bytes[..fill]);
+        *buf_len += fill;
+        bytes = &bytes[fill..];
+        if *buf_len < size {
+            return;
+        }
+        block(buf);
+        *buf_len = 0;
+    }
+    let mut chunks = bytes.chunks_exact(size);
+    for chunk in &mut chunks {
+        block(chunk);
+    }
+    // stuff away the last few bytes
+    let rest = chunks.remainder();
+    buf[..rest.len()].copy_from_slice(rest);
+    *buf_len = rest.len();
+}
+
+/// Copy a partial block into a zeroed array, so the tail can be read as
+/// little-endian words.
+#[inline]
+fn pad<T: Default + AsMut<[u8]>>(tail: &[u8]) -> T {
+    let mut block = T::default();
+    block.as_mut()[..tail.len()].copy_from_slice(tail);
+    block
+}
+
+// ====================================
+// MurmurHash3
+
+/// > Finalization mix - force all bits of a hash block to avalanche
+#[inline]
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^= h >> 16;
+    h
+}
+
+#[inline]
+fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51afd7ed558ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ceb9fe1a85ec53);
+    k ^= k >> 33;
+    k
+}
+
+// ------------------------------------
+// MurmurHash3_x86_32
+
+const C1_X86_32: u32 = 0xcc9e2d51;
+const C2_X86_32: u32 = 0x1b873593;
+
+/// MurmurHash3_x86_32: a 32-bit hash, processing 4 bytes at a time.
+///
+/// This is the variant behind, among others, Guava's `murmur3_32`.
+pub struct Murmur3Hasher32 {
+    h1: u32,
+    // unhashed data, for partial blocks
+    buf: [u8; 4],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl Murmur3Hasher32 {
+    pub fn new(seed: u32) -> Murmur3Hasher32 {
+        Murmur3Hasher32 {
+            h1: seed,
+            buf: [0; 4],
+            buf_len: 0,
+            total_len: 0,
+        }
+    }
+
+    /// The full 32-bit result.
+    pub fn finish32(&self) -> u32 {
+        let mut h1 = self.h1;
+        if self.buf_len > 0 {
+            let tail: [u8; 4] = pad(&self.buf[..self.buf_len]);
+            let mut k1 = u32::from_le_bytes(tail);
+            k1 = k1.wrapping_mul(C1_X86_32);
+            k1 = k1.rotate_left(15);
+            k1 = k1.wrapping_mul(C2_X86_32);
+            h1 ^= k1;
+        }
+        // the reference implementation takes the length as an int
+        h1 ^= self.total_len as u32;
+        fmix32(h1)
+    }
+}
+
+impl Default for Murmur3Hasher32 {
+    fn default() -> Murmur3Hasher32 {
+        Murmur3Hasher32::new(0)
+    }
+}
+
+impl SeedableHasher for Murmur3Hasher32 {
+    fn with_seed(seed: u64) -> Murmur3Hasher32 {
+        Murmur3Hasher32::new(seed as u32)
+    }
+}
+
+impl Hasher for Murmur3Hasher32 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let Murmur3Hasher32 {
+            ref mut h1,
+            ref mut buf,
+            ref mut buf_len,
+            ..
+        } = *self;
+        write_blocks(buf, buf_len, bytes, |block| {
+            let mut k1 = load_int_le!(block, 0, u32);
+            k1 = k1.wrapping_mul(C1_X86_32);
+            k1 = k1.rotate_left(15);
+            k1 = k1.wrapping_mul(C2_X86_32);
+
+            *h1 ^= k1;
+            *h1 = h1.rotate_left(13);
+            *h1 = h1.wrapping_mul(5).wrapping_add(0xe6546b64);
+        });
+    }
+}
+
+random_state!(RandomMurmur3State32, Murmur3Hasher32);
+hash_collections!(Murmur3HashMap32, Murmur3HashSet32, Murmur3Hasher32);
+hasher_to_fcn!(
+    /// Provide access to Murmur3Hasher32 in a single call.
+    murmur3_32,
+    Murmur3Hasher32
+);
+
+// ------------------------------------
+// MurmurHash3_x86_128
+
+const C1_X86_128: u32 = 0x239b961b;
+const C2_X86_128: u32 = 0xab0e9789;
+const C3_X86_128: u32 = 0x38b34ae5;
+const C4_X86_128: u32 = 0xa1e38b93;
+
+#[inline]
+fn mix_k_x86_128(k: u32, rot: u32, c: u32, c_next: u32) -> u32 {
+    k.wrapping_mul(c).rotate_left(rot).wrapping_mul(c_next)
+}
+
+/// MurmurHash3_x86_128: a 128-bit hash computed with 32-bit arithmetic,
+/// processing 16 bytes at a time.
+///
+/// `finish128` returns the four 32-bit words of the result packed into
+/// two u64s, low word first, which is how the reference implementation
+/// lays them out in memory; `finish` returns the first of them.
+pub struct Murmur3x86Hasher128 {
+    h: [u32; 4],
+    // unhashed data, for partial blocks
+    buf: [u8; 16],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl Murmur3x86Hasher128 {
+    pub fn new(seed: u32) -> Murmur3x86Hasher128 {
+        Murmur3x86Hasher128 {
+            h: [seed; 4],
+            buf: [0; 16],
+            buf_len: 0,
+            total_len: 0,
+        }
+    }
+
+    pub fn finish128(&self) -> (u64, u64) {
+        let [mut h1, mut h2, mut h3, mut h4] = self.h;
+        let tail: [u8; 16] = pad(&self.buf[..self.buf_len]);
+        let len = self.buf_len;
+        if len > 12 {
+            h4 ^= mix_k_x86_128(load_int_le!(tail, 12, u32), 18, C4_X86_128, C1_X86_128);
+        }
+        if len > 8 {
+            h3 ^= mix_k_x86_128(load_int_le!(tail, 8, u32), 17, C3_X86_128, C4_X86_128);
+        }
+        if len > 4 {
+            h2 ^= mix_k_x86_128(load_int_le!(tail, 4, u32), 16, C2_X86_128, C3_X86_128);
+        }
+        if len > 0 {
+            h1 ^= mix_k_x86_128(load_int_le!(tail, 0, u32), 15, C1_X86_128, C2_X86_128);
+        }
+
+        let total_len = self.total_len as u32;
+        h1 ^= total_len;
+        h2 ^= total_len;
+        h3 ^= total_len;
+        h4 ^= total_len;
+
+        h1 = h1.wrapping_add(h2).wrapping_add(h3).wrapping_add(h4);
+        h2 = h2.wrapping_add(h1);
+        h3 = h3.wrapping_add(h1);
+        h4 = h4.wrapping_add(h1);
+
+        h1 = fmix32(h1);
+        h2 = fmix32(h2);
+        h3 = fmix32(h3);
+        h4 = fmix32(h4);
+
+        h1 = h1.wrapping_add(h2).wrapping_add(h3).wrapping_add(h4);
+        h2 = h2.wrapping_add(h1);
+        h3 = h3.wrapping_add(h1);
+        h4 = h4.wrapping_add(h1);
+
+        (
+            (h1 as u64) | ((h2 as u64) << 32),
+            (h3 as u64) | ((h4 as u64) << 32),
+        )
+    }
+}
+
+impl Default for Murmur3x86Hasher128 {
+    fn default() -> Murmur3x86Hasher128 {
+        Murmur3x86Hasher128::new(0)
+    }
+}
+
+impl SeedableHasher for Murmur3x86Hasher128 {
+    fn with_seed(seed: u64) -> Murmur3x86Hasher128 {
+        Murmur3x86Hasher128::new(seed as u32)
+    }
+}
+
+impl Hasher for Murmur3x86Hasher128 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish128().0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let Murmur3x86Hasher128 {
+            ref mut h,
+            ref mut buf,
+            ref mut buf_len,
+            ..
+        } = *self;
+        write_blocks(buf, buf_len, bytes, |block| {
+            let k1 = load_int_le!(block, 0, u32);
+            let k2 = load_int_le!(block, 4, u32);
+            let k3 = load_int_le!(block, 8, u32);
+            let k4 = load_int_le!(block, 12, u32);
+
+            h[0] ^= mix_k_x86_128(k1, 15, C1_X86_128, C2_X86_128);
+            h[0] = h[0].rotate_left(19).wrapping_add(h[1]);
+            h[0] = h[0].wrapping_mul(5).wrapping_add(0x561ccd1b);
+
+            h[1] ^= mix_k_x86_128(k2, 16, C2_X86_128, C3_X86_128);
+            h[1] = h[1].rotate_left(17).wrapping_add(h[2]);
+            h[1] = h[1].wrapping_mul(5).wrapping_add(0x0bcaa747);
+
+            h[2] ^= mix_k_x86_128(k3, 17, C3_X86_128, C4_X86_128);
+            h[2] = h[2].rotate_left(15).wrapping_add(h[3]);
+            h[2] = h[2].wrapping_mul(5).wrapping_add(0x96cd1c35);
+
+            h[3] ^= mix_k_x86_128(k4, 18, C4_X86_128, C1_X86_128);
+            h[3] = h[3].rotate_left(13).wrapping_add(h[0]);
+            h[3] = h[3].wrapping_mul(5).wrapping_add(0x32ac3b17);
+        });
+    }
+}
+
+random_state!(RandomMurmur3x86State128, Murmur3x86Hasher128);
+hash_collections!(Murmur3x86HashMap128, Murmur3x86HashSet128, Murmur3x86Hasher128);
+hasher_to_fcn!(
+    /// Provide access to Murmur3x86Hasher128 in a single call.
+    murmur3_x86_128,
+    Murmur3x86Hasher128
+);
+
+// ------------------------------------
+// MurmurHash3_x64_128
+
+const C1_X64_128: u64 = 0x87c37b91114253d5;
+const C2_X64_128: u64 = 0x4cf5ad432745937f;
+
+#[inline]
+fn mix_k1_x64_128(k1: u64) -> u64 {
+    k1.wrapping_mul(C1_X64_128)
+        .rotate_left(31)
+        .wrapping_mul(C2_X64_128)
+}
+
+#[inline]
+fn mix_k2_x64_128(k2: u64) -> u64 {
+    k2.wrapping_mul(C2_X64_128)
+        .rotate_left(33)
+        .wrapping_mul(C1_X64_128)
+}
+
+/// MurmurHash3_x64_128: a 128-bit hash computed with 64-bit arithmetic,
+/// processing 16 bytes at a time.
+///
+/// `finish128` returns (h1, h2), the first and second halves of the
+/// reference implementation's output; `finish` returns h1. (Cassandra's
+/// Murmur3Partitioner token is h1, as a signed integer.)
+pub struct Murmur3x64Hasher128 {
+    h1: u64,
+    h2: u64,
+    // unhashed data, for partial blocks
+    buf: [u8; 16],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl Murmur3x64Hasher128 {
+    pub fn new(seed: u32) -> Murmur3x64Hasher128 {
+        Murmur3x64Hasher128 {
+            h1: seed as u64,
+            h2: seed as u64,
+            buf: [0; 16],
+            buf_len: 0,
+            total_len: 0,
+        }
+    }
+
+    pub fn finish128(&self) -> (u64, u64) {
+        let mut h1 = self.h1;
+        let mut h2 = self.h2;
+        let tail: [u8; 16] = pad(&self.buf[..self.buf_len]);
+        if self.buf_len > 8 {
+            h2 ^= mix_k2_x64_128(load_int_le!(tail, 8, u64));
+        }
+        if self.buf_len > 0 {
+            h1 ^= mix_k1_x64_128(load_int_le!(tail, 0, u64));
+        }
+
+        h1 ^= self.total_len;
+        h2 ^= self.total_len;
+
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+
+        h1 = fmix64(h1);
+        h2 = fmix64(h2);
+
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+
+        (h1, h2)
+    }
+}
+
+impl Default for Murmur3x64Hasher128 {
+    fn default() -> Murmur3x64Hasher128 {
+        Murmur3x64Hasher128::new(0)
+    }
+}
+
+impl SeedableHasher for Murmur3x64Hasher128 {
+    fn with_seed(seed: u64) -> Murmur3x64Hasher128 {
+        Murmur3x64Hasher128::new(seed as u32)
+    }
+}
+
+impl Hasher for Murmur3x64Hasher128 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish128().0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let Murmur3x64Hasher128 {
+            ref mut h1,
+            ref mut h2,
+            ref mut buf,
+            ref mut buf_len,
+            ..
+        } = *self;
+        write_blocks(buf, buf_len, bytes, |block| {
+            let k1 = load_int_le!(block, 0, u64);
+            let k2 = load_int_le!(block, 8, u64);
+
+            *h1 ^= mix_k1_x64_128(k1);
+            *h1 = h1.rotate_left(27).wrapping_add(*h2);
+            *h1 = h1.wrapping_mul(5).wrapping_add(0x52dce729);
+
+            *h2 ^= mix_k2_x64_128(k2);
+            *h2 = h2.rotate_left(31).wrapping_add(*h1);
+            *h2 = h2.wrapping_mul(5).wrapping_add(0x38495ab5);
+        });
+    }
+}
+
+random_state!(RandomMurmur3x64State128, Murmur3x64Hasher128);
+hash_collections!(Murmur3x64HashMap128, Murmur3x64HashSet128, Murmur3x64Hasher128);
+hasher_to_fcn!(
+    /// Provide access to Murmur3x64Hasher128 in a single call.
+    murmur3_x64_128,
+    Murmur3x64Hasher128
+);
+
+// ====================================
+// MurmurHash2
+//
+// MurmurHash2 mixes the length of the input into the initial state, so
+// the Hashers can't hash anything until they know how long the input
+// is. They buffer it all and do the real work in finish.
+
+const M_32: u32 = 0x5bd1e995;
+const R_32: u32 = 24;
+
+#[inline]
+fn mix_k_32(k: u32) -> u32 {
+    let mut k = k.wrapping_mul(M_32);
+    k ^= k >> R_32;
+    k.wrapping_mul(M_32)
+}
+
+// ------------------------------------
+// MurmurHash2
+
+/// MurmurHash2, the 32-bit hash, with an explicit seed.
+pub fn murmur2_with_seed(bytes: &[u8], seed: u32) -> u32 {
+    // the reference implementation takes the length as an int
+    let mut h = seed ^ (bytes.len() as u32);
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        h = h.wrapping_mul(M_32);
+        h ^= mix_k_32(load_int_le!(chunk, 0, u32));
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let tail: [u8; 4] = pad(tail);
+        h ^= u32::from_le_bytes(tail);
+        h = h.wrapping_mul(M_32);
+    }
+    // > Do a few final mixes of the hash to ensure the last few
+    // > bytes are well-incorporated.
+    h ^= h >> 13;
+    h = h.wrapping_mul(M_32);
+    h ^= h >> 15;
+    h
+}
+
+/// MurmurHash2: a 32-bit hash, processing 4 bytes at a time.
+///
+/// This buffers all of its input; see `murmur2_with_seed` for hashing
+/// a slice directly.
+#[derive(Default)]
+pub struct Murmur2Hasher {
+    seed: u32,
+    buf: Vec<u8>,
+}
+
+impl Murmur2Hasher {
+    pub fn new(seed: u32) -> Murmur2Hasher {
+        Murmur2Hasher {
+            seed,
+            buf: Vec::new(),
+        }
+    }
+
+    /// The full 32-bit result.
+    pub fn finish32(&self) -> u32 {
+        murmur2_with_seed(&self.buf, self.seed)
+    }
+}
+
+impl SeedableHasher for Murmur2Hasher {
+    fn with_seed(seed: u64) -> Murmur2Hasher {
+        Murmur2Hasher::new(seed as u32)
+    }
+}
+
+random_state!(RandomMurmur2State, Murmur2Hasher);
+hash_collections!(Murmur2HashMap, Murmur2HashSet, Murmur2Hasher);
+
+impl Hasher for Murmur2Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+/// Provide access to MurmurHash2 in a single call.
+pub fn murmur2(bytes: &[u8]) -> u64 {
+    murmur2_with_seed(bytes, 0) as u64
+}
+
+// ------------------------------------
+// MurmurHash64A
+
+const M_64: u64 = 0xc6a4a7935bd1e995;
+const R_64: u32 = 47;
+
+/// MurmurHash64A, the 64-bit hash for 64-bit platforms, with an
+/// explicit seed.
+pub fn murmur64a_with_seed(bytes: &[u8], seed: u64) -> u64 {
+    let mut h = seed ^ (bytes.len() as u64).wrapping_mul(M_64);
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut k = load_int_le!(chunk, 0, u64);
+        k = k.wrapping_mul(M_64);
+        k ^= k >> R_64;
+        k = k.wrapping_mul(M_64);
+        h ^= k;
+        h = h.wrapping_mul(M_64);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let tail: [u8; 8] = pad(tail);
+        h ^= u64::from_le_bytes(tail);
+        h = h.wrapping_mul(M_64);
+    }
+    h ^= h >> R_64;
+    h = h.wrapping_mul(M_64);
+    h ^= h >> R_64;
+    h
+}
+
+/// MurmurHash64A: a 64-bit hash, processing 8 bytes at a time.
+///
+/// This buffers all of its input; see `murmur64a_with_seed` for hashing
+/// a slice directly.
+#[derive(Default)]
+pub struct Murmur64AHasher {
+    seed: u64,
+    buf: Vec<u8>,
+}
+
+impl Murmur64AHasher {
+    pub fn new(seed: u64) -> Murmur64AHasher {
+        Murmur64AHasher {
+            seed,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl SeedableHasher for Murmur64AHasher {
+    fn with_seed(seed: u64) -> Murmur64AHasher {
+        Murmur64AHasher::new(seed)
+    }
+}
+
+random_state!(RandomMurmur64AState, Murmur64AHasher);
+hash_collections!(Murmur64AHashMap, Murmur64AHashSet, Murmur64AHasher);
+
+impl Hasher for Murmur64AHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        murmur64a_with_seed(&self.buf, self.seed)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+/// Provide access to MurmurHash64A in a single call.
+pub fn murmur64a(bytes: &[u8]) -> u64 {
+    murmur64a_with_seed(bytes, 0)
+}
+
+// ------------------------------------
+// MurmurHash64B
+
+/// MurmurHash64B, the 64-bit hash for 32-bit platforms, with an
+/// explicit seed. This does not produce the same results as
+/// MurmurHash64A.
+pub fn murmur64b_with_seed(bytes: &[u8], seed: u64) -> u64 {
+    let mut h1 = (seed as u32) ^ (bytes.len() as u32);
+    let mut h2 = (seed >> 32) as u32;
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        h1 = h1.wrapping_mul(M_32);
+        h1 ^= mix_k_32(load_int_le!(chunk, 0, u32));
+        h2 = h2.wrapping_mul(M_32);
+        h2 ^= mix_k_32(load_int_le!(chunk, 4, u32));
+    }
+    let mut tail = chunks.remainder();
+    if tail.len() >= 4 {
+        h1 = h1.wrapping_mul(M_32);
+        h1 ^= mix_k_32(load_int_le!(tail, 0, u32));
+        tail = &tail[4..];
+    }
+    if !tail.is_empty() {
+        let tail: [u8; 4] = pad(tail);
+        h2 ^= u32::from_le_bytes(tail);
+        h2 = h2.wrapping_mul(M_32);
+    }
+    h1 ^= h2 >> 18;
+    h1 = h1.wrapping_mul(M_32);
+    h2 ^= h1 >> 22;
+    h2 = h2.wrapping_mul(M_32);
+    h1 ^= h2 >> 17;
+    h1 = h1.wrapping_mul(M_32);
+    h2 ^= h1 >> 19;
+    h2 = h2.wrapping_mul(M_32);
+    ((h1 as u64) << 32) | (h2 as u64)
+}
+
+/// MurmurHash64B: a 64-bit hash computed with 32-bit arithmetic,
+/// processing 8 bytes at a time.
+///
+/// This buffers all of its input; see `murmur64b_with_seed` for hashing
+/// a slice directly.
+#[derive(Default)]
+pub struct Murmur64BHasher {
+    seed: u64,
+    buf: Vec<u8>,
+}
+
+impl Murmur64BHasher {
+    pub fn new(seed: u64) -> Murmur64BHasher {
+        Murmur64BHasher {
+            seed,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl SeedableHasher for Murmur64BHasher {
+    fn with_seed(seed: u64) -> Murmur64BHasher {
+        Murmur64BHasher::new(seed)
+    }
+}
+
+random_state!(RandomMurmur64BState, Murmur64BHasher);
+hash_collections!(Murmur64BHashMap, Murmur64BHashSet, Murmur64BHasher);
+
+impl Hasher for Murmur64BHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        murmur64b_with_seed(&self.buf, self.seed)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+/// Provide access to MurmurHash64B in a single call.
+pub fn murmur64b(bytes: &[u8]) -> u64 {
+    murmur64b_with_seed(bytes, 0)
+}
+
+// ------------------------------------
+
+#[cfg(test)]
+mod murmur3_tests {
+    use super::*;
+    use {check_seedable, smhasher_verification};
+
+    fn x86_32(seed: u32, bytes: &[u8]) -> u32 {
+        let mut hasher = Murmur3Hasher32::new(seed);
+        hasher.write(bytes);
+        hasher.finish32()
+    }
+
+    fn x86_128(seed: u32, bytes: &[u8]) -> (u64, u64) {
+        let mut hasher = Murmur3x86Hasher128::new(seed);
+        hasher.write(bytes);
+        hasher.finish128()
+    }
+
+    fn x64_128(seed: u32, bytes: &[u8]) -> (u64, u64) {
+        let mut hasher = Murmur3x64Hasher128::new(seed);
+        hasher.write(bytes);
+        hasher.finish128()
+    }
+
+    fn to_bytes128(h: (u64, u64)) -> Vec<u8> {
+        let mut v = h.0.to_le_bytes().to_vec();
+        v.extend_from_slice(&h.1.to_le_bytes());
+        v
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(murmur3_32(b""), 0);
+        assert_eq!(x86_32(1, b""), 0x514e28b7);
+        assert_eq!(x86_32(0xffffffff, b""), 0x81f16f39);
+        assert_eq!(x86_32(0, b"\0\0\0\0"), 0x2362f9de);
+        assert_eq!(x86_32(0x9747b28c, b"aaaa"), 0x5a97808a);
+        assert_eq!(x86_32(0x9747b28c, b"Hello, world!"), 0x24884cba);
+        assert_eq!(
+            x86_32(0x9747b28c, b"The quick brown fox jumps over the lazy dog"),
+            0x2fa826cd
+        );
+
+        let fox = b"The quick brown fox jumps over the lazy dog";
+        assert_eq!(x86_128(0, fox), (0xecee2c672f1583c3, 0xe5e91d2c5d7bf66c));
+        assert_eq!(x64_128(0, fox), (0xe34bbc7bbc071b6c, 0x7a433ca9c49a9347));
+        assert_eq!(murmur3_x64_128(fox), 0xe34bbc7bbc071b6c);
+    }
+
+    #[test]
+    fn verification() {
+        let v32 = smhasher_verification(4, |key, seed| x86_32(seed, key).to_le_bytes().to_vec());
+        assert_eq!(v32, 0xB0F57EE3);
+        let v86 = smhasher_verification(16, |key, seed| to_bytes128(x86_128(seed, key)));
+        assert_eq!(v86, 0xB3ECE62A);
+        let v64 = smhasher_verification(16, |key, seed| to_bytes128(x64_128(seed, key)));
+        assert_eq!(v64, 0x6384BA69);
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut h32 = Murmur3Hasher32::default();
+            let mut h86 = Murmur3x86Hasher128::default();
+            let mut h64 = Murmur3x64Hasher128::default();
+            for chunk in data.chunks(split + 1) {
+                h32.write(chunk);
+                h86.write(chunk);
+                h64.write(chunk);
+            }
+            assert_eq!(h32.finish32(), x86_32(0, &data));
+            assert_eq!(h86.finish128(), x86_128(0, &data));
+            assert_eq!(h64.finish128(), x64_128(0, &data));
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Murmur3Hasher32>(true);
+        check_seedable::<Murmur3x86Hasher128>(true);
+        check_seedable::<Murmur3x64Hasher128>(true);
+    }
+}
+
+#[cfg(test)]
+mod murmur2_tests {
+    use super::*;
+    use {check_seedable, smhasher_verification};
+
+    #[test]
+    fn basic() {
+        assert_eq!(murmur2(b""), 0);
+        assert_eq!(murmur64a(b""), 0);
+        assert_eq!(murmur64b(b""), 0);
+
+        assert_eq!(murmur2(b"abc"), 0x13577c9b);
+        assert_eq!(murmur64a(b"abc"), 0x9cc9c33498a95efb);
+        assert_eq!(murmur64b(b"abc"), 0xa60d4251ce5c599d);
+
+        let fox = b"The quick brown fox jumps over the lazy dog";
+        assert_eq!(murmur2(fox), 0x212729d0);
+        assert_eq!(murmur64a(fox), 0x5589ca33042a861b);
+        assert_eq!(murmur64b(fox), 0x758dd7cc8fc2b751);
+        assert_eq!(murmur2_with_seed(fox, 0x9747b28c), 0x1d84d036);
+        assert_eq!(murmur64a_with_seed(fox, 0x9747b28c), 0x029a7747a564bd84);
+        assert_eq!(
+            murmur64b_with_seed(fox, 0x1234_5678_9abc_def0),
+            0x7b40a18a1ce8a8d9
+        );
+    }
+
+    #[test]
+    fn verification() {
+        let v2 = smhasher_verification(4, |key, seed| {
+            murmur2_with_seed(key, seed).to_le_bytes().to_vec()
+        });
+        assert_eq!(v2, 0x27864C1E);
+        let v64a = smhasher_verification(8, |key, seed| {
+            murmur64a_with_seed(key, seed as u64).to_le_bytes().to_vec()
+        });
+        assert_eq!(v64a, 0x1F0D3804);
+        let v64b = smhasher_verification(8, |key, seed| {
+            murmur64b_with_seed(key, seed as u64).to_le_bytes().to_vec()
+        });
+        assert_eq!(v64b, 0xDD537C05);
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut h2 = Murmur2Hasher::new(0x9747b28c);
+            let mut h64a = Murmur64AHasher::new(0x9747b28c);
+            let mut h64b = Murmur64BHasher::new(0x1234_5678_9abc_def0);
+            for chunk in data.chunks(split + 1) {
+                h2.write(chunk);
+                h64a.write(chunk);
+                h64b.write(chunk);
+            }
+            assert_eq!(h2.finish32(), murmur2_with_seed(&data, 0x9747b28c));
+            assert_eq!(h64a.finish(), murmur64a_with_seed(&data, 0x9747b28c));
+            assert_eq!(
+                h64b.finish(),
+                murmur64b_with_seed(&data, 0x1234_5678_9abc_def0)
+            );
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Murmur2Hasher>(true);
+        check_seedable::<Murmur64AHasher>(true);
+        check_seedable::<Murmur64BHasher>(true);
+    }
+}
@@ -12,9 +12,17 @@
 //!
 //! "tpop" is *The Practice of Programming*. This page shows three
 //! classic hashing algorithms.
+//!
+//! This module also has the "well known" functions, and the string
+//! hashes of some language runtimes: Java, PHP and Perl.
+//!
+//! Apart from Perl's, which takes a seed, these Hashers are seeded by
+//! exclusive-oring the seed into their initial value.
 use std::hash::Hasher;
 use std::num::Wrapping;
 
+use SeedableHasher;
+
 // ====================================
 // DJB2
 
@@ -50,6 +58,10 @@ use std::num::Wrapping;
 /// > has a easily detectable flaws. For example, there's a 3-into-2
 /// > funnel that 0x0021 and 0x0100 both have the same hash (hex
 /// > 0x21, decimal 33) (you saw that one coming, yes?).
+///
+/// This is the xor version, computed in 32 bits; DJB2Hasher64 computes it
+/// in 64 bits, and DJB2AdditiveHasher32 and DJB2AdditiveHasher64 compute
+/// the original, additive version.
 pub struct DJB2Hasher(Wrapping<u32>);
 
 impl Hasher for DJB2Hasher {
@@ -61,23 +73,113 @@ impl Hasher for DJB2Hasher {
     #[inline]
     fn write(&mut self, bytes: &[u8]) {
         for byte in bytes.iter() {
-            self.0 = self.0 + (self.0 << 5) ^ Wrapping(*byte as u32);
+            self.0 = (self.0 + (self.0 << 5)) ^ Wrapping(*byte as u32);
         }
     }
 }
 
 default_for_constant!(DJB2Hasher, Wrapping(5381));
+seedable_for_constant!(DJB2Hasher, seed => Wrapping(5381 ^ seed as u32));
+random_state!(RandomDJB2State, DJB2Hasher);
+hash_collections!(DJB2HashMap, DJB2HashSet, DJB2Hasher);
 hasher_to_fcn!(
     /// Provide access to DJB2Hasher in a single call.
     djb2,
     DJB2Hasher
 );
 
+/// DJB2Hasher, named for its size.
+pub type DJB2Hasher32 = DJB2Hasher;
+
+/// The xor version of DJB2, `hash * 33 ^ byte`, computed in 64 bits.
+pub struct DJB2Hasher64(Wrapping<u64>);
+
+impl Hasher for DJB2Hasher64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = (self.0 + (self.0 << 5)) ^ Wrapping(*byte as u64);
+        }
+    }
+}
+
+default_for_constant!(DJB2Hasher64, Wrapping(5381));
+seedable_for_constant!(DJB2Hasher64, seed => Wrapping(5381 ^ seed));
+random_state!(RandomDJB2State64, DJB2Hasher64);
+hash_collections!(DJB2HashMap64, DJB2HashSet64, DJB2Hasher64);
+hasher_to_fcn!(
+    /// Provide access to DJB2Hasher64 in a single call.
+    djb2_64,
+    DJB2Hasher64
+);
+
+/// The original, additive version of DJB2, `hash * 33 + byte`, computed
+/// in 32 bits.
+pub struct DJB2AdditiveHasher32(Wrapping<u32>);
+
+impl Hasher for DJB2AdditiveHasher32 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0 as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = self.0 + (self.0 << 5) + Wrapping(*byte as u32);
+        }
+    }
+}
+
+default_for_constant!(DJB2AdditiveHasher32, Wrapping(5381));
+seedable_for_constant!(DJB2AdditiveHasher32, seed => Wrapping(5381 ^ seed as u32));
+random_state!(RandomDJB2AdditiveState32, DJB2AdditiveHasher32);
+hash_collections!(DJB2AdditiveHashMap32, DJB2AdditiveHashSet32, DJB2AdditiveHasher32);
+hasher_to_fcn!(
+    /// Provide access to DJB2AdditiveHasher32 in a single call.
+    djb2_additive32,
+    DJB2AdditiveHasher32
+);
+
+/// The additive version of DJB2, computed in 64 bits.
+pub struct DJB2AdditiveHasher64(Wrapping<u64>);
+
+impl Hasher for DJB2AdditiveHasher64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = self.0 + (self.0 << 5) + Wrapping(*byte as u64);
+        }
+    }
+}
+
+default_for_constant!(DJB2AdditiveHasher64, Wrapping(5381));
+seedable_for_constant!(DJB2AdditiveHasher64, seed => Wrapping(5381 ^ seed));
+random_state!(RandomDJB2AdditiveState64, DJB2AdditiveHasher64);
+hash_collections!(DJB2AdditiveHashMap64, DJB2AdditiveHashSet64, DJB2AdditiveHasher64);
+hasher_to_fcn!(
+    /// Provide access to DJB2AdditiveHasher64 in a single call.
+    djb2_additive64,
+    DJB2AdditiveHasher64
+);
+
 // ------------------------------------
 
 #[cfg(test)]
 mod djb2_tests {
     use super::*;
+    use check_seedable;
+    use smhasher_verification;
 
     #[test]
     fn basic() {
@@ -86,6 +188,42 @@ mod djb2_tests {
         assert_eq!(djb2(b"b"), 177607);
         assert_eq!(djb2(b"ab"), 5860902);
     }
+
+    #[test]
+    fn sizes() {
+        let fox = b"The quick brown fox jumps over the lazy dog";
+        assert_eq!(djb2_64(b""), 5381);
+        assert_eq!(djb2_64(b"ab"), 5860902);
+        assert_eq!(djb2_64(b"hello world"), 13769359310528598853);
+        assert_eq!(djb2_64(fox), 16177768371041449994);
+        assert_eq!(djb2_additive32(b""), 5381);
+        assert_eq!(djb2_additive32(b"a"), 177670);
+        assert_eq!(djb2_additive32(b"ab"), 5863208);
+        assert_eq!(djb2_additive32(b"hello world"), 894552257);
+        assert_eq!(djb2_additive32(fox), 885799134);
+        assert_eq!(djb2_additive64(b"ab"), 5863208);
+        assert_eq!(djb2_additive64(b"hello world"), 13876786532495509697);
+        assert_eq!(djb2_additive64(fox), 3950289020261251294);
+    }
+
+    #[test]
+    fn verification() {
+        // SMHasher's "bernstein", seeded through the initial hash.
+        let v = smhasher_verification(4, |key, seed| {
+            let mut hasher = DJB2AdditiveHasher32(Wrapping(seed));
+            hasher.write(key);
+            (hasher.finish() as u32).to_le_bytes().to_vec()
+        });
+        assert_eq!(v, 0xBDB4B640);
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<DJB2Hasher>(true);
+        check_seedable::<DJB2Hasher64>(true);
+        check_seedable::<DJB2AdditiveHasher32>(true);
+        check_seedable::<DJB2AdditiveHasher64>(true);
+    }
 }
 
 // ====================================
@@ -124,17 +262,52 @@ impl Hasher for SDBMHasher {
 }
 
 default_for_constant!(SDBMHasher, Wrapping(0));
+seedable_for_constant!(SDBMHasher, seed => Wrapping(seed as u32));
+random_state!(RandomSDBMState, SDBMHasher);
+hash_collections!(SDBMHashMap, SDBMHashSet, SDBMHasher);
 hasher_to_fcn!(
     /// Provide access to SDBMHasher in a single call.
     sdbm,
     SDBMHasher
 );
 
+/// SDBMHasher, named for its size.
+pub type SDBMHasher32 = SDBMHasher;
+
+/// SDBM, computed in 64 bits.
+pub struct SDBMHasher64(Wrapping<u64>);
+
+impl Hasher for SDBMHasher64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = Wrapping(*byte as u64) + (self.0 << 6) + (self.0 << 16) - self.0;
+        }
+    }
+}
+
+default_for_constant!(SDBMHasher64, Wrapping(0));
+seedable_for_constant!(SDBMHasher64, seed => Wrapping(seed));
+random_state!(RandomSDBMState64, SDBMHasher64);
+hash_collections!(SDBMHashMap64, SDBMHashSet64, SDBMHasher64);
+hasher_to_fcn!(
+    /// Provide access to SDBMHasher64 in a single call.
+    sdbm64,
+    SDBMHasher64
+);
+
 // ------------------------------------
 
 #[cfg(test)]
 mod sdbm_tests {
     use super::*;
+    use check_seedable;
+    use smhasher_verification;
 
     #[test]
     fn basic() {
@@ -143,6 +316,34 @@ mod sdbm_tests {
         assert_eq!(sdbm(b"b"), 98);
         assert_eq!(sdbm(b"ab"), 6363201);
     }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(sdbm64(b""), 0);
+        assert_eq!(sdbm64(b"ab"), 6363201);
+        assert_eq!(sdbm64(b"hello world"), 3262740067968255172);
+        assert_eq!(
+            sdbm64(b"The quick brown fox jumps over the lazy dog"),
+            5076848107273548147
+        );
+    }
+
+    #[test]
+    fn verification() {
+        // SMHasher's "sdbm", seeded through the initial hash.
+        let v = smhasher_verification(4, |key, seed| {
+            let mut hasher = SDBMHasher(Wrapping(seed));
+            hasher.write(key);
+            (hasher.finish() as u32).to_le_bytes().to_vec()
+        });
+        assert_eq!(v, 0x582AF769);
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<SDBMHasher>(true);
+        check_seedable::<SDBMHasher64>(true);
+    }
 }
 
 // ====================================
@@ -180,6 +381,9 @@ impl Hasher for LoseLoseHasher {
 }
 
 default_for_constant!(LoseLoseHasher, Wrapping(0));
+seedable_for_constant!(LoseLoseHasher, seed => Wrapping(seed));
+random_state!(RandomLoseLoseState, LoseLoseHasher);
+hash_collections!(LoseLoseHashMap, LoseLoseHashSet, LoseLoseHasher);
 hasher_to_fcn!(
     /// Provide access to LoseLoseHasher in a single call.
     loselose,
@@ -191,6 +395,7 @@ hasher_to_fcn!(
 #[cfg(test)]
 mod loselose_tests {
     use super::*;
+    use check_seedable;
 
     #[test]
     fn basic() {
@@ -199,4 +404,445 @@ mod loselose_tests {
         assert_eq!(loselose(b"b"), 98);
         assert_eq!(loselose(b"ab"), 195);
     }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<LoseLoseHasher>(true);
+    }
+}
+
+// ====================================
+// K&R 2nd edition
+
+/// The hash function from the 2nd edition of the K&R C book,
+/// `hash * 31 + byte`, computed in 32 bits.
+///
+/// The book reduces the result modulo the table size; this Hasher
+/// returns the whole value.
+pub struct KR2Hasher(Wrapping<u32>);
+
+impl Hasher for KR2Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0 as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = Wrapping(*byte as u32) + Wrapping(31) * self.0;
+        }
+    }
+}
+
+default_for_constant!(KR2Hasher, Wrapping(0));
+seedable_for_constant!(KR2Hasher, seed => Wrapping(seed as u32));
+random_state!(RandomKR2State, KR2Hasher);
+hash_collections!(KR2HashMap, KR2HashSet, KR2Hasher);
+hasher_to_fcn!(
+    /// Provide access to KR2Hasher in a single call.
+    kr2,
+    KR2Hasher
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod kr2_tests {
+    use super::*;
+    use check_seedable;
+
+    #[test]
+    fn basic() {
+        assert_eq!(kr2(b""), 0);
+        assert_eq!(kr2(b"a"), 97);
+        assert_eq!(kr2(b"ab"), 3105);
+        assert_eq!(kr2(b"printf"), 3314983769);
+        assert_eq!(
+            kr2(b"The quick brown fox jumps over the lazy dog"),
+            3685539155
+        );
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<KR2Hasher>(true);
+    }
+}
+
+// ====================================
+// ELF/PJW
+
+/// The hash function from the System V ELF object file format's symbol
+/// hash table, a 32-bit form of P. J. Weinberger's hash from his C
+/// compiler (popularized by the Dragon Book).
+///
+/// The top four bits of the hash are folded back into bits 4-7 and then
+/// cleared, so the result always fits in 28 bits.
+pub struct ELFHasher(Wrapping<u32>);
+
+impl Hasher for ELFHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0 as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = (self.0 << 4) + Wrapping(*byte as u32);
+            let g = self.0 & Wrapping(0xf000_0000);
+            self.0 ^= g >> 24;
+            self.0 &= !g;
+        }
+    }
+}
+
+default_for_constant!(ELFHasher, Wrapping(0));
+seedable_for_constant!(ELFHasher, seed => Wrapping(seed as u32));
+random_state!(RandomELFState, ELFHasher);
+hash_collections!(ELFHashMap, ELFHashSet, ELFHasher);
+hasher_to_fcn!(
+    /// Provide access to ELFHasher in a single call.
+    elf,
+    ELFHasher
+);
+
+/// ELFHasher, under its original name. For 32-bit words the two
+/// are the same function.
+pub type PJWHasher = ELFHasher;
+
+// ------------------------------------
+
+#[cfg(test)]
+mod elf_tests {
+    use super::*;
+    use check_seedable;
+
+    #[test]
+    fn basic() {
+        assert_eq!(elf(b""), 0);
+        assert_eq!(elf(b"a"), 0x61);
+        assert_eq!(elf(b"printf"), 0x077905a6);
+        assert_eq!(elf(b"hello world"), 0x0114ac14);
+        assert_eq!(
+            elf(b"The quick brown fox jumps over the lazy dog"),
+            0x04280c57
+        );
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<ELFHasher>(true);
+    }
+}
+
+// ====================================
+// Java
+
+/// Java's `String.hashCode`, `h * 31 + c` over the string's UTF-16
+/// code units, in 32-bit two's complement arithmetic.
+///
+/// Each byte passed to `write` is taken as one code unit, which matches
+/// Java for strings of Latin-1 characters. For any other string, hash
+/// its UTF-16 code units with `write_utf16` (or `write_u16`, which takes
+/// a single code unit), or use `java_string_hash`. Note that the `Hash`
+/// impl for `str` writes UTF-8 plus a terminating 0xff byte, so
+/// `"abc".hash(&mut hasher)` does not give Java's value.
+///
+/// `finish` returns the hash code as an unsigned 32-bit value;
+/// `hash_code` returns it as Java's `int`.
+pub struct JavaHasher(Wrapping<i32>);
+
+impl JavaHasher {
+    /// Hash a sequence of UTF-16 code units.
+    #[inline]
+    pub fn write_utf16(&mut self, units: &[u16]) {
+        for unit in units.iter() {
+            self.write_u16(*unit);
+        }
+    }
+
+    /// The hash code, as Java would return it.
+    #[inline]
+    pub fn hash_code(&self) -> i32 {
+        (self.0).0
+    }
+}
+
+impl Hasher for JavaHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0 as u32 as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.write_u16(*byte as u16);
+        }
+    }
+
+    #[inline]
+    fn write_u16(&mut self, unit: u16) {
+        self.0 = Wrapping(31) * self.0 + Wrapping(unit as i32);
+    }
+}
+
+default_for_constant!(JavaHasher, Wrapping(0));
+seedable_for_constant!(JavaHasher, seed => Wrapping(seed as i32));
+random_state!(RandomJavaState, JavaHasher);
+hash_collections!(JavaHashMap, JavaHashSet, JavaHasher);
+hasher_to_fcn!(
+    /// Provide access to JavaHasher in a single call, treating each byte
+    /// as a Latin-1 character.
+    java,
+    JavaHasher
+);
+
+/// Compute Java's `String.hashCode` for `s`.
+pub fn java_string_hash(s: &str) -> i32 {
+    let mut hasher = JavaHasher::default();
+    for unit in s.encode_utf16() {
+        hasher.write_u16(unit);
+    }
+    hasher.hash_code()
+}
+
+// ------------------------------------
+
+#[cfg(test)]
+mod java_tests {
+    use super::*;
+    use check_seedable;
+
+    #[test]
+    fn basic() {
+        assert_eq!(java(b""), 0);
+        assert_eq!(java(b"a"), 97);
+        assert_eq!(java(b"hello world"), 1794106052);
+        assert_eq!(java(b"printf"), (-979983527i32) as u32 as u64);
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(java_string_hash(""), 0);
+        assert_eq!(java_string_hash("hello"), 99162322);
+        assert_eq!(java_string_hash("Hello World"), -862545276);
+        assert_eq!(java_string_hash("h\u{e9}llo"), 103094734);
+        // A surrogate pair.
+        assert_eq!(java_string_hash("\u{1f600}"), 1772899);
+        assert_eq!(java_string_hash("a\u{1f600}b"), 57849694);
+    }
+
+    #[test]
+    fn latin1_bytes() {
+        // For Latin-1 text, bytes and code units agree.
+        let mut hasher = JavaHasher::default();
+        hasher.write(&[b'h', 0xe9, b'l', b'l', b'o']);
+        assert_eq!(hasher.hash_code(), java_string_hash("h\u{e9}llo"));
+
+        let mut hasher = JavaHasher::default();
+        hasher.write_utf16(&[0xd83d, 0xde00]);
+        assert_eq!(hasher.hash_code(), java_string_hash("\u{1f600}"));
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<JavaHasher>(true);
+    }
+}
+
+// ====================================
+// PHP's DJBX33A
+
+/// DJBX33A ("Daniel J. Bernstein, Times 33 with Addition"), the string
+/// hash of PHP's hash tables, as computed by `zend_inline_hash_func` in
+/// PHP 7 and later on 64-bit platforms.
+///
+/// This is DJB2AdditiveHasher64 with the high bit of the result set, so
+/// that PHP can reserve zero to mean "not yet hashed".
+pub struct DJBX33AHasher(Wrapping<u64>);
+
+impl Hasher for DJBX33AHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.0).0 | 0x8000_0000_0000_0000
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = self.0 + (self.0 << 5) + Wrapping(*byte as u64);
+        }
+    }
+}
+
+default_for_constant!(DJBX33AHasher, Wrapping(5381));
+seedable_for_constant!(DJBX33AHasher, seed => Wrapping(5381 ^ seed));
+random_state!(RandomDJBX33AState, DJBX33AHasher);
+hash_collections!(DJBX33AHashMap, DJBX33AHashSet, DJBX33AHasher);
+hasher_to_fcn!(
+    /// Provide access to DJBX33AHasher in a single call.
+    djbx33a,
+    DJBX33AHasher
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod djbx33a_tests {
+    use super::*;
+    use check_seedable;
+
+    #[test]
+    fn basic() {
+        assert_eq!(djbx33a(b""), 0x8000000000001505);
+        assert_eq!(djbx33a(b"a"), 0x800000000002b606);
+        assert_eq!(djbx33a(b"hello world"), 0xc0943fd43551c8c1);
+        assert_eq!(
+            djbx33a(b"The quick brown fox jumps over the lazy dog"),
+            0xb6d23eef34cc38de
+        );
+    }
+
+    #[test]
+    fn additive() {
+        for key in [&b"ab"[..], b"hello world", b"printf"].iter() {
+            assert_eq!(djbx33a(key), djb2_additive64(key) | 1 << 63);
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<DJBX33AHasher>(true);
+    }
+}
+
+// ====================================
+// Perl's one-at-a-time-hard
+
+fn one_at_a_time_hard(seed: &[u8; 8], mut hash: Wrapping<u32>, key: &[u8]) -> u32 {
+    for byte in key.iter().chain(seed[4..].iter()) {
+        hash += hash << 10;
+        hash ^= hash >> 6;
+        hash += Wrapping(*byte as u32);
+    }
+    hash += hash << 10;
+    hash ^= hash >> 6;
+
+    hash += hash << 3;
+    hash ^= hash >> 11;
+    hash += hash << 15;
+    hash.0
+}
+
+/// Bob Jenkins' one-at-a-time hash as hardened for Perl 5.18's hash
+/// tables, `one_at_a_time_hard`.
+///
+/// Perl uses an 8-byte seed. The first four bytes, plus the length of
+/// the key, are the initial hash; the last four are mixed in after the
+/// key. The seed's first four bytes are read little-endian, as on x86.
+///
+/// Because the length comes first, this Hasher buffers its input until
+/// `finish`.
+pub struct PerlOAATHardHasher {
+    seed: [u8; 8],
+    // The input so far.
+    buf: Vec<u8>,
+}
+
+impl PerlOAATHardHasher {
+    /// Create a hasher with Perl's 8-byte seed.
+    pub fn new(seed: [u8; 8]) -> PerlOAATHardHasher {
+        PerlOAATHardHasher {
+            seed,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl Hasher for PerlOAATHardHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let s = &self.seed;
+        let initial = u32::from_le_bytes([s[0], s[1], s[2], s[3]]);
+        let hash = Wrapping(initial) + Wrapping(self.buf.len() as u32);
+        one_at_a_time_hard(s, hash, &self.buf) as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+impl Default for PerlOAATHardHasher {
+    fn default() -> PerlOAATHardHasher {
+        PerlOAATHardHasher::new([0; 8])
+    }
+}
+
+/// The seed's bytes, little-endian, are Perl's seed.
+impl SeedableHasher for PerlOAATHardHasher {
+    fn with_seed(seed: u64) -> PerlOAATHardHasher {
+        PerlOAATHardHasher::new(seed.to_le_bytes())
+    }
+}
+
+random_state!(RandomPerlOAATHardState, PerlOAATHardHasher);
+hash_collections!(PerlOAATHardHashMap, PerlOAATHardHashSet, PerlOAATHardHasher);
+hasher_to_fcn!(
+    /// Provide access to PerlOAATHardHasher in a single call.
+    perl_oaat_hard,
+    PerlOAATHardHasher
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod perl_oaat_hard_tests {
+    use super::*;
+    use check_seedable;
+    use smhasher_verification;
+
+    #[test]
+    fn basic() {
+        assert_eq!(perl_oaat_hard(b""), 0);
+        // The length goes into the initial hash.
+        let mut hasher = PerlOAATHardHasher::new([3, 0, 0, 0, 0, 0, 0, 0]);
+        hasher.write(b"abc");
+        let expected = one_at_a_time_hard(&[0; 8], Wrapping(6), b"abc");
+        assert_eq!(hasher.finish(), expected as u64);
+    }
+
+    #[test]
+    fn fragments() {
+        let mut hasher = PerlOAATHardHasher::new(*b"01234567");
+        hasher.write(b"hello");
+        hasher.write(b" ");
+        hasher.write(b"world");
+        let mut whole = PerlOAATHardHasher::new(*b"01234567");
+        whole.write(b"hello world");
+        assert_eq!(hasher.finish(), whole.finish());
+    }
+
+    #[test]
+    fn verification() {
+        // SMHasher's "JenkinsOOAT" leaves out the length and widens its
+        // 32-bit seed, so the last four seed bytes are zero.
+        let v = smhasher_verification(4, |key, seed| {
+            let mut s = [0; 8];
+            s[..4].copy_from_slice(&seed.to_le_bytes());
+            one_at_a_time_hard(&s, Wrapping(seed), key)
+                .to_le_bytes()
+                .to_vec()
+        });
+        assert_eq!(v, 0x83E133DA);
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<PerlOAATHardHasher>(true);
+    }
 }
@@ -61,13 +61,24 @@
 //! Unfortunately, although this *looks* like a good hash function, it is
 //! very slow, likely because it processes the input one byte at a time. If
 //! it were modified to correctly handle a larger block, it might actually
-//! be competitive.
+//! be competitive. Bricolage64 is that modification.
+//!
+//! This module also has the functions the series compares Bricolage to,
+//! from parts IV and V, and the reversible scrambler from "mild
+//! obfuscation." They follow the articles' descriptions: each is a
+//! combining step (an addition or an exclusive or) followed by a
+//! confusion step (a shift, rotation, `cut_deck` or `perfect_shuffle`),
+//! applied one byte at a time. A seed is the starting hash (or, for the
+//! scrambler, the key).
 
 use std::hash::Hasher;
+use std::{mem, ptr};
+
+use SeedableHasher;
 
 #[inline]
 fn cut_deck(x: u64) -> u64 {
-    (x.wrapping_shl(32) | x.wrapping_shr(32))
+    x.wrapping_shl(32) | x.wrapping_shr(32)
 }
 
 #[inline]
@@ -87,9 +98,284 @@ fn perfect_shuffle_64(mut x: u64) -> u64 {
     xh.wrapping_shl(32) | xl
 }
 
+// Each step of perfect_shuffle_32 swaps two fields, so undoing them in
+// reverse order inverts it.
+#[inline]
+fn perfect_unshuffle_32(mut x: u32) -> u32 {
+    x = (x & 0x99999999u32) | (x & 0x44444444u32).wrapping_shr(1) | (x & 0x22222222u32).wrapping_shl(1);
+    x = (x & 0xc3c3c3c3u32) | (x & 0x30303030u32).wrapping_shr(2) | (x & 0x0c0c0c0cu32).wrapping_shl(2);
+    x = (x & 0xf00ff00fu32) | (x & 0x0f000f00u32).wrapping_shr(4) | (x & 0x00f000f0u32).wrapping_shl(4);
+    x = (x & 0xff0000ffu32) | (x & 0x00ff0000u32).wrapping_shr(8) | (x & 0x0000ff00u32).wrapping_shl(8);
+    x
+}
+
+#[inline]
+fn perfect_unshuffle_64(x: u64) -> u64 {
+    let xh = perfect_unshuffle_32(x.wrapping_shr(32) as u32) as u64;
+    let xl = perfect_unshuffle_32(x as u32) as u64;
+    cut_deck(xh.wrapping_shl(32) | xl)
+}
+
+// ====================================
+// Three bad functions
+
+/// The sum of the bytes, from part IV.
+///
+/// Anagrams collide, and the hash barely uses the high bits of the
+/// word.
+pub struct Checksum(u64);
+
+default_for_constant!(Checksum, 0);
+seedable_for_constant!(Checksum, seed => seed);
+
+impl Hasher for Checksum {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = self.0.wrapping_add(*byte as u64);
+        }
+    }
+}
+
+random_state!(RandomChecksumState, Checksum);
+hash_collections!(ChecksumHashMap, ChecksumHashSet, Checksum);
+hasher_to_fcn!(
+    /// Provide access to Checksum in a single call.
+    checksum,
+    Checksum
+);
+
+/// Shift the hash left one bit and add the byte, from part IV.
+///
+/// Only the last 64 bytes affect the hash, and the low bits depend on
+/// the last few bytes alone.
+pub struct ShiftAdd(u64);
+
+default_for_constant!(ShiftAdd, 0);
+seedable_for_constant!(ShiftAdd, seed => seed);
+
+impl Hasher for ShiftAdd {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = self.0.wrapping_shl(1).wrapping_add(*byte as u64);
+        }
+    }
+}
+
+random_state!(RandomShiftAddState, ShiftAdd);
+hash_collections!(ShiftAddHashMap, ShiftAddHashSet, ShiftAdd);
+hasher_to_fcn!(
+    /// Provide access to ShiftAdd in a single call.
+    shift_add,
+    ShiftAdd
+);
+
+/// Rotate the hash left five bits and exclusive-or the byte, Knuth's
+/// string hash, from part IV.
+///
+/// Nothing carries between bits, so each output bit is the parity of a
+/// fixed set of input bits.
+pub struct RotateXor(u64);
+
+default_for_constant!(RotateXor, 0);
+seedable_for_constant!(RotateXor, seed => seed);
+
+impl Hasher for RotateXor {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = self.0.rotate_left(5) ^ (*byte as u64);
+        }
+    }
+}
+
+random_state!(RandomRotateXorState, RotateXor);
+hash_collections!(RotateXorHashMap, RotateXorHashSet, RotateXor);
+hasher_to_fcn!(
+    /// Provide access to RotateXor in a single call.
+    rotate_xor,
+    RotateXor
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod bad_tests {
+    use super::*;
+    use check_seedable;
+
+    #[test]
+    fn basic() {
+        assert_eq!(checksum(b""), 0);
+        assert_eq!(checksum(b"ab"), 195);
+        assert_eq!(checksum(b"ba"), 195);
+        assert_eq!(shift_add(b""), 0);
+        assert_eq!(shift_add(b"ab"), 292);
+        assert_eq!(shift_add(&[1; 65]), 0xffffffffffffffff);
+        assert_eq!(rotate_xor(b""), 0);
+        assert_eq!(rotate_xor(b"ab"), 3138);
+        assert_eq!(rotate_xor(b"ba"), 3105);
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Checksum>(true);
+        check_seedable::<ShiftAdd>(true);
+        check_seedable::<RotateXor>(true);
+    }
+}
+
+// ====================================
+// Three somewhat better functions
+
+/// Add the byte and swap the halves of the hash with `cut_deck`, from
+/// part V.
+pub struct CutDeck(u64);
+
+default_for_constant!(CutDeck, 0);
+seedable_for_constant!(CutDeck, seed => seed);
+
+impl Hasher for CutDeck {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = cut_deck(self.0.wrapping_add(*byte as u64));
+        }
+    }
+}
+
+random_state!(RandomCutDeckState, CutDeck);
+hash_collections!(CutDeckHashMap, CutDeckHashSet, CutDeck);
+hasher_to_fcn!(
+    /// Provide access to CutDeck in a single call.
+    cut_deck_hash,
+    CutDeck
+);
+
+/// Add the byte and interleave the halves of the hash with
+/// `perfect_shuffle`, from part V.
+pub struct PerfectShuffle(u64);
+
+default_for_constant!(PerfectShuffle, 0);
+seedable_for_constant!(PerfectShuffle, seed => seed);
+
+impl Hasher for PerfectShuffle {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = perfect_shuffle_64(self.0.wrapping_add(*byte as u64));
+        }
+    }
+}
+
+random_state!(RandomPerfectShuffleState, PerfectShuffle);
+hash_collections!(PerfectShuffleHashMap, PerfectShuffleHashSet, PerfectShuffle);
+hasher_to_fcn!(
+    /// Provide access to PerfectShuffle in a single call.
+    perfect_shuffle,
+    PerfectShuffle
+);
+
+/// Exclusive-or the byte, then `perfect_shuffle` and `cut_deck` the
+/// hash, from part V.
+pub struct ShuffleCutDeck(u64);
+
+default_for_constant!(ShuffleCutDeck, 0);
+seedable_for_constant!(ShuffleCutDeck, seed => seed);
+
+impl Hasher for ShuffleCutDeck {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = cut_deck(perfect_shuffle_64(self.0 ^ (*byte as u64)));
+        }
+    }
+}
+
+random_state!(RandomShuffleCutDeckState, ShuffleCutDeck);
+hash_collections!(ShuffleCutDeckHashMap, ShuffleCutDeckHashSet, ShuffleCutDeck);
+hasher_to_fcn!(
+    /// Provide access to ShuffleCutDeck in a single call.
+    shuffle_cut_deck,
+    ShuffleCutDeck
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod better_tests {
+    use super::*;
+    use check_seedable;
+
+    #[test]
+    fn shuffles() {
+        assert_eq!(cut_deck(0x0123456789abcdef), 0x89abcdef01234567);
+        assert_eq!(perfect_shuffle_32(0xffff0000), 0xaaaaaaaa);
+        assert_eq!(perfect_shuffle_32(0x0000ffff), 0x55555555);
+        for &x in [0u64, 1, 0x0123456789abcdef, MAGIC, !0].iter() {
+            assert_eq!(perfect_unshuffle_64(perfect_shuffle_64(x)), x);
+        }
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(cut_deck_hash(b""), 0);
+        assert_eq!(cut_deck_hash(b"a"), 97 << 32);
+        assert_eq!(cut_deck_hash(b"ab"), (98 << 32) + 97);
+        assert_eq!(perfect_shuffle(b""), 0);
+        assert_eq!(perfect_shuffle(b"a"), perfect_shuffle_64(97));
+        assert_eq!(shuffle_cut_deck(b""), 0);
+        assert_eq!(shuffle_cut_deck(b"a"), cut_deck(perfect_shuffle_64(97)));
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<CutDeck>(true);
+        check_seedable::<PerfectShuffle>(true);
+        check_seedable::<ShuffleCutDeck>(true);
+    }
+}
+
+// ====================================
+// And a good one
+
+/// Multiply the byte by a large prime, `perfect_shuffle` the product,
+/// add it, and `cut_deck` the hash, from part VI.
 pub struct Bricolage(u64);
 
 default_for_constant!(Bricolage, 0);
+seedable_for_constant!(Bricolage, seed => seed);
 
 const MAGIC: u64 = 173773926194192273u64;
 
@@ -108,6 +394,8 @@ impl Hasher for Bricolage {
     }
 }
 
+random_state!(RandomBricolageState, Bricolage);
+hash_collections!(BricolageHashMap, BricolageHashSet, Bricolage);
 hasher_to_fcn!(
     /// Provide access to Bricolage in a single call.
     bricolage,
@@ -119,6 +407,7 @@ hasher_to_fcn!(
 #[cfg(test)]
 mod bricolage_tests {
     use super::*;
+    use check_seedable;
 
     #[test]
     fn basic() {
@@ -127,5 +416,267 @@ mod bricolage_tests {
         assert_eq!(bricolage(b"b"),  12457347154332739726);
         assert_eq!(bricolage(b"ab"), 16461606921607156355);
     }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Bricolage>(true);
+    }
+}
+
+/// Bricolage, taking the input eight bytes at a time.
+///
+/// Each little-endian u64 word of the input goes through Bricolage's
+/// step: multiply by the prime, `perfect_shuffle`, add to the hash and
+/// `cut_deck`. A final partial word is padded with zeros, and the total
+/// length of the input is mixed in last, so inputs that differ only by
+/// trailing zeros hash differently. The result does not depend on how
+/// the input is split across writes.
+#[derive(Default)]
+pub struct Bricolage64 {
+    hash: u64,
+    // Bytes not yet mixed, packed little-endian.
+    tail: u64,
+    // The number of bytes in tail.
+    tail_len: usize,
+    // The total number of bytes written.
+    len: u64,
+}
+
+#[inline]
+fn bricolage_step(hash: u64, word: u64) -> u64 {
+    cut_deck(hash.wrapping_add(perfect_shuffle_64(word.wrapping_mul(MAGIC))))
+}
+
+impl Bricolage64 {
+    // Add bytes to the tail, mixing it in when it is full.
+    #[inline]
+    fn push(&mut self, bytes: u64, n: usize) {
+        self.tail |= bytes << (8 * self.tail_len);
+        self.tail_len += n;
+        if self.tail_len == 8 {
+            self.hash = bricolage_step(self.hash, self.tail);
+            self.tail = 0;
+            self.tail_len = 0;
+        }
+    }
 }
 
+impl SeedableHasher for Bricolage64 {
+    fn with_seed(seed: u64) -> Bricolage64 {
+        Bricolage64 {
+            hash: seed,
+            ..Bricolage64::default()
+        }
+    }
+}
+
+impl Hasher for Bricolage64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut hash = self.hash;
+        if self.tail_len > 0 {
+            hash = bricolage_step(hash, self.tail);
+        }
+        bricolage_step(hash, self.len)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.len = self.len.wrapping_add(bytes.len() as u64);
+        let mut i = 0;
+        while self.tail_len > 0 && i < bytes.len() {
+            self.push(bytes[i] as u64, 1);
+            i += 1;
+        }
+        while i + 8 <= bytes.len() {
+            self.hash = bricolage_step(self.hash, load_int_le!(bytes, i, u64));
+            i += 8;
+        }
+        for byte in bytes[i..].iter() {
+            self.push(*byte as u64, 1);
+        }
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        if self.tail_len <= 4 {
+            self.len = self.len.wrapping_add(4);
+            self.push(i as u64, 4);
+        } else {
+            self.write(&i.to_le_bytes());
+        }
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        if self.tail_len == 0 {
+            self.len = self.len.wrapping_add(8);
+            self.hash = bricolage_step(self.hash, i);
+        } else {
+            self.write(&i.to_le_bytes());
+        }
+    }
+}
+
+random_state!(RandomBricolage64State, Bricolage64);
+hash_collections!(Bricolage64HashMap, Bricolage64HashSet, Bricolage64);
+hasher_to_fcn!(
+    /// Provide access to Bricolage64 in a single call.
+    bricolage64,
+    Bricolage64
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod bricolage64_tests {
+    use super::*;
+    use check_seedable;
+
+    #[test]
+    fn basic() {
+        assert_eq!(bricolage64(b""), bricolage_step(0, 0));
+        assert_eq!(bricolage64(b"a"), bricolage_step(bricolage_step(0, 97), 1));
+        assert_ne!(bricolage64(b"a"), bricolage64(b"a\0"));
+        assert_ne!(bricolage64(b"ab"), bricolage64(b"ba"));
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100u8).collect();
+        let whole = bricolage64(&data);
+        for size in 1..20 {
+            let mut hasher = Bricolage64::default();
+            for chunk in data.chunks(size) {
+                hasher.write(chunk);
+            }
+            assert_eq!(hasher.finish(), whole, "fragment size {}", size);
+        }
+    }
+
+    #[test]
+    fn integers() {
+        // write_u32 and write_u64 agree with writing the bytes, at any
+        // position in the tail.
+        for lead in 0..8 {
+            let mut words = Bricolage64::default();
+            let mut bytes = Bricolage64::default();
+            for k in 0..lead {
+                words.write_u8(k);
+                bytes.write(&[k]);
+            }
+            words.write_u32(0xdeadbeef);
+            bytes.write(&0xdeadbeefu32.to_le_bytes());
+            words.write_u64(0x0123456789abcdef);
+            bytes.write(&0x0123456789abcdefu64.to_le_bytes());
+            words.write_u32(7);
+            bytes.write(&7u32.to_le_bytes());
+            assert_eq!(words.finish(), bytes.finish(), "lead {}", lead);
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Bricolage64>(true);
+    }
+}
+
+// ====================================
+// Mild obfuscation
+
+const MAGIC_INVERSE: u64 = 0x5d25dd9cd3530171u64;
+
+/// Scramble a word reversibly: exclusive-or it with the key, shuffle its
+/// bits with `perfect_shuffle`, and multiply it by an odd constant.
+///
+/// Every step can be undone, so `unscramble` recovers `x` and distinct
+/// words always scramble to distinct words.
+#[inline]
+pub fn scramble(x: u64, key: u64) -> u64 {
+    perfect_shuffle_64(x ^ key).wrapping_mul(MAGIC)
+}
+
+/// Undo `scramble`.
+#[inline]
+pub fn unscramble(x: u64, key: u64) -> u64 {
+    perfect_unshuffle_64(x.wrapping_mul(MAGIC_INVERSE)) ^ key
+}
+
+/// Add each byte to the hash and `scramble` it, with a key.
+pub struct Scrambler {
+    hash: u64,
+    key: u64,
+}
+
+impl Scrambler {
+    /// Create a Scrambler with the given key.
+    pub fn new(key: u64) -> Scrambler {
+        Scrambler { hash: 0, key }
+    }
+}
+
+impl Default for Scrambler {
+    fn default() -> Scrambler {
+        Scrambler::new(0)
+    }
+}
+
+impl SeedableHasher for Scrambler {
+    fn with_seed(seed: u64) -> Scrambler {
+        Scrambler::new(seed)
+    }
+}
+
+impl Hasher for Scrambler {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.hash = scramble(self.hash.wrapping_add(*byte as u64), self.key);
+        }
+    }
+}
+
+random_state!(RandomScramblerState, Scrambler);
+hash_collections!(ScramblerHashMap, ScramblerHashSet, Scrambler);
+hasher_to_fcn!(
+    /// Provide access to Scrambler in a single call.
+    scrambler,
+    Scrambler
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod scrambler_tests {
+    use super::*;
+    use check_seedable;
+
+    #[test]
+    fn inverse() {
+        assert_eq!(MAGIC.wrapping_mul(MAGIC_INVERSE), 1);
+        for &key in [0u64, 0xdeadbeef, !0].iter() {
+            for x in 0..1000u64 {
+                assert_eq!(unscramble(scramble(x, key), key), x);
+            }
+        }
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(scrambler(b""), 0);
+        assert_eq!(scrambler(b"a"), scramble(97, 0));
+        let mut hasher = Scrambler::new(42);
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), scramble(97, 42));
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Scrambler>(true);
+    }
+}
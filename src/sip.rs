@@ -0,0 +1,725 @@
+//! From https://github.com/veorq/SipHash.
+//!
+//! Jean-Philippe Aumasson and Daniel J. Bernstein's SipHash, a keyed
+//! pseudo-random function designed to keep hash tables safe from
+//! flooding attacks, given a secret key. From the reference
+//! implementation's README:
+//!
+//! > SipHash is a family of pseudorandom functions (PRFs) optimized for
+//! > speed on short messages.
+//!
+//! SipHash-c-d runs c rounds per 8-byte word of input and d rounds
+//! during finalization. SipHash-2-4 is the original recommendation;
+//! SipHash-1-3 is the faster variant Rust's `DefaultHasher` uses at the
+//! time of writing. Both have 64- and 128-bit outputs, which differ from
+//! the start: the output size is part of the Hasher's type.
+//!
+//! HalfSipHash is the 32-bit-word variant, intended for 32-bit
+//! platforms, with 32- and 64-bit outputs and a 64-bit key.
+//!
+//! Unlike `builtin::DefaultHasher`, whose algorithm and keys may change
+//! between Rust releases, the Hashers here are explicitly keyed and
+//! their results are fixed by the reference test vectors, so they can be
+//! persisted. `Default` uses an all-zero key, which provides none of
+//! SipHash's protection.
+//!
+//! As a SeedableHasher, a 64-bit seed is k0, with k1 zero, and a 128-bit
+//! seed is (k0, k1); for HalfSipHash, the seed's low and high halves are
+//! k0 and k1.
+
+use std::hash::Hasher;
+use std::{mem, ptr};
+
+use {SeedableHasher, SeedableHasher128};
+
+// ====================================
+// SipHash
+
+/// The SipHash state and the partial word waiting to be hashed.
+#[derive(Clone, Copy)]
+struct Sip {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    c_rounds: usize,
+    d_rounds: usize,
+    // 128-bit output
+    wide: bool,
+    // unhashed data, for partial words
+    buf: [u8; 8],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl Sip {
+    fn new(k0: u64, k1: u64, c_rounds: usize, d_rounds: usize, wide: bool) -> Sip {
+        Sip {
+            v0: k0 ^ 0x736f6d6570736575,
+            v1: k1 ^ 0x646f72616e646f6d ^ if wide { 0xee } else { 0 },
+            v2: k0 ^ 0x6c7967656e657261,
+            v3: k1 ^ 0x7465646279746573,
+            c_rounds,
+            d_rounds,
+            wide,
+            buf: [0; 8],
+            buf_len: 0,
+            total_len: 0,
+        }
+    }
+
+    #[inline]
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13);
+        self.v1 ^= self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16);
+        self.v3 ^= self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21);
+        self.v3 ^= self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17);
+        self.v1 ^= self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    #[inline]
+    fn compress(&mut self, m: u64) {
+        self.v3 ^= m;
+        for _ in 0..self.c_rounds {
+            self.round();
+        }
+        self.v0 ^= m;
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> u64 {
+        for _ in 0..self.d_rounds {
+            self.round();
+        }
+        self.v0 ^ self.v1 ^ self.v2 ^ self.v3
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let mut bytes = bytes;
+        if self.buf_len > 0 {
+            let fill = (8 - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+            if self.buf_len < 8 {
+                return;
+            }
+            let m = load_int_le!(self.buf, 0, u64);
+            self.compress(m);
+            self.buf_len = 0;
+        }
+        let mut words = bytes.chunks_exact(8);
+        for word in &mut words {
+            self.compress(load_int_le!(word, 0, u64));
+        }
+        let rest = words.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+
+    /// Finish the hash, returning (first, second) output words; second
+    /// is 0 for 64-bit output.
+    fn finish(&self) -> (u64, u64) {
+        let mut state = *self;
+        let mut tail = [0u8; 8];
+        tail[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        let b = u64::from_le_bytes(tail) | (self.total_len << 56);
+        state.compress(b);
+        state.v2 ^= if self.wide { 0xee } else { 0xff };
+        let first = state.finalize();
+        if !self.wide {
+            return (first, 0);
+        }
+        state.v1 ^= 0xdd;
+        (first, state.finalize())
+    }
+}
+
+// ------------------------------------
+// SipHash-2-4
+
+/// SipHash-2-4, with 64-bit output.
+pub struct SipHasher24(Sip);
+
+impl SipHasher24 {
+    pub fn new(k0: u64, k1: u64) -> SipHasher24 {
+        SipHasher24(Sip::new(k0, k1, 2, 4, false))
+    }
+}
+
+impl Default for SipHasher24 {
+    fn default() -> SipHasher24 {
+        SipHasher24::new(0, 0)
+    }
+}
+
+impl SeedableHasher for SipHasher24 {
+    fn with_seed(seed: u64) -> SipHasher24 {
+        SipHasher24::new(seed, 0)
+    }
+}
+
+impl SeedableHasher128 for SipHasher24 {
+    fn with_seed128(seed: (u64, u64)) -> SipHasher24 {
+        SipHasher24::new(seed.0, seed.1)
+    }
+}
+
+impl Hasher for SipHasher24 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish().0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomSipState24, SipHasher24, 128);
+hash_collections!(SipHashMap24, SipHashSet24, SipHasher24);
+hasher_to_fcn!(
+    /// Provide access to SipHasher24 in a single call.
+    siphash24,
+    SipHasher24
+);
+
+/// SipHash-2-4, with 128-bit output.
+pub struct Sip24Hasher128(Sip);
+
+impl Sip24Hasher128 {
+    pub fn new(k0: u64, k1: u64) -> Sip24Hasher128 {
+        Sip24Hasher128(Sip::new(k0, k1, 2, 4, true))
+    }
+
+    /// The full 128-bit result, as (low, high): the reference's output
+    /// bytes, read as two little-endian words.
+    pub fn finish128(&self) -> (u64, u64) {
+        self.0.finish()
+    }
+}
+
+impl Default for Sip24Hasher128 {
+    fn default() -> Sip24Hasher128 {
+        Sip24Hasher128::new(0, 0)
+    }
+}
+
+impl SeedableHasher for Sip24Hasher128 {
+    fn with_seed(seed: u64) -> Sip24Hasher128 {
+        Sip24Hasher128::new(seed, 0)
+    }
+}
+
+impl SeedableHasher128 for Sip24Hasher128 {
+    fn with_seed128(seed: (u64, u64)) -> Sip24Hasher128 {
+        Sip24Hasher128::new(seed.0, seed.1)
+    }
+}
+
+impl Hasher for Sip24Hasher128 {
+    /// The low 64 bits of the result.
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish128().0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomSip24State128, Sip24Hasher128, 128);
+hash_collections!(Sip24HashMap128, Sip24HashSet128, Sip24Hasher128);
+hasher_to_fcn!(
+    /// Provide access to Sip24Hasher128 in a single call.
+    siphash24_128,
+    Sip24Hasher128
+);
+
+// ------------------------------------
+// SipHash-1-3
+
+/// SipHash-1-3, with 64-bit output.
+pub struct SipHasher13(Sip);
+
+impl SipHasher13 {
+    pub fn new(k0: u64, k1: u64) -> SipHasher13 {
+        SipHasher13(Sip::new(k0, k1, 1, 3, false))
+    }
+}
+
+impl Default for SipHasher13 {
+    fn default() -> SipHasher13 {
+        SipHasher13::new(0, 0)
+    }
+}
+
+impl SeedableHasher for SipHasher13 {
+    fn with_seed(seed: u64) -> SipHasher13 {
+        SipHasher13::new(seed, 0)
+    }
+}
+
+impl SeedableHasher128 for SipHasher13 {
+    fn with_seed128(seed: (u64, u64)) -> SipHasher13 {
+        SipHasher13::new(seed.0, seed.1)
+    }
+}
+
+impl Hasher for SipHasher13 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish().0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomSipState13, SipHasher13, 128);
+hash_collections!(SipHashMap13, SipHashSet13, SipHasher13);
+hasher_to_fcn!(
+    /// Provide access to SipHasher13 in a single call.
+    siphash13,
+    SipHasher13
+);
+
+/// SipHash-1-3, with 128-bit output.
+pub struct Sip13Hasher128(Sip);
+
+impl Sip13Hasher128 {
+    pub fn new(k0: u64, k1: u64) -> Sip13Hasher128 {
+        Sip13Hasher128(Sip::new(k0, k1, 1, 3, true))
+    }
+
+    /// The full 128-bit result, as (low, high).
+    pub fn finish128(&self) -> (u64, u64) {
+        self.0.finish()
+    }
+}
+
+impl Default for Sip13Hasher128 {
+    fn default() -> Sip13Hasher128 {
+        Sip13Hasher128::new(0, 0)
+    }
+}
+
+impl SeedableHasher for Sip13Hasher128 {
+    fn with_seed(seed: u64) -> Sip13Hasher128 {
+        Sip13Hasher128::new(seed, 0)
+    }
+}
+
+impl SeedableHasher128 for Sip13Hasher128 {
+    fn with_seed128(seed: (u64, u64)) -> Sip13Hasher128 {
+        Sip13Hasher128::new(seed.0, seed.1)
+    }
+}
+
+impl Hasher for Sip13Hasher128 {
+    /// The low 64 bits of the result.
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish128().0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomSip13State128, Sip13Hasher128, 128);
+hash_collections!(Sip13HashMap128, Sip13HashSet128, Sip13Hasher128);
+hasher_to_fcn!(
+    /// Provide access to Sip13Hasher128 in a single call.
+    siphash13_128,
+    Sip13Hasher128
+);
+
+// ====================================
+// HalfSipHash
+
+/// The HalfSipHash-2-4 state; as Sip, but with 32-bit words.
+#[derive(Clone, Copy)]
+struct HalfSip {
+    v0: u32,
+    v1: u32,
+    v2: u32,
+    v3: u32,
+    // 64-bit output
+    wide: bool,
+    // unhashed data, for partial words
+    buf: [u8; 4],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl HalfSip {
+    fn new(k0: u32, k1: u32, wide: bool) -> HalfSip {
+        HalfSip {
+            v0: k0,
+            v1: k1 ^ if wide { 0xee } else { 0 },
+            v2: k0 ^ 0x6c796765,
+            v3: k1 ^ 0x74656462,
+            wide,
+            buf: [0; 4],
+            buf_len: 0,
+            total_len: 0,
+        }
+    }
+
+    #[inline]
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(5);
+        self.v1 ^= self.v0;
+        self.v0 = self.v0.rotate_left(16);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(8);
+        self.v3 ^= self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(7);
+        self.v3 ^= self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13);
+        self.v1 ^= self.v2;
+        self.v2 = self.v2.rotate_left(16);
+    }
+
+    #[inline]
+    fn compress(&mut self, m: u32) {
+        self.v3 ^= m;
+        self.round();
+        self.round();
+        self.v0 ^= m;
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> u32 {
+        for _ in 0..4 {
+            self.round();
+        }
+        // HalfSipHash only folds two of its words into the result
+        self.v1 ^ self.v3
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let mut bytes = bytes;
+        if self.buf_len > 0 {
+            let fill = (4 - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+            if self.buf_len < 4 {
+                return;
+            }
+            let m = load_int_le!(self.buf, 0, u32);
+            self.compress(m);
+            self.buf_len = 0;
+        }
+        let mut words = bytes.chunks_exact(4);
+        for word in &mut words {
+            self.compress(load_int_le!(word, 0, u32));
+        }
+        let rest = words.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+
+    /// Finish the hash, returning (first, second) output words; second
+    /// is 0 for 32-bit output.
+    fn finish(&self) -> (u32, u32) {
+        let mut state = *self;
+        let mut tail = [0u8; 4];
+        tail[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        // the reference implementation only keeps the low byte of the length
+        let b = u32::from_le_bytes(tail) | ((self.total_len as u32) << 24);
+        state.compress(b);
+        state.v2 ^= if self.wide { 0xee } else { 0xff };
+        let first = state.finalize();
+        if !self.wide {
+            return (first, 0);
+        }
+        state.v1 ^= 0xdd;
+        (first, state.finalize())
+    }
+}
+
+/// HalfSipHash-2-4, with 32-bit output.
+pub struct HalfSipHasher(HalfSip);
+
+impl HalfSipHasher {
+    pub fn new(k0: u32, k1: u32) -> HalfSipHasher {
+        HalfSipHasher(HalfSip::new(k0, k1, false))
+    }
+
+    /// The full 32-bit result.
+    pub fn finish32(&self) -> u32 {
+        self.0.finish().0
+    }
+}
+
+impl Default for HalfSipHasher {
+    fn default() -> HalfSipHasher {
+        HalfSipHasher::new(0, 0)
+    }
+}
+
+impl SeedableHasher for HalfSipHasher {
+    fn with_seed(seed: u64) -> HalfSipHasher {
+        HalfSipHasher::new(seed as u32, (seed >> 32) as u32)
+    }
+}
+
+impl Hasher for HalfSipHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomHalfSipState, HalfSipHasher);
+hash_collections!(HalfSipHashMap, HalfSipHashSet, HalfSipHasher);
+hasher_to_fcn!(
+    /// Provide access to HalfSipHasher in a single call.
+    halfsiphash,
+    HalfSipHasher
+);
+
+/// HalfSipHash-2-4, with 64-bit output.
+pub struct HalfSipHasher64(HalfSip);
+
+impl HalfSipHasher64 {
+    pub fn new(k0: u32, k1: u32) -> HalfSipHasher64 {
+        HalfSipHasher64(HalfSip::new(k0, k1, true))
+    }
+}
+
+impl Default for HalfSipHasher64 {
+    fn default() -> HalfSipHasher64 {
+        HalfSipHasher64::new(0, 0)
+    }
+}
+
+impl SeedableHasher for HalfSipHasher64 {
+    fn with_seed(seed: u64) -> HalfSipHasher64 {
+        HalfSipHasher64::new(seed as u32, (seed >> 32) as u32)
+    }
+}
+
+impl Hasher for HalfSipHasher64 {
+    /// The reference's 8 output bytes, read as a little-endian word.
+    #[inline]
+    fn finish(&self) -> u64 {
+        let (first, second) = self.0.finish();
+        (first as u64) | (second as u64) << 32
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+random_state!(RandomHalfSipState64, HalfSipHasher64);
+hash_collections!(HalfSipHashMap64, HalfSipHashSet64, HalfSipHasher64);
+hasher_to_fcn!(
+    /// Provide access to HalfSipHasher64 in a single call.
+    halfsiphash64,
+    HalfSipHasher64
+);
+
+#[cfg(test)]
+mod sip_tests {
+    use super::*;
+    use {check_seedable, check_seedable128, smhasher_verification};
+
+    // The reference test vectors' key, 00 01 02 ... 0f, as two
+    // little-endian words.
+    const K0: u64 = 0x0706050403020100;
+    const K1: u64 = 0x0f0e0d0c0b0a0908;
+
+    fn hash<H: Hasher>(mut hasher: H, n: usize) -> H {
+        let data: Vec<u8> = (0..64).collect();
+        hasher.write(&data[..n]);
+        hasher
+    }
+
+    fn hash_all<H: Hasher>(mut hasher: H, data: &[u8]) -> u64 {
+        hasher.write(data);
+        hasher.finish()
+    }
+
+    #[test]
+    fn siphash() {
+        // (length, SipHash-2-4, SipHash-1-3), for the messages 00 01 02 ...
+        let expected: [(usize, u64, u64); 8] = [
+            (0, 0x726fdb47dd0e0e31, 0xabac0158050fc4dc),
+            (1, 0x74f839c593dc67fd, 0xc9f49bf37d57ca93),
+            (7, 0xab0200f58b01d137, 0xd3927d989bb11140),
+            (8, 0x93f5f5799a932462, 0x369095118d299a8e),
+            (9, 0x9e0082df0ba9e4b0, 0x25a48eb36c063de4),
+            (15, 0xa129ca6149be45e5, 0xd320d86d2a519956),
+            (16, 0x3f2acc7f57c29bdb, 0xcc4fdd1a7d908b66),
+            (63, 0x958a324ceb064572, 0x9d199062b7bbb3a8),
+        ];
+        for &(n, h24, h13) in expected.iter() {
+            assert_eq!(hash(SipHasher24::new(K0, K1), n).finish(), h24, "{}", n);
+            assert_eq!(hash(SipHasher13::new(K0, K1), n).finish(), h13, "{}", n);
+        }
+    }
+
+    // (length, SipHash-2-4-128, SipHash-1-3-128)
+    type Vector128 = (usize, (u64, u64), (u64, u64));
+
+    #[test]
+    fn siphash128() {
+        let expected: [Vector128; 4] = [
+            (
+                0,
+                (0xe6a825ba047f81a3, 0x930255c71472f66d),
+                (0xbea58827b2bc7ee7, 0x013030dd6adb62fd),
+            ),
+            (
+                1,
+                (0x44af996bd8c187da, 0x45fc229b11597634),
+                (0xa8edd36004376ffc, 0x63f02f2bcc73055e),
+            ),
+            (
+                15,
+                (0x11a8b03399e99354, 0xd9c3cf970fec087e),
+                (0x6c52bdb205557ec1, 0x09017e1eeccd2129),
+            ),
+            (
+                63,
+                (0x4a83502f77d15051, 0x7cbd3f979a063e50),
+                (0x6f42fe4ee300584c, 0xad6052a70a6b9f07),
+            ),
+        ];
+        for &(n, h24, h13) in expected.iter() {
+            assert_eq!(
+                hash(Sip24Hasher128::new(K0, K1), n).finish128(),
+                h24,
+                "{}",
+                n
+            );
+            assert_eq!(
+                hash(Sip13Hasher128::new(K0, K1), n).finish128(),
+                h13,
+                "{}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn halfsiphash() {
+        // (length, 32-bit output, 64-bit output), keyed with 00 01 ... 07
+        let expected: [(usize, u32, u64); 8] = [
+            (0, 0x5b9f35a9, 0xc83cb8b9591f8d21),
+            (1, 0xb85a4727, 0x157338f8122455be),
+            (3, 0x04e7fe8a, 0x790606f7451a0fce),
+            (4, 0x89466e2a, 0xa12ee55b178ae7d5),
+            (5, 0x69b6fac5, 0x80b53d2f3f7c9dcb),
+            (7, 0xc563cf8b, 0x84c67bb0282720ff),
+            (8, 0x8f84b8d0, 0x8c85e4bc20e8feed),
+            (63, 0x744aea59, 0x876032bf713ca62e),
+        ];
+        let (k0, k1) = (K0 as u32, (K0 >> 32) as u32);
+        for &(n, h32, h64) in expected.iter() {
+            assert_eq!(hash(HalfSipHasher::new(k0, k1), n).finish32(), h32, "{}", n);
+            assert_eq!(hash(HalfSipHasher64::new(k0, k1), n).finish(), h64, "{}", n);
+        }
+    }
+
+    #[test]
+    fn verification() {
+        // SMHasher keys these with the seed as k0 and hashes the empty
+        // string to 0.
+        fn sip<H: Hasher>(key: &[u8], hasher: H) -> Vec<u8> {
+            if key.is_empty() {
+                return vec![0; 8];
+            }
+            hash_key(key, hasher).to_le_bytes().to_vec()
+        }
+        fn hash_key<H: Hasher>(key: &[u8], mut hasher: H) -> u64 {
+            hasher.write(key);
+            hasher.finish()
+        }
+        let v24 = smhasher_verification(8, |key, seed| sip(key, SipHasher24::new(seed as u64, 0)));
+        assert_eq!(v24, 0xC58D7F9C);
+        let v13 = smhasher_verification(8, |key, seed| sip(key, SipHasher13::new(seed as u64, 0)));
+        assert_eq!(v13, 0x29C010BF);
+        let vhalf = smhasher_verification(4, |key, seed| {
+            let h = if key.is_empty() {
+                0
+            } else {
+                hash_key(key, HalfSipHasher::new(seed, 0)) as u32
+            };
+            h.to_le_bytes().to_vec()
+        });
+        assert_eq!(vhalf, 0xA7A05F72);
+    }
+
+    #[test]
+    fn fragments() {
+        let data: Vec<u8> = (0..100).collect();
+        for split in 0..data.len() {
+            let mut h24 = SipHasher24::new(K0, K1);
+            let mut h128 = Sip13Hasher128::new(K0, K1);
+            let mut half = HalfSipHasher64::new(1, 2);
+            for chunk in data.chunks(split + 1) {
+                h24.write(chunk);
+                h128.write(chunk);
+                half.write(chunk);
+            }
+            assert_eq!(h24.finish(), hash_all(SipHasher24::new(K0, K1), &data));
+            let mut whole = Sip13Hasher128::new(K0, K1);
+            whole.write(&data);
+            assert_eq!(h128.finish128(), whole.finish128());
+            assert_eq!(half.finish(), hash_all(HalfSipHasher64::new(1, 2), &data));
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<SipHasher24>(true);
+        check_seedable128::<SipHasher24>();
+        check_seedable::<Sip24Hasher128>(true);
+        check_seedable128::<Sip24Hasher128>();
+        check_seedable::<SipHasher13>(true);
+        check_seedable128::<SipHasher13>();
+        check_seedable::<Sip13Hasher128>(true);
+        check_seedable128::<Sip13Hasher128>();
+        check_seedable::<HalfSipHasher>(true);
+        check_seedable::<HalfSipHasher64>(true);
+    }
+}
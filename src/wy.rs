@@ -0,0 +1,522 @@
+//! From https://github.com/wangyi-fudan/wyhash and https://github.com/Nicoshev/rapidhash.
+//!
+//! Wang Yi's wyhash, the final version (final4.2), and Nicolas De
+//! Carli's rapidhash (V3), which is derived from it. Both are built
+//! around a 64x64->128-bit multiply, folding the two halves of the
+//! product back together.
+//!
+//! Both take a 64-bit seed and a secret: an array of 64-bit constants
+//! mixed into the input. The default secrets are the reference
+//! implementations'. wyhash's author suggests that a secret's words be
+//! odd, with every byte having four bits set, and pairs of words
+//! differing in 32 bits; rapidhash's secrets follow the same rules.
+//!
+//! The Hashers here are streaming versions of the reference functions,
+//! producing the same results however the input is split up.
+//!
+//! `wyrand` is wyhash's companion pseudo-random number generator,
+//! suitable for generating seeds.
+
+use std::hash::Hasher;
+use std::{mem, ptr};
+
+use SeedableHasher;
+
+/// The full 128-bit product of a and b, as (low, high).
+#[inline]
+fn mum(a: u64, b: u64) -> (u64, u64) {
+    let r = (a as u128) * (b as u128);
+    (r as u64, (r >> 64) as u64)
+}
+
+/// Multiply and fold the halves of the product together.
+#[inline]
+fn mix(a: u64, b: u64) -> u64 {
+    let (lo, hi) = mum(a, b);
+    lo ^ hi
+}
+
+#[inline]
+fn r8(buf: &[u8], i: usize) -> u64 {
+    load_int_le!(buf, i, u64)
+}
+
+#[inline]
+fn r4(buf: &[u8], i: usize) -> u64 {
+    load_int_le!(buf, i, u32) as u64
+}
+
+// ====================================
+// wyhash
+
+/// The default wyhash secret.
+pub const WYHASH_SECRET: [u64; 4] = [
+    0xa0761d6478bd642f,
+    0xe7037ed1a0b428db,
+    0x8ebc6af09c88c6e3,
+    0x589965cc75374cc3,
+];
+
+/// wyhash's pseudo-random number generator: advance the state and
+/// return the next value.
+#[inline]
+pub fn wyrand(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(WYHASH_SECRET[0]);
+    mix(*state, *state ^ WYHASH_SECRET[1])
+}
+
+/// wyhash, final version.
+///
+/// wyhash consumes its input in 48-byte blocks, leaving between 1 and 48
+/// bytes (none, if the input is exactly one block), which it consumes in
+/// 16-byte pieces; then it hashes the last 16 bytes of the input (even if
+/// some of them have already been consumed).
+pub struct WyHasher {
+    secret: [u64; 4],
+    seed: u64,
+    see1: u64,
+    see2: u64,
+    // unhashed data, for partial blocks
+    buf: [u8; 48],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // the last 16 bytes of the last block hashed
+    last: [u8; 16],
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl WyHasher {
+    pub fn new(seed: u64) -> WyHasher {
+        WyHasher::with_secret(seed, WYHASH_SECRET)
+    }
+
+    pub fn with_secret(seed: u64, secret: [u64; 4]) -> WyHasher {
+        let seed = seed ^ mix(seed ^ secret[0], secret[1]);
+        WyHasher {
+            secret,
+            seed,
+            see1: seed,
+            see2: seed,
+            buf: [0; 48],
+            buf_len: 0,
+            last: [0; 16],
+            total_len: 0,
+        }
+    }
+
+    #[inline]
+    fn block(&mut self) {
+        let WyHasher {
+            ref secret,
+            ref mut seed,
+            ref mut see1,
+            ref mut see2,
+            ref buf,
+            ..
+        } = *self;
+        *seed = mix(r8(buf, 0) ^ secret[1], r8(buf, 8) ^ *seed);
+        *see1 = mix(r8(buf, 16) ^ secret[2], r8(buf, 24) ^ *see1);
+        *see2 = mix(r8(buf, 32) ^ secret[3], r8(buf, 40) ^ *see2);
+        self.last.copy_from_slice(&buf[32..]);
+    }
+}
+
+impl Default for WyHasher {
+    fn default() -> WyHasher {
+        WyHasher::new(0)
+    }
+}
+
+impl SeedableHasher for WyHasher {
+    fn with_seed(seed: u64) -> WyHasher {
+        WyHasher::new(seed)
+    }
+}
+
+impl Hasher for WyHasher {
+    fn finish(&self) -> u64 {
+        let secret = &self.secret;
+        let len = self.total_len;
+        let buf = &self.buf[..self.buf_len];
+        let mut seed = self.seed;
+        let a;
+        let b;
+        if len <= 16 {
+            if len >= 4 {
+                let n = buf.len();
+                let skip = (n >> 3) << 2;
+                a = (r4(buf, 0) << 32) | r4(buf, skip);
+                b = (r4(buf, n - 4) << 32) | r4(buf, n - 4 - skip);
+            } else if len > 0 {
+                let n = buf.len();
+                a = ((buf[0] as u64) << 16) | ((buf[n >> 1] as u64) << 8) | buf[n - 1] as u64;
+                b = 0;
+            } else {
+                a = 0;
+                b = 0;
+            }
+        } else {
+            if len >= 48 {
+                seed ^= self.see1 ^ self.see2;
+            }
+            let mut i = 0;
+            while buf.len() - i > 16 {
+                seed = mix(r8(buf, i) ^ secret[1], r8(buf, i + 8) ^ seed);
+                i += 16;
+            }
+            // the last 16 bytes may reach back into the last block
+            let mut tail = [0u8; 64];
+            tail[..16].copy_from_slice(&self.last);
+            tail[16..16 + buf.len()].copy_from_slice(buf);
+            a = r8(&tail, buf.len());
+            b = r8(&tail, buf.len() + 8);
+        }
+        let (a, b) = mum(a ^ secret[1], b ^ seed);
+        mix(a ^ secret[0] ^ len, b ^ secret[1])
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            // later blocks are only hashed once we know more follows them
+            if self.buf_len == 48 {
+                self.block();
+                self.buf_len = 0;
+            }
+            let fill = (48 - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+            // but the first is hashed as soon as it is full
+            if self.buf_len == 48 && self.total_len - bytes.len() as u64 == 48 {
+                self.block();
+                self.buf_len = 0;
+            }
+        }
+    }
+}
+
+/// wyhash of `bytes`, with the given seed and the default secret.
+pub fn wyhash_with_seed(bytes: &[u8], seed: u64) -> u64 {
+    let mut hasher = WyHasher::new(seed);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// wyhash of `bytes`, with the given seed and secret.
+pub fn wyhash_with_secret(bytes: &[u8], seed: u64, secret: [u64; 4]) -> u64 {
+    let mut hasher = WyHasher::with_secret(seed, secret);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+random_state!(RandomWyState, WyHasher);
+hash_collections!(WyHashMap, WyHashSet, WyHasher);
+hasher_to_fcn!(
+    /// Provide access to WyHasher in a single call.
+    wyhash,
+    WyHasher
+);
+
+// ====================================
+// rapidhash
+
+/// The default rapidhash secret.
+pub const RAPIDHASH_SECRET: [u64; 7] = [
+    0x2d358dccaa6c78a5,
+    0x8bb84b93962eacc9,
+    0x4b33a62ed433d4a3,
+    0x4d5a2da51de1aa47,
+    0xa0761d6478bd642f,
+    0xe7037ed1a0b428db,
+    0x90ed1765281c388c,
+];
+
+/// rapidhash V3.
+///
+/// rapidhash consumes its input in 112-byte blocks, leaving between 1
+/// and 112 bytes, which it consumes in 16-byte pieces; then it hashes
+/// the last 16 bytes of the input (even if some of them have already
+/// been consumed).
+pub struct RapidHasher {
+    secret: [u64; 7],
+    seed: u64,
+    see: [u64; 6],
+    // unhashed data, for partial blocks
+    buf: [u8; 112],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // the last 16 bytes of the last block hashed
+    last: [u8; 16],
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl RapidHasher {
+    pub fn new(seed: u64) -> RapidHasher {
+        RapidHasher::with_secret(seed, RAPIDHASH_SECRET)
+    }
+
+    pub fn with_secret(seed: u64, secret: [u64; 7]) -> RapidHasher {
+        let seed = seed ^ mix(seed ^ secret[2], secret[1]);
+        RapidHasher {
+            secret,
+            seed,
+            see: [seed; 6],
+            buf: [0; 112],
+            buf_len: 0,
+            last: [0; 16],
+            total_len: 0,
+        }
+    }
+
+    #[inline]
+    fn block(&mut self) {
+        let RapidHasher {
+            ref secret,
+            ref mut seed,
+            ref mut see,
+            ref buf,
+            ..
+        } = *self;
+        *seed = mix(r8(buf, 0) ^ secret[0], r8(buf, 8) ^ *seed);
+        for (j, s) in see.iter_mut().enumerate() {
+            let i = 16 * (j + 1);
+            *s = mix(r8(buf, i) ^ secret[j + 1], r8(buf, i + 8) ^ *s);
+        }
+        self.last.copy_from_slice(&buf[96..]);
+    }
+}
+
+impl Default for RapidHasher {
+    fn default() -> RapidHasher {
+        RapidHasher::new(0)
+    }
+}
+
+impl SeedableHasher for RapidHasher {
+    fn with_seed(seed: u64) -> RapidHasher {
+        RapidHasher::new(seed)
+    }
+}
+
+impl Hasher for RapidHasher {
+    fn finish(&self) -> u64 {
+        let secret = &self.secret;
+        let len = self.total_len;
+        let buf = &self.buf[..self.buf_len];
+        let mut seed = self.seed;
+        let mut a = 0;
+        let mut b = 0;
+        if len <= 16 {
+            let n = buf.len();
+            if n >= 4 {
+                seed ^= len;
+                if n >= 8 {
+                    a = r8(buf, 0);
+                    b = r8(buf, n - 8);
+                } else {
+                    a = r4(buf, 0);
+                    b = r4(buf, n - 4);
+                }
+            } else if n > 0 {
+                a = ((buf[0] as u64) << 45) | buf[n - 1] as u64;
+                b = buf[n >> 1] as u64;
+            }
+        } else {
+            if len > 112 {
+                let see = &self.see;
+                seed ^= see[0] ^ see[1] ^ see[2] ^ see[3] ^ see[4] ^ see[5];
+            }
+            // secrets for the 16-byte pieces, in order
+            let pieces = [2, 2, 1, 1, 2, 1];
+            let mut i = 0;
+            while buf.len() - i > 16 {
+                seed = mix(r8(buf, i) ^ secret[pieces[i / 16]], r8(buf, i + 8) ^ seed);
+                i += 16;
+            }
+            // the last 16 bytes may reach back into the last block
+            let mut tail = [0u8; 128];
+            tail[..16].copy_from_slice(&self.last);
+            tail[16..16 + buf.len()].copy_from_slice(buf);
+            a = r8(&tail, buf.len()) ^ buf.len() as u64;
+            b = r8(&tail, buf.len() + 8);
+        }
+        let remainder = if len <= 16 { len } else { self.buf_len as u64 };
+        let (a, b) = mum(a ^ secret[1], b ^ seed);
+        mix(a ^ 0xaaaaaaaaaaaaaaaa, b ^ secret[1] ^ remainder)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            // a full block is only hashed once we know more follows it
+            if self.buf_len == 112 {
+                self.block();
+                self.buf_len = 0;
+            }
+            let fill = (112 - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+        }
+    }
+}
+
+/// rapidhash of `bytes`, with the given seed and the default secret.
+pub fn rapidhash_with_seed(bytes: &[u8], seed: u64) -> u64 {
+    let mut hasher = RapidHasher::new(seed);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// rapidhash of `bytes`, with the given seed and secret.
+pub fn rapidhash_with_secret(bytes: &[u8], seed: u64, secret: [u64; 7]) -> u64 {
+    let mut hasher = RapidHasher::with_secret(seed, secret);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+random_state!(RandomRapidState, RapidHasher);
+hash_collections!(RapidHashMap, RapidHashSet, RapidHasher);
+hasher_to_fcn!(
+    /// Provide access to RapidHasher in a single call.
+    rapidhash,
+    RapidHasher
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod wy_tests {
+    use super::*;
+    use check_seedable;
+
+    // wyhash's test_vector.cpp: message i is hashed with seed i.
+    const WYHASH_VECTORS: [(&str, u64); 7] = [
+        ("", 0x0409638ee2bde459),
+        ("a", 0xa8412d091b5fe0a9),
+        ("abc", 0x32dd92e4b2915153),
+        ("message digest", 0x8619124089a3a16b),
+        ("abcdefghijklmnopqrstuvwxyz", 0x7a43afb61d7f5f40),
+        (
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+            0xff42329b90e50d58,
+        ),
+        (
+            "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+            0xc39cab13b115aad3,
+        ),
+    ];
+
+    #[test]
+    fn wyhash_basic() {
+        for (i, &(msg, expected)) in WYHASH_VECTORS.iter().enumerate() {
+            assert_eq!(
+                wyhash_with_seed(msg.as_bytes(), i as u64),
+                expected,
+                "{:?}",
+                msg
+            );
+        }
+        assert_eq!(wyhash(b""), 0x0409638ee2bde459);
+        assert_eq!(
+            wyhash_with_secret(b"abc", 2, WYHASH_SECRET),
+            wyhash_with_seed(b"abc", 2)
+        );
+    }
+
+    fn data() -> Vec<u8> {
+        (0..1000u32).map(|i| (i * 7 + 3) as u8).collect()
+    }
+
+    #[test]
+    fn wyhash_blocks() {
+        // Checked against wyhash.h: (length, seed 0, seed 42).
+        let expected: [(usize, u64, u64); 9] = [
+            (17, 0x8c406b9bd1cbf3ff, 0x0837648bf8741625),
+            (48, 0x0d06d1e9c7409057, 0x8b58d62d4b71b33f),
+            (49, 0x271253a8708703e1, 0xf5b639be9dee1040),
+            (95, 0xbf2456fd5a765f20, 0x4631c87988eb75eb),
+            (96, 0x8461f4e504f284fb, 0x8dd445425329793f),
+            (97, 0xde2063f25fd5deac, 0xc47e59cf9e6434d3),
+            (144, 0xa9812259f50e72f3, 0xcdc900118335ec6c),
+            (192, 0x8a7588619bb2bfb5, 0xe727b7f9c96b0998),
+            (1000, 0x0b881934093d1e81, 0x27f8f2cf9eaaa0f0),
+        ];
+        let data = data();
+        for &(n, h0, h42) in expected.iter() {
+            assert_eq!(wyhash(&data[..n]), h0, "{}", n);
+            assert_eq!(wyhash_with_seed(&data[..n], 42), h42, "{}", n);
+        }
+    }
+
+    #[test]
+    fn rapidhash_basic() {
+        // Checked against the rapidhash crate's V3: (length, seed 0, seed 42).
+        let expected: [(usize, u64, u64); 8] = [
+            (0, 0x0338dc4be2cecdae, 0x9293ba21a570895d),
+            (1, 0xc6939e8fb00709ff, 0x9ef27c6f81850556),
+            (3, 0x5655e9f764e8fb47, 0x4ade820b9173c224),
+            (16, 0xd0e68844cb24a480, 0xa2c15136824b5e3b),
+            (17, 0x7998c836066e171b, 0xedf47b8d3b84ca16),
+            (112, 0xbde27e659c073097, 0xd4fe8b656f7661ff),
+            (113, 0xe7198c47a15df67f, 0xe8fbfdd50531ef3c),
+            (1000, 0x871e919aec95c844, 0x562db81788b5ee58),
+        ];
+        let data = data();
+        for &(n, h0, h42) in expected.iter() {
+            assert_eq!(rapidhash(&data[..n]), h0, "{}", n);
+            assert_eq!(rapidhash_with_seed(&data[..n], 42), h42, "{}", n);
+        }
+    }
+
+    #[test]
+    fn secrets() {
+        let mut secret = WYHASH_SECRET;
+        secret.swap(1, 2);
+        assert_ne!(wyhash_with_secret(b"abc", 0, secret), wyhash(b"abc"));
+        let mut secret = RAPIDHASH_SECRET;
+        secret.swap(1, 2);
+        assert_ne!(rapidhash_with_secret(b"abc", 0, secret), rapidhash(b"abc"));
+    }
+
+    #[test]
+    fn wyrand_sequence() {
+        let mut state = 0;
+        assert_eq!(wyrand(&mut state), 0x111cb3a78f59a58e);
+        assert_eq!(wyrand(&mut state), 0xceabd938ff4e856d);
+        assert_eq!(wyrand(&mut state), 0x61fb51318f47d2a4);
+        assert_eq!(state, WYHASH_SECRET[0].wrapping_mul(3));
+    }
+
+    #[test]
+    fn fragments() {
+        let data = data();
+        // 288 is a whole number of wyhash blocks
+        for &len in [288, 300].iter() {
+            let data = &data[..len];
+            for split in 0..data.len() {
+                let mut wy = WyHasher::new(7);
+                let mut rapid = RapidHasher::new(7);
+                for chunk in data.chunks(split + 1) {
+                    wy.write(chunk);
+                    rapid.write(chunk);
+                }
+                assert_eq!(wy.finish(), wyhash_with_seed(data, 7));
+                assert_eq!(rapid.finish(), rapidhash_with_seed(data, 7));
+            }
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<WyHasher>(true);
+        check_seedable::<RapidHasher>(true);
+    }
+}
@@ -0,0 +1,458 @@
+//! From https://github.com/Cyan4973/xxHash and http://cyan4973.github.io/xxHash/.
+//!
+//! > xxHash is an Extremely fast Hash algorithm, running at RAM speed
+//! > limits. It successfully completes the SMHasher test suite which
+//! > evaluates collision, dispersion and randomness qualities of hash
+//! > functions. Code is highly portable, and hashes are identical
+//! > across all platforms (little / big endian).
+//!
+//! Quoted comments are from https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md.
+//!
+//! This module provides both the 32- and 64-bit versions of the
+//! original xxHash algorithm. Both are seedable; the Default
+//! implementations use a seed of 0, which is what most other
+//! implementations use when no seed is given.
+//!
+//! This module includes a sub-module implementing XXH3.
+
+use std::hash::Hasher;
+use std::{mem, ptr};
+
+use SeedableHasher;
+
+pub mod xxh3;
+
+// ====================================
+// XXH64
+
+const PRIME64_1: u64 = 0x9E3779B185EBCA87;
+const PRIME64_2: u64 = 0xC2B2AE3D27D4EB4F;
+const PRIME64_3: u64 = 0x165667B19E3779F9;
+const PRIME64_4: u64 = 0x85EBCA77C2B2AE63;
+const PRIME64_5: u64 = 0x27D4EB2F165667C5;
+
+/// size of a stripe, in bytes: four u64 lanes
+const STRIPE64: usize = 4 * mem::size_of::<u64>(); // 32
+
+#[inline]
+fn round64(acc: u64, lane: u64) -> u64 {
+    acc.wrapping_add(lane.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+#[inline]
+fn merge_round64(acc: u64, val: u64) -> u64 {
+    (acc ^ round64(0, val))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+/// > The final mix ensures that all input bits have a chance to impact
+/// > any bit in the output digest, resulting in an unbiased
+/// > distribution. This is also called avalanche.
+#[inline]
+fn avalanche64(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(PRIME64_2);
+    h ^= h >> 29;
+    h = h.wrapping_mul(PRIME64_3);
+    h ^= h >> 32;
+    h
+}
+
+/// Process one 32-byte stripe into the four accumulators.
+#[inline]
+fn stripe64(acc: &mut [u64; 4], stripe: &[u8]) {
+    debug_assert!(stripe.len() >= STRIPE64);
+    acc[0] = round64(acc[0], load_int_le!(stripe, 0, u64));
+    acc[1] = round64(acc[1], load_int_le!(stripe, 8, u64));
+    acc[2] = round64(acc[2], load_int_le!(stripe, 16, u64));
+    acc[3] = round64(acc[3], load_int_le!(stripe, 24, u64));
+}
+
+/// The 64-bit version of xxHash.
+///
+/// > XXH64's algorithm structure is very similar to XXH32 one. The
+/// > major difference is that XXH64 uses 64-bit arithmetic, speeding up
+/// > memory transfer for 64-bit compliant systems, but also relying on
+/// > cpu capability to properly perform 64-bit operations.
+///
+/// Data written to this Hasher is buffered until a complete stripe is
+/// available, so the result does not depend on how the input is split
+/// across calls to `write`.
+pub struct XxHash64 {
+    // seed, needed for short inputs in finish
+    seed: u64,
+    // the four lane accumulators
+    acc: [u64; 4],
+    // unhashed data, for partial stripes
+    buf: [u8; STRIPE64],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl XxHash64 {
+    pub fn new(seed: u64) -> XxHash64 {
+        XxHash64 {
+            seed,
+            acc: [
+                seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+                seed.wrapping_add(PRIME64_2),
+                seed,
+                seed.wrapping_sub(PRIME64_1),
+            ],
+            buf: [0; STRIPE64],
+            buf_len: 0,
+            total_len: 0,
+        }
+    }
+}
+
+impl Default for XxHash64 {
+    fn default() -> XxHash64 {
+        XxHash64::new(0)
+    }
+}
+
+impl SeedableHasher for XxHash64 {
+    fn with_seed(seed: u64) -> XxHash64 {
+        XxHash64::new(seed)
+    }
+}
+
+impl Hasher for XxHash64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut h = if self.total_len >= STRIPE64 as u64 {
+            let acc = &self.acc;
+            let mut h = acc[0]
+                .rotate_left(1)
+                .wrapping_add(acc[1].rotate_left(7))
+                .wrapping_add(acc[2].rotate_left(12))
+                .wrapping_add(acc[3].rotate_left(18));
+            for lane in acc.iter() {
+                h = merge_round64(h, *lane);
+            }
+            h
+        } else {
+            self.seed.wrapping_add(PRIME64_5)
+        };
+        h = h.wrapping_add(self.total_len);
+
+        let mut rest = &self.buf[..self.buf_len];
+        while rest.len() >= 8 {
+            h ^= round64(0, load_int_le!(rest, 0, u64));
+            h = h.rotate_left(27).wrapping_mul(PRIME64_1).wrapping_add(PRIME64_4);
+            rest = &rest[8..];
+        }
+        if rest.len() >= 4 {
+            h ^= (load_int_le!(rest, 0, u32) as u64).wrapping_mul(PRIME64_1);
+            h = h.rotate_left(23).wrapping_mul(PRIME64_2).wrapping_add(PRIME64_3);
+            rest = &rest[4..];
+        }
+        for byte in rest.iter() {
+            h ^= (*byte as u64).wrapping_mul(PRIME64_5);
+            h = h.rotate_left(11).wrapping_mul(PRIME64_1);
+        }
+        avalanche64(h)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let mut bytes = bytes;
+        // if we've got anything stuffed away, top it up first
+        if self.buf_len > 0 {
+            let fill = (STRIPE64 - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+            if self.buf_len < STRIPE64 {
+                return;
+            }
+            stripe64(&mut self.acc, &self.buf);
+            self.buf_len = 0;
+        }
+        let mut chunks = bytes.chunks_exact(STRIPE64);
+        for chunk in &mut chunks {
+            stripe64(&mut self.acc, chunk);
+        }
+        // stuff away the last few bytes
+        let rest = chunks.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+}
+
+random_state!(RandomXxHash64State, XxHash64);
+hash_collections!(XxHash64HashMap, XxHash64HashSet, XxHash64);
+hasher_to_fcn!(
+    /// Provide access to XxHash64 in a single call.
+    xxhash64,
+    XxHash64
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod xxhash64_tests {
+    use super::*;
+    use check_seedable;
+
+    fn seeded(seed: u64, bytes: &[u8]) -> u64 {
+        let mut hasher = XxHash64::new(seed);
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(xxhash64(b""), 0xef46db3751d8e999);
+        assert_eq!(xxhash64(b"a"), 0xd24ec4f1a98c6e5b);
+        assert_eq!(xxhash64(b"b"), 0x78452aa11af39f9b);
+        assert_eq!(xxhash64(b"ab"), 0x65f708ca92d04a61);
+        assert_eq!(xxhash64(b"abcd"), 0xde0327b0d25d92cc);
+        assert_eq!(xxhash64(b"abcdefg"), 0x1860940e2902822d);
+        assert_eq!(xxhash64(b"abcdefghijklmnopqrstuvwxyz"), 0xcfe1f278fa89835c);
+        assert_eq!(
+            xxhash64(b"Nobody inspects the spammish repetition"),
+            0xfbcea83c8a378bf1
+        );
+    }
+
+    #[test]
+    fn seeds() {
+        assert_eq!(seeded(1, b""), 0xd5afba1336a3be4b);
+        assert_eq!(seeded(1, b"abc"), 0xbea9ca8199328908);
+        assert_eq!(
+            seeded(1, b"The quick brown fox jumps over the lazy dog"),
+            0xdf5091b6dad2c6db
+        );
+    }
+
+    #[test]
+    fn fragments() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        for split in 0..data.len() {
+            let mut hasher = XxHash64::default();
+            hasher.write(&data[..split]);
+            hasher.write(&data[split..]);
+            assert_eq!(hasher.finish(), 0x0b242d361fda71bc);
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<XxHash64>(true);
+    }
+}
+
+// ====================================
+// XXH32
+
+const PRIME32_1: u32 = 0x9E3779B1;
+const PRIME32_2: u32 = 0x85EBCA77;
+const PRIME32_3: u32 = 0xC2B2AE3D;
+const PRIME32_4: u32 = 0x27D4EB2F;
+const PRIME32_5: u32 = 0x165667B1;
+
+/// size of a stripe, in bytes: four u32 lanes
+const STRIPE32: usize = 4 * mem::size_of::<u32>(); // 16
+
+#[inline]
+fn round32(acc: u32, lane: u32) -> u32 {
+    acc.wrapping_add(lane.wrapping_mul(PRIME32_2))
+        .rotate_left(13)
+        .wrapping_mul(PRIME32_1)
+}
+
+#[inline]
+fn avalanche32(mut h: u32) -> u32 {
+    h ^= h >> 15;
+    h = h.wrapping_mul(PRIME32_2);
+    h ^= h >> 13;
+    h = h.wrapping_mul(PRIME32_3);
+    h ^= h >> 16;
+    h
+}
+
+/// Process one 16-byte stripe into the four accumulators.
+#[inline]
+fn stripe32(acc: &mut [u32; 4], stripe: &[u8]) {
+    debug_assert!(stripe.len() >= STRIPE32);
+    acc[0] = round32(acc[0], load_int_le!(stripe, 0, u32));
+    acc[1] = round32(acc[1], load_int_le!(stripe, 4, u32));
+    acc[2] = round32(acc[2], load_int_le!(stripe, 8, u32));
+    acc[3] = round32(acc[3], load_int_le!(stripe, 12, u32));
+}
+
+/// The 32-bit version of xxHash.
+///
+/// > The algorithm collect and transform input in stripes of 16 bytes.
+/// > The transforms are stored inside 4 "accumulators", each one storing
+/// > an unsigned 32-bit value.
+///
+/// The result is the 32-bit hash, zero-extended by `finish`.
+pub struct XxHash32 {
+    // seed, needed for short inputs in finish
+    seed: u32,
+    // the four lane accumulators
+    acc: [u32; 4],
+    // unhashed data, for partial stripes
+    buf: [u8; STRIPE32],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl XxHash32 {
+    pub fn new(seed: u32) -> XxHash32 {
+        XxHash32 {
+            seed,
+            acc: [
+                seed.wrapping_add(PRIME32_1).wrapping_add(PRIME32_2),
+                seed.wrapping_add(PRIME32_2),
+                seed,
+                seed.wrapping_sub(PRIME32_1),
+            ],
+            buf: [0; STRIPE32],
+            buf_len: 0,
+            total_len: 0,
+        }
+    }
+}
+
+impl Default for XxHash32 {
+    fn default() -> XxHash32 {
+        XxHash32::new(0)
+    }
+}
+
+impl SeedableHasher for XxHash32 {
+    fn with_seed(seed: u64) -> XxHash32 {
+        XxHash32::new(seed as u32)
+    }
+}
+
+impl Hasher for XxHash32 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut h = if self.total_len >= STRIPE32 as u64 {
+            let acc = &self.acc;
+            acc[0]
+                .rotate_left(1)
+                .wrapping_add(acc[1].rotate_left(7))
+                .wrapping_add(acc[2].rotate_left(12))
+                .wrapping_add(acc[3].rotate_left(18))
+        } else {
+            self.seed.wrapping_add(PRIME32_5)
+        };
+        // only the low 32 bits of the length are used
+        h = h.wrapping_add(self.total_len as u32);
+
+        let mut rest = &self.buf[..self.buf_len];
+        while rest.len() >= 4 {
+            h = h.wrapping_add(load_int_le!(rest, 0, u32).wrapping_mul(PRIME32_3));
+            h = h.rotate_left(17).wrapping_mul(PRIME32_4);
+            rest = &rest[4..];
+        }
+        for byte in rest.iter() {
+            h = h.wrapping_add((*byte as u32).wrapping_mul(PRIME32_5));
+            h = h.rotate_left(11).wrapping_mul(PRIME32_1);
+        }
+        avalanche32(h) as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        let mut bytes = bytes;
+        // if we've got anything stuffed away, top it up first
+        if self.buf_len > 0 {
+            let fill = (STRIPE32 - self.buf_len).min(bytes.len());
+            self.buf[self.buf_len..self.buf_len + fill].copy_from_slice(&bytes[..fill]);
+            self.buf_len += fill;
+            bytes = &bytes[fill..];
+            if self.buf_len < STRIPE32 {
+                return;
+            }
+            stripe32(&mut self.acc, &self.buf);
+            self.buf_len = 0;
+        }
+        let mut chunks = bytes.chunks_exact(STRIPE32);
+        for chunk in &mut chunks {
+            stripe32(&mut self.acc, chunk);
+        }
+        // stuff away the last few bytes
+        let rest = chunks.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+}
+
+random_state!(RandomXxHash32State, XxHash32);
+hash_collections!(XxHash32HashMap, XxHash32HashSet, XxHash32);
+hasher_to_fcn!(
+    /// Provide access to XxHash32 in a single call.
+    xxhash32,
+    XxHash32
+);
+
+// ------------------------------------
+
+#[cfg(test)]
+mod xxhash32_tests {
+    use super::*;
+    use check_seedable;
+
+    fn seeded(seed: u32, bytes: &[u8]) -> u64 {
+        let mut hasher = XxHash32::new(seed);
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(xxhash32(b""), 0x02cc5d05);
+        assert_eq!(xxhash32(b"a"), 0x550d7456);
+        assert_eq!(xxhash32(b"b"), 0xa20cadbf);
+        assert_eq!(xxhash32(b"ab"), 0x4999fc53);
+        assert_eq!(xxhash32(b"abcd"), 0xa3643705);
+        assert_eq!(xxhash32(b"abcdefg"), 0x9dd093b3);
+        assert_eq!(xxhash32(b"abcdefghijklmnopqrstuvwxyz"), 0x63a14d5f);
+        assert_eq!(
+            xxhash32(b"Nobody inspects the spammish repetition"),
+            0xe2293b2f
+        );
+    }
+
+    #[test]
+    fn seeds() {
+        assert_eq!(seeded(1, b""), 0x0b2cb792);
+        assert_eq!(seeded(1, b"abc"), 0xaa3da8ff);
+        assert_eq!(
+            seeded(1, b"The quick brown fox jumps over the lazy dog"),
+            0x234f8471
+        );
+    }
+
+    #[test]
+    fn fragments() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        for split in 0..data.len() {
+            let mut hasher = XxHash32::default();
+            hasher.write(&data[..split]);
+            hasher.write(&data[split..]);
+            assert_eq!(hasher.finish(), 0xe85ea4de);
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<XxHash32>(true);
+    }
+}
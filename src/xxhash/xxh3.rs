@@ -0,0 +1,814 @@
+//! From https://github.com/Cyan4973/xxHash/blob/dev/xxhash.h
+//!
+//! > XXH3 is a more recent hash algorithm featuring:
+//! >  - Improved speed for both small and large inputs
+//! >  - True 64-bit and 128-bit outputs
+//! >  - SIMD acceleration
+//! >  - Improved 32-bit viability
+//!
+//! XXH3 splits its work by input length: inputs of 0-16 bytes, 17-128
+//! bytes and 129-240 bytes each get their own short, unrolled path,
+//! while anything longer is processed in 64-byte stripes against a
+//! "secret", which is scrambled into the accumulators after each block.
+//! This is the portable (scalar) version of the algorithm.
+//!
+//! The secret is normally the 192-byte default below. Seeded hashes
+//! mix the seed into the short paths directly and derive a custom
+//! secret from it for long inputs; the `_with_secret` functions accept
+//! any secret of at least `SECRET_SIZE_MIN` bytes instead.
+
+use std::hash::Hasher;
+use std::{mem, ptr};
+
+use SeedableHasher;
+
+use super::{avalanche64, PRIME32_1, PRIME32_2, PRIME32_3};
+use super::{PRIME64_1, PRIME64_2, PRIME64_3, PRIME64_4, PRIME64_5};
+
+/// > The bare minimum size for a custom secret.
+pub const SECRET_SIZE_MIN: usize = 136;
+
+/// size of the default secret
+const SECRET_DEFAULT_SIZE: usize = 192;
+
+/// > Pseudorandom secret taken directly from FARSH.
+const DEFAULT_SECRET: [u8; SECRET_DEFAULT_SIZE] = [
+    0xb8, 0xfe, 0x6c, 0x39, 0x23, 0xa4, 0x4b, 0xbe, 0x7c, 0x01, 0x81, 0x2c, 0xf7, 0x21, 0xad, 0x1c,
+    0xde, 0xd4, 0x6d, 0xe9, 0x83, 0x90, 0x97, 0xdb, 0x72, 0x40, 0xa4, 0xa4, 0xb7, 0xb3, 0x67, 0x1f,
+    0xcb, 0x79, 0xe6, 0x4e, 0xcc, 0xc0, 0xe5, 0x78, 0x82, 0x5a, 0xd0, 0x7d, 0xcc, 0xff, 0x72, 0x21,
+    0xb8, 0x08, 0x46, 0x74, 0xf7, 0x43, 0x24, 0x8e, 0xe0, 0x35, 0x90, 0xe6, 0x81, 0x3a, 0x26, 0x4c,
+    0x3c, 0x28, 0x52, 0xbb, 0x91, 0xc3, 0x00, 0xcb, 0x88, 0xd0, 0x65, 0x8b, 0x1b, 0x53, 0x2e, 0xa3,
+    0x71, 0x64, 0x48, 0x97, 0xa2, 0x0d, 0xf9, 0x4e, 0x38, 0x19, 0xef, 0x46, 0xa9, 0xde, 0xac, 0xd8,
+    0xa8, 0xfa, 0x76, 0x3f, 0xe3, 0x9c, 0x34, 0x3f, 0xf9, 0xdc, 0xbb, 0xc7, 0xc7, 0x0b, 0x4f, 0x1d,
+    0x8a, 0x51, 0xe0, 0x4b, 0xcd, 0xb4, 0x59, 0x31, 0xc8, 0x9f, 0x7e, 0xc9, 0xd9, 0x78, 0x73, 0x64,
+    0xea, 0xc5, 0xac, 0x83, 0x34, 0xd3, 0xeb, 0xc3, 0xc5, 0x81, 0xa0, 0xff, 0xfa, 0x13, 0x63, 0xeb,
+    0x17, 0x0d, 0xdd, 0x51, 0xb7, 0xf0, 0xda, 0x49, 0xd3, 0x16, 0x55, 0x26, 0x29, 0xd4, 0x68, 0x9e,
+    0x2b, 0x16, 0xbe, 0x58, 0x7d, 0x47, 0xa1, 0xfc, 0x8f, 0xf8, 0xb8, 0xd1, 0x7a, 0xd0, 0x31, 0xce,
+    0x45, 0xcb, 0x3a, 0x8f, 0x95, 0x16, 0x04, 0x28, 0xaf, 0xd7, 0xfb, 0xca, 0xbb, 0x4b, 0x40, 0x7e,
+];
+
+const PRIME_MX1: u64 = 0x165667919E3779F9;
+const PRIME_MX2: u64 = 0x9FB21C651E98DF25;
+
+/// size of a stripe, in bytes
+const STRIPE_LEN: usize = 64;
+/// number of secret bytes consumed at each stripe
+const SECRET_CONSUME_RATE: usize = 8;
+/// number of u64 accumulators
+const ACC_NB: usize = STRIPE_LEN / mem::size_of::<u64>(); // 8
+/// > not aligned on 8, last secret is different from acc & scrambler
+const SECRET_LASTACC_START: usize = 7;
+/// > do not align on 8, so that the secret is different from the accumulator
+const SECRET_MERGEACCS_START: usize = 11;
+const MIDSIZE_MAX: usize = 240;
+const MIDSIZE_STARTOFFSET: usize = 3;
+const MIDSIZE_LASTOFFSET: usize = 17;
+
+/// size of the streaming buffer, in bytes
+const BUFFER_SIZE: usize = 256;
+const BUFFER_STRIPES: usize = BUFFER_SIZE / STRIPE_LEN; // 4
+
+const INIT_ACC: [u64; ACC_NB] = [
+    PRIME32_3 as u64,
+    PRIME64_1,
+    PRIME64_2,
+    PRIME64_3,
+    PRIME64_4,
+    PRIME32_2 as u64,
+    PRIME64_5,
+    PRIME32_1 as u64,
+];
+
+// ====================================
+// Primitives
+
+#[inline]
+fn read32(buf: &[u8], i: usize) -> u32 {
+    load_int_le!(buf, i, u32)
+}
+
+#[inline]
+fn read64(buf: &[u8], i: usize) -> u64 {
+    load_int_le!(buf, i, u64)
+}
+
+#[inline]
+fn mult64to128(a: u64, b: u64) -> (u64, u64) {
+    let product = (a as u128) * (b as u128);
+    (product as u64, (product >> 64) as u64)
+}
+
+/// > Calculates a 64-bit to 128-bit multiply, then XOR folds it.
+#[inline]
+fn mul128_fold64(a: u64, b: u64) -> u64 {
+    let (low, high) = mult64to128(a, b);
+    low ^ high
+}
+
+/// > This is a fast avalanche stage, suitable when input bits are
+/// > already partially mixed.
+#[inline]
+fn avalanche(mut h: u64) -> u64 {
+    h ^= h >> 37;
+    h = h.wrapping_mul(PRIME_MX1);
+    h ^ (h >> 32)
+}
+
+/// > This is a stronger avalanche, inspired by Pelle Evensen's rrmxmx
+/// > preferable when input has not been previously mixed.
+#[inline]
+fn rrmxmx(mut h: u64, len: usize) -> u64 {
+    h ^= h.rotate_left(49) ^ h.rotate_left(24);
+    h = h.wrapping_mul(PRIME_MX2);
+    h ^= (h >> 35).wrapping_add(len as u64);
+    h = h.wrapping_mul(PRIME_MX2);
+    h ^ (h >> 28)
+}
+
+#[inline]
+fn mix16b(input: &[u8], i: usize, secret: &[u8], s: usize, seed: u64) -> u64 {
+    mul128_fold64(
+        read64(input, i) ^ read64(secret, s).wrapping_add(seed),
+        read64(input, i + 8) ^ read64(secret, s + 8).wrapping_sub(seed),
+    )
+}
+
+/// Mix two 16-byte inputs into a 128-bit accumulator.
+#[inline]
+fn mix32b(
+    acc: &mut (u64, u64),
+    input: &[u8],
+    i1: usize,
+    i2: usize,
+    secret: &[u8],
+    s: usize,
+    seed: u64,
+) {
+    acc.0 = acc.0.wrapping_add(mix16b(input, i1, secret, s, seed));
+    acc.0 ^= read64(input, i2).wrapping_add(read64(input, i2 + 8));
+    acc.1 = acc.1.wrapping_add(mix16b(input, i2, secret, s + 16, seed));
+    acc.1 ^= read64(input, i1).wrapping_add(read64(input, i1 + 8));
+}
+
+/// Derive the secret used for long inputs from a seed.
+fn derive_secret(seed: u64) -> [u8; SECRET_DEFAULT_SIZE] {
+    let mut secret = DEFAULT_SECRET;
+    if seed != 0 {
+        for i in (0..SECRET_DEFAULT_SIZE).step_by(16) {
+            let lo = read64(&DEFAULT_SECRET, i).wrapping_add(seed);
+            let hi = read64(&DEFAULT_SECRET, i + 8).wrapping_sub(seed);
+            secret[i..i + 8].copy_from_slice(&lo.to_le_bytes());
+            secret[i + 8..i + 16].copy_from_slice(&hi.to_le_bytes());
+        }
+    }
+    secret
+}
+
+// ====================================
+// Long inputs
+
+#[inline]
+fn accumulate_512(acc: &mut [u64; ACC_NB], input: &[u8], i: usize, secret: &[u8], s: usize) {
+    for lane in 0..ACC_NB {
+        let data_val = read64(input, i + 8 * lane);
+        let data_key = data_val ^ read64(secret, s + 8 * lane);
+        acc[lane ^ 1] = acc[lane ^ 1].wrapping_add(data_val);
+        acc[lane] = acc[lane].wrapping_add((data_key & 0xFFFF_FFFF).wrapping_mul(data_key >> 32));
+    }
+}
+
+#[inline]
+fn scramble_acc(acc: &mut [u64; ACC_NB], secret: &[u8], s: usize) {
+    for (lane, a) in acc.iter_mut().enumerate() {
+        let mut a64 = *a;
+        a64 ^= a64 >> 47;
+        a64 ^= read64(secret, s + 8 * lane);
+        *a = a64.wrapping_mul(PRIME32_1 as u64);
+    }
+}
+
+#[inline]
+fn accumulate(
+    acc: &mut [u64; ACC_NB],
+    input: &[u8],
+    i: usize,
+    secret: &[u8],
+    s: usize,
+    nb_stripes: usize,
+) {
+    for n in 0..nb_stripes {
+        accumulate_512(acc, input, i + n * STRIPE_LEN, secret, s + n * SECRET_CONSUME_RATE);
+    }
+}
+
+#[inline]
+fn merge_accs(acc: &[u64; ACC_NB], secret: &[u8], s: usize, start: u64) -> u64 {
+    let mut result = start;
+    for i in 0..4 {
+        result = result.wrapping_add(mul128_fold64(
+            acc[2 * i] ^ read64(secret, s + 16 * i),
+            acc[2 * i + 1] ^ read64(secret, s + 16 * i + 8),
+        ));
+    }
+    avalanche(result)
+}
+
+/// Accumulate all the stripes of a long input; the result still needs
+/// to be merged.
+fn hash_long(input: &[u8], secret: &[u8]) -> [u64; ACC_NB] {
+    let len = input.len();
+    let nb_stripes_per_block = (secret.len() - STRIPE_LEN) / SECRET_CONSUME_RATE;
+    let block_len = STRIPE_LEN * nb_stripes_per_block;
+    let nb_blocks = (len - 1) / block_len;
+    let mut acc = INIT_ACC;
+
+    for n in 0..nb_blocks {
+        accumulate(&mut acc, input, n * block_len, secret, 0, nb_stripes_per_block);
+        scramble_acc(&mut acc, secret, secret.len() - STRIPE_LEN);
+    }
+    // last partial block
+    let nb_stripes = ((len - 1) - (block_len * nb_blocks)) / STRIPE_LEN;
+    accumulate(&mut acc, input, nb_blocks * block_len, secret, 0, nb_stripes);
+    // last stripe
+    accumulate_512(
+        &mut acc,
+        input,
+        len - STRIPE_LEN,
+        secret,
+        secret.len() - STRIPE_LEN - SECRET_LASTACC_START,
+    );
+    acc
+}
+
+#[inline]
+fn merge_long64(acc: &[u64; ACC_NB], len: usize, secret: &[u8]) -> u64 {
+    merge_accs(
+        acc,
+        secret,
+        SECRET_MERGEACCS_START,
+        (len as u64).wrapping_mul(PRIME64_1),
+    )
+}
+
+#[inline]
+fn merge_long128(acc: &[u64; ACC_NB], len: usize, secret: &[u8]) -> (u64, u64) {
+    let low = merge_long64(acc, len, secret);
+    let high = merge_accs(
+        acc,
+        secret,
+        secret.len() - mem::size_of::<[u64; ACC_NB]>() - SECRET_MERGEACCS_START,
+        !(len as u64).wrapping_mul(PRIME64_2),
+    );
+    (low, high)
+}
+
+// ====================================
+// XXH3 64-bit
+
+fn len_0to16_64(input: &[u8], secret: &[u8], seed: u64) -> u64 {
+    let len = input.len();
+    if len > 8 {
+        let bitflip1 = (read64(secret, 24) ^ read64(secret, 32)).wrapping_add(seed);
+        let bitflip2 = (read64(secret, 40) ^ read64(secret, 48)).wrapping_sub(seed);
+        let input_lo = read64(input, 0) ^ bitflip1;
+        let input_hi = read64(input, len - 8) ^ bitflip2;
+        let acc = (len as u64)
+            .wrapping_add(input_lo.swap_bytes())
+            .wrapping_add(input_hi)
+            .wrapping_add(mul128_fold64(input_lo, input_hi));
+        avalanche(acc)
+    } else if len >= 4 {
+        let seed = seed ^ (((seed as u32).swap_bytes() as u64) << 32);
+        let input1 = read32(input, 0) as u64;
+        let input2 = read32(input, len - 4) as u64;
+        let bitflip = (read64(secret, 8) ^ read64(secret, 16)).wrapping_sub(seed);
+        let input64 = input2.wrapping_add(input1 << 32);
+        rrmxmx(input64 ^ bitflip, len)
+    } else if len > 0 {
+        let combined = ((input[0] as u32) << 16)
+            | ((input[len >> 1] as u32) << 24)
+            | (input[len - 1] as u32)
+            | ((len as u32) << 8);
+        let bitflip = ((read32(secret, 0) ^ read32(secret, 4)) as u64).wrapping_add(seed);
+        avalanche64(combined as u64 ^ bitflip)
+    } else {
+        avalanche64(seed ^ read64(secret, 56) ^ read64(secret, 64))
+    }
+}
+
+fn len_17to128_64(input: &[u8], secret: &[u8], seed: u64) -> u64 {
+    let len = input.len();
+    let mut acc = (len as u64).wrapping_mul(PRIME64_1);
+    if len > 32 {
+        if len > 64 {
+            if len > 96 {
+                acc = acc.wrapping_add(mix16b(input, 48, secret, 96, seed));
+                acc = acc.wrapping_add(mix16b(input, len - 64, secret, 112, seed));
+            }
+            acc = acc.wrapping_add(mix16b(input, 32, secret, 64, seed));
+            acc = acc.wrapping_add(mix16b(input, len - 48, secret, 80, seed));
+        }
+        acc = acc.wrapping_add(mix16b(input, 16, secret, 32, seed));
+        acc = acc.wrapping_add(mix16b(input, len - 32, secret, 48, seed));
+    }
+    acc = acc.wrapping_add(mix16b(input, 0, secret, 0, seed));
+    acc = acc.wrapping_add(mix16b(input, len - 16, secret, 16, seed));
+    avalanche(acc)
+}
+
+fn len_129to240_64(input: &[u8], secret: &[u8], seed: u64) -> u64 {
+    let len = input.len();
+    let nb_rounds = len / 16;
+    let mut acc = (len as u64).wrapping_mul(PRIME64_1);
+    for i in 0..8 {
+        acc = acc.wrapping_add(mix16b(input, 16 * i, secret, 16 * i, seed));
+    }
+    acc = avalanche(acc);
+    for i in 8..nb_rounds {
+        acc = acc.wrapping_add(mix16b(
+            input,
+            16 * i,
+            secret,
+            16 * (i - 8) + MIDSIZE_STARTOFFSET,
+            seed,
+        ));
+    }
+    // last bytes
+    acc = acc.wrapping_add(mix16b(
+        input,
+        len - 16,
+        secret,
+        SECRET_SIZE_MIN - MIDSIZE_LASTOFFSET,
+        seed,
+    ));
+    avalanche(acc)
+}
+
+/// Hash input of at most MIDSIZE_MAX bytes.
+#[inline]
+fn short64(input: &[u8], secret: &[u8], seed: u64) -> u64 {
+    debug_assert!(input.len() <= MIDSIZE_MAX);
+    if input.len() <= 16 {
+        len_0to16_64(input, secret, seed)
+    } else if input.len() <= 128 {
+        len_17to128_64(input, secret, seed)
+    } else {
+        len_129to240_64(input, secret, seed)
+    }
+}
+
+/// The 64-bit XXH3 hash of `bytes`.
+pub fn xxh3_64(bytes: &[u8]) -> u64 {
+    xxh3_64_with_seed(bytes, 0)
+}
+
+/// The 64-bit XXH3 hash of `bytes`, using `seed`.
+pub fn xxh3_64_with_seed(bytes: &[u8], seed: u64) -> u64 {
+    if bytes.len() <= MIDSIZE_MAX {
+        short64(bytes, &DEFAULT_SECRET, seed)
+    } else {
+        let secret = derive_secret(seed);
+        merge_long64(&hash_long(bytes, &secret), bytes.len(), &secret)
+    }
+}
+
+/// The 64-bit XXH3 hash of `bytes`, using a custom `secret`.
+///
+/// Panics if `secret` is shorter than `SECRET_SIZE_MIN` bytes.
+pub fn xxh3_64_with_secret(bytes: &[u8], secret: &[u8]) -> u64 {
+    assert!(secret.len() >= SECRET_SIZE_MIN);
+    if bytes.len() <= MIDSIZE_MAX {
+        short64(bytes, secret, 0)
+    } else {
+        merge_long64(&hash_long(bytes, secret), bytes.len(), secret)
+    }
+}
+
+// ====================================
+// XXH3 128-bit
+
+fn len_0to16_128(input: &[u8], secret: &[u8], seed: u64) -> (u64, u64) {
+    let len = input.len();
+    if len > 8 {
+        let bitflipl = (read64(secret, 32) ^ read64(secret, 40)).wrapping_sub(seed);
+        let bitfliph = (read64(secret, 48) ^ read64(secret, 56)).wrapping_add(seed);
+        let input_lo = read64(input, 0);
+        let mut input_hi = read64(input, len - 8);
+        let (mut m_lo, mut m_hi) = mult64to128(input_lo ^ input_hi ^ bitflipl, PRIME64_1);
+        m_lo = m_lo.wrapping_add(((len - 1) as u64) << 54);
+        input_hi ^= bitfliph;
+        m_hi = m_hi
+            .wrapping_add(input_hi)
+            .wrapping_add((input_hi as u32 as u64).wrapping_mul((PRIME32_2 - 1) as u64));
+        m_lo ^= m_hi.swap_bytes();
+        let (h_lo, h_hi) = mult64to128(m_lo, PRIME64_2);
+        let h_hi = h_hi.wrapping_add(m_hi.wrapping_mul(PRIME64_2));
+        (avalanche(h_lo), avalanche(h_hi))
+    } else if len >= 4 {
+        let seed = seed ^ (((seed as u32).swap_bytes() as u64) << 32);
+        let input_lo = read32(input, 0) as u64;
+        let input_hi = read32(input, len - 4) as u64;
+        let input64 = input_lo.wrapping_add(input_hi << 32);
+        let bitflip = (read64(secret, 16) ^ read64(secret, 24)).wrapping_add(seed);
+        let keyed = input64 ^ bitflip;
+        let (mut m_lo, mut m_hi) = mult64to128(keyed, PRIME64_1.wrapping_add((len as u64) << 2));
+        m_hi = m_hi.wrapping_add(m_lo << 1);
+        m_lo ^= m_hi >> 3;
+        m_lo ^= m_lo >> 35;
+        m_lo = m_lo.wrapping_mul(PRIME_MX2);
+        m_lo ^= m_lo >> 28;
+        (m_lo, avalanche(m_hi))
+    } else if len > 0 {
+        let combinedl = ((input[0] as u32) << 16)
+            | ((input[len >> 1] as u32) << 24)
+            | (input[len - 1] as u32)
+            | ((len as u32) << 8);
+        let combinedh = combinedl.swap_bytes().rotate_left(13);
+        let bitflipl = ((read32(secret, 0) ^ read32(secret, 4)) as u64).wrapping_add(seed);
+        let bitfliph = ((read32(secret, 8) ^ read32(secret, 12)) as u64).wrapping_sub(seed);
+        (
+            avalanche64(combinedl as u64 ^ bitflipl),
+            avalanche64(combinedh as u64 ^ bitfliph),
+        )
+    } else {
+        (
+            avalanche64(seed ^ read64(secret, 64) ^ read64(secret, 72)),
+            avalanche64(seed ^ read64(secret, 80) ^ read64(secret, 88)),
+        )
+    }
+}
+
+/// Shared final step of the 17-128 and 129-240 byte paths.
+#[inline]
+fn mid_end128(acc: (u64, u64), len: usize, seed: u64) -> (u64, u64) {
+    let low = acc.0.wrapping_add(acc.1);
+    let high = acc
+        .0
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(acc.1.wrapping_mul(PRIME64_4))
+        .wrapping_add((len as u64).wrapping_sub(seed).wrapping_mul(PRIME64_2));
+    (avalanche(low), 0u64.wrapping_sub(avalanche(high)))
+}
+
+fn len_17to128_128(input: &[u8], secret: &[u8], seed: u64) -> (u64, u64) {
+    let len = input.len();
+    let mut acc = ((len as u64).wrapping_mul(PRIME64_1), 0u64);
+    if len > 32 {
+        if len > 64 {
+            if len > 96 {
+                mix32b(&mut acc, input, 48, len - 64, secret, 96, seed);
+            }
+            mix32b(&mut acc, input, 32, len - 48, secret, 64, seed);
+        }
+        mix32b(&mut acc, input, 16, len - 32, secret, 32, seed);
+    }
+    mix32b(&mut acc, input, 0, len - 16, secret, 0, seed);
+    mid_end128(acc, len, seed)
+}
+
+fn len_129to240_128(input: &[u8], secret: &[u8], seed: u64) -> (u64, u64) {
+    let len = input.len();
+    let nb_rounds = len / 32;
+    let mut acc = ((len as u64).wrapping_mul(PRIME64_1), 0u64);
+    for i in 0..4 {
+        mix32b(&mut acc, input, 32 * i, 32 * i + 16, secret, 32 * i, seed);
+    }
+    acc = (avalanche(acc.0), avalanche(acc.1));
+    for i in 4..nb_rounds {
+        let s = MIDSIZE_STARTOFFSET + 32 * (i - 4);
+        mix32b(&mut acc, input, 32 * i, 32 * i + 16, secret, s, seed);
+    }
+    // last bytes
+    mix32b(
+        &mut acc,
+        input,
+        len - 16,
+        len - 32,
+        secret,
+        SECRET_SIZE_MIN - MIDSIZE_LASTOFFSET - 16,
+        0u64.wrapping_sub(seed),
+    );
+    mid_end128(acc, len, seed)
+}
+
+/// Hash input of at most MIDSIZE_MAX bytes.
+#[inline]
+fn short128(input: &[u8], secret: &[u8], seed: u64) -> (u64, u64) {
+    debug_assert!(input.len() <= MIDSIZE_MAX);
+    if input.len() <= 16 {
+        len_0to16_128(input, secret, seed)
+    } else if input.len() <= 128 {
+        len_17to128_128(input, secret, seed)
+    } else {
+        len_129to240_128(input, secret, seed)
+    }
+}
+
+/// The 128-bit XXH3 hash of `bytes`, as (low, high) 64-bit halves.
+pub fn xxh3_128(bytes: &[u8]) -> (u64, u64) {
+    xxh3_128_with_seed(bytes, 0)
+}
+
+/// The 128-bit XXH3 hash of `bytes`, using `seed`.
+pub fn xxh3_128_with_seed(bytes: &[u8], seed: u64) -> (u64, u64) {
+    if bytes.len() <= MIDSIZE_MAX {
+        short128(bytes, &DEFAULT_SECRET, seed)
+    } else {
+        let secret = derive_secret(seed);
+        merge_long128(&hash_long(bytes, &secret), bytes.len(), &secret)
+    }
+}
+
+/// The 128-bit XXH3 hash of `bytes`, using a custom `secret`.
+///
+/// Panics if `secret` is shorter than `SECRET_SIZE_MIN` bytes.
+pub fn xxh3_128_with_secret(bytes: &[u8], secret: &[u8]) -> (u64, u64) {
+    assert!(secret.len() >= SECRET_SIZE_MIN);
+    if bytes.len() <= MIDSIZE_MAX {
+        short128(bytes, secret, 0)
+    } else {
+        merge_long128(&hash_long(bytes, secret), bytes.len(), secret)
+    }
+}
+
+// ====================================
+// Streaming
+
+/// A streaming XXH3 Hasher, producing either the 64-bit (`finish`) or
+/// the 128-bit (`finish128`) hash.
+///
+/// Up to MIDSIZE_MAX bytes of input are simply buffered and hashed in
+/// one go by `finish`, so short keys take the same fast paths as the
+/// one-shot functions. Longer input is consumed a buffer-full of stripes
+/// at a time; at least one byte is always kept back so that `finish`
+/// can process the last stripe the same way the one-shot version does.
+pub struct Xxh3Hasher {
+    // seed, for short inputs
+    seed: u64,
+    // secret derived from the seed, for long inputs
+    secret: [u8; SECRET_DEFAULT_SIZE],
+    // internal state of the hash
+    acc: [u64; ACC_NB],
+    // unhashed data; for long inputs, the last stripe consumed is kept
+    // at the end
+    buf: [u8; BUFFER_SIZE],
+    // length of unhashed data stashed in buf
+    buf_len: usize,
+    // number of stripes accumulated in the current block
+    nb_stripes: usize,
+    // total length of the input so far
+    total_len: u64,
+}
+
+impl Xxh3Hasher {
+    pub fn new(seed: u64) -> Xxh3Hasher {
+        Xxh3Hasher {
+            seed,
+            secret: derive_secret(seed),
+            acc: INIT_ACC,
+            buf: [0; BUFFER_SIZE],
+            buf_len: 0,
+            nb_stripes: 0,
+            total_len: 0,
+        }
+    }
+
+    pub fn finish128(&self) -> (u64, u64) {
+        if self.total_len > MIDSIZE_MAX as u64 {
+            let acc = self.digest_long();
+            merge_long128(&acc, self.total_len as usize, &self.secret)
+        } else {
+            short128(&self.buf[..self.buf_len], &DEFAULT_SECRET, self.seed)
+        }
+    }
+
+    /// Accumulate whole stripes, scrambling at the end of each block.
+    fn consume_stripes(&mut self, input: &[u8], i: usize, nb_stripes: usize) {
+        let secret = &self.secret[..];
+        let nb_stripes_per_block = (secret.len() - STRIPE_LEN) / SECRET_CONSUME_RATE;
+        let to_end = nb_stripes_per_block - self.nb_stripes;
+        let s = self.nb_stripes * SECRET_CONSUME_RATE;
+        if to_end <= nb_stripes {
+            // need a scrambling operation
+            let after_block = nb_stripes - to_end;
+            accumulate(&mut self.acc, input, i, secret, s, to_end);
+            scramble_acc(&mut self.acc, secret, secret.len() - STRIPE_LEN);
+            accumulate(&mut self.acc, input, i + to_end * STRIPE_LEN, secret, 0, after_block);
+            self.nb_stripes = after_block;
+        } else {
+            accumulate(&mut self.acc, input, i, secret, s, nb_stripes);
+            self.nb_stripes += nb_stripes;
+        }
+    }
+
+    /// Accumulate the buffered tail into a copy of the state.
+    fn digest_long(&self) -> [u64; ACC_NB] {
+        // work on copies so finish can be called more than once
+        let mut state = Xxh3Hasher {
+            buf: [0; BUFFER_SIZE],
+            ..*self
+        };
+        let secret = &self.secret[..];
+        let last = secret.len() - STRIPE_LEN - SECRET_LASTACC_START;
+        if self.buf_len >= STRIPE_LEN {
+            let nb_stripes = (self.buf_len - 1) / STRIPE_LEN;
+            state.consume_stripes(&self.buf, 0, nb_stripes);
+            accumulate_512(&mut state.acc, &self.buf, self.buf_len - STRIPE_LEN, secret, last);
+        } else {
+            // the last stripe straddles the previous stripe and the buffer
+            let mut last_stripe = [0u8; STRIPE_LEN];
+            let catchup = STRIPE_LEN - self.buf_len;
+            last_stripe[..catchup].copy_from_slice(&self.buf[BUFFER_SIZE - catchup..]);
+            last_stripe[catchup..].copy_from_slice(&self.buf[..self.buf_len]);
+            accumulate_512(&mut state.acc, &last_stripe, 0, secret, last);
+        }
+        state.acc
+    }
+}
+
+impl Default for Xxh3Hasher {
+    fn default() -> Xxh3Hasher {
+        Xxh3Hasher::new(0)
+    }
+}
+
+impl SeedableHasher for Xxh3Hasher {
+    fn with_seed(seed: u64) -> Xxh3Hasher {
+        Xxh3Hasher::new(seed)
+    }
+}
+
+random_state!(RandomXxh3State, Xxh3Hasher);
+hash_collections!(Xxh3HashMap, Xxh3HashSet, Xxh3Hasher);
+
+impl Hasher for Xxh3Hasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        if self.total_len > MIDSIZE_MAX as u64 {
+            let acc = self.digest_long();
+            merge_long64(&acc, self.total_len as usize, &self.secret)
+        } else {
+            short64(&self.buf[..self.buf_len], &DEFAULT_SECRET, self.seed)
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+        // if the fragment fits, store it for later
+        if bytes.len() <= BUFFER_SIZE - self.buf_len {
+            self.buf[self.buf_len..self.buf_len + bytes.len()].copy_from_slice(bytes);
+            self.buf_len += bytes.len();
+            return;
+        }
+        let mut processed = 0;
+        // if we've got anything stuffed away, use it now
+        if self.buf_len > 0 {
+            processed = BUFFER_SIZE - self.buf_len;
+            self.buf[self.buf_len..].copy_from_slice(&bytes[..processed]);
+            let buf = self.buf;
+            self.consume_stripes(&buf, 0, BUFFER_STRIPES);
+            self.buf_len = 0;
+        }
+        // process the rest of the bytes, keeping at least one back
+        if bytes.len() - processed > BUFFER_SIZE {
+            while bytes.len() - processed > BUFFER_SIZE {
+                self.consume_stripes(bytes, processed, BUFFER_STRIPES);
+                processed += BUFFER_SIZE;
+            }
+            // keep the last stripe for finish
+            self.buf[BUFFER_SIZE - STRIPE_LEN..]
+                .copy_from_slice(&bytes[processed - STRIPE_LEN..processed]);
+        }
+        // stuff away the last few bytes
+        let rest = &bytes[processed..];
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+}
+
+// ------------------------------------
+
+#[cfg(test)]
+mod xxh3_tests {
+    use super::*;
+    use check_seedable;
+
+    // the reference sanity checks use this rather than PRIME64_1
+    const PRIME64: u64 = 11400714785074694797;
+
+    /// Deterministic test input, as in the reference sanity checks.
+    fn sanity_buffer(len: usize) -> Vec<u8> {
+        let mut byte_gen = PRIME32_1 as u64;
+        (0..len)
+            .map(|_| {
+                let b = (byte_gen >> 56) as u8;
+                byte_gen = byte_gen.wrapping_mul(PRIME64);
+                b
+            })
+            .collect()
+    }
+
+    fn streamed(bytes: &[u8], seed: u64, fragment: usize) -> Xxh3Hasher {
+        let mut hasher = Xxh3Hasher::new(seed);
+        for chunk in bytes.chunks(fragment) {
+            hasher.write(chunk);
+        }
+        hasher
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(xxh3_64(b""), 0x2d06800538d394c2);
+        assert_eq!(xxh3_64(b"a"), 0xe6c632b61e964e1f);
+        assert_eq!(xxh3_64(b"ab"), 0xa873719c24d5735c);
+        assert_eq!(xxh3_64(b"abcd"), 0x6497a96f53a89890);
+        assert_eq!(xxh3_64(b"abcdefg"), 0x5a40dc3fd44c052f);
+        assert_eq!(xxh3_64(b"abcdefghijklmnopqrstuvwxyz"), 0x810f9ca067fbb90c);
+
+        assert_eq!(xxh3_128(b""), (0x6001c324468d497f, 0x99aa06d3014798d8));
+        assert_eq!(xxh3_128(b"a"), (0xe6c632b61e964e1f, 0xa96faf705af16834));
+        assert_eq!(xxh3_128(b"abcd"), (0x1be79eecd1b1353d, 0x8d6b60383dfa90c2));
+        assert_eq!(
+            xxh3_128(b"abcdefghijklmnopqrstuvwxyz"),
+            (0xebe162220154e1e6, 0xdb7ca44e84843d67)
+        );
+    }
+
+    // (length, seed 0, seed PRIME64, custom secret)
+    const SANITY64: [(usize, u64, u64, u64); 13] = [
+        (0, 0x2d06800538d394c2, 0xa8a6b918b2f0364a, 0x3559d64878c5c66c),
+        (1, 0xc44bdff4074eecdb, 0x032be332dd766ef8, 0x8a52451418b2da4d),
+        (6, 0x27b56a84cd2d7325, 0x84589c116ab59ab9, 0x82c90ab0519369ad),
+        (12, 0xa713daf0dfbb77e7, 0xe7303e1b2336de0e, 0x14631e773b78ec57),
+        (24, 0xa3fe70bf9d3510eb, 0x850e80fc35bdd690, 0xcdd5542e4a9d9fe8),
+        (48, 0x397da259ecba1f11, 0xadc2cbaa44acc616, 0x33abd54d094b2534),
+        (80, 0xbcdefbbb2c47c90a, 0xc6dd0cb699532e73, 0xe687ba1684965297),
+        (195, 0xcd94217ee362ec3a, 0xba68003d370cb3d9, 0xa057273f5eecfb20),
+        (403, 0xcdeb804d65c6dea4, 0x6259f6ecfd6443fd, 0x14546019124d43b8),
+        (512, 0x617e49599013cb6b, 0x3ce457de14c27708, 0x7564693dd526e28d),
+        (2048, 0xdd59e2c3a5f038e0, 0x66f81670669ababc, 0xd32e975821d6519f),
+        (2240, 0x6e73a90539cf2948, 0x757ba8487d1b5247, 0xb26c938c7af3a71f),
+        (2367, 0xcb37aeb9e5d361ed, 0xd2db3415b942b42a, 0x293fa8e5173bb5e7),
+    ];
+
+    #[test]
+    fn lengths64() {
+        let data = sanity_buffer(2367);
+        let secret = &data[7..7 + SECRET_SIZE_MIN + 11];
+        for &(len, h0, h1, hs) in SANITY64.iter() {
+            assert_eq!(xxh3_64_with_seed(&data[..len], 0), h0);
+            assert_eq!(xxh3_64_with_seed(&data[..len], PRIME64), h1);
+            assert_eq!(xxh3_64_with_secret(&data[..len], secret), hs);
+        }
+    }
+
+    // (length, seed 0, seed PRIME64, custom secret)
+    type Sanity128 = (usize, (u64, u64), (u64, u64), (u64, u64));
+
+    const SANITY128: [Sanity128; 13] = [
+        (0, (0x6001c324468d497f, 0x99aa06d3014798d8), (0xa986dfc5d7605bfe, 0x00feaa732a3ce25e), (0x005923cceecbe8ae, 0x5f70f4ea232f1d38)),
+        (1, (0xc44bdff4074eecdb, 0xa6cd5e9392000f6a), (0x032be332dd766ef8, 0x20e49abcc53b3842), (0x8a52451418b2da4d, 0x3a66af5a9819198e)),
+        (6, (0x3e7039bdda43cfc6, 0x082afe0b8162d12a), (0xc5b54d56038e4e40, 0x014bd95a51ca5ddb), (0x0b61c8aca7d4778f, 0x376bd91b6432f36d)),
+        (12, (0x061a192713f69ad9, 0x6e3efd8fc7802b18), (0x5d92b5d7190b12d1, 0xff0d60acd02ed401), (0xaf82f6eba263d7d8, 0x90a3c2d839f57d0f)),
+        (24, (0x1e7044d28b1b901d, 0x0ce966e4678d3761), (0xc6cbf92a70680b19, 0xd7895ded1f62559d), (0xd9ed8351e0bb5526, 0x3476c01ab8b8e821)),
+        (48, (0xf942219aed80f67b, 0xa002ac4e5478227e), (0x3a94d91333ed395a, 0xbc689f4c0152fb44), (0xd3488d14a0fc9147, 0x2c599633a4d78138)),
+        (80, (0x454ae6bf7a8a532d, 0xfdf2cefde9eaac8a), (0xa5eac764d1ff1166, 0x19bf02d69bc56833), (0x12568d8d732f8544, 0x5de1c8eb7bd056b8)),
+        (195, (0x3fb593c086a66075, 0x7729543a26b207ee), (0xcf9d9ec2c8c9913f, 0x0326104c4d4849e7), (0x002cdb4476b9a36f, 0x18783075f43015be)),
+        (403, (0xcdeb804d65c6dea4, 0x1b6de21e332dd73d), (0x6259f6ecfd6443fd, 0xbed311971e0be8f2), (0x14546019124d43b8, 0xe14eedf084a487f3)),
+        (512, (0x617e49599013cb6b, 0x18d2d110dcc9bca1), (0x3ce457de14c27708, 0x925d06b8ec5b8040), (0x7564693dd526e28d, 0x918c0f2c7656ab6d)),
+        (2048, (0xdd59e2c3a5f038e0, 0xf736557fd47073a5), (0x66f81670669ababc, 0x23cc3a2e75ebaaea), (0xd32e975821d6519f, 0xe862d841c07049af)),
+        (2240, (0x6e73a90539cf2948, 0xccb134fbfa7ce49d), (0x757ba8487d1b5247, 0xe40842f585875ba9), (0xb26c938c7af3a71f, 0x1e89ee710a768055)),
+        (2367, (0xcb37aeb9e5d361ed, 0xe89c0f6ff369b427), (0xd2db3415b942b42a, 0xccb7a94cca1a6496), (0x293fa8e5173bb5e7, 0x343654a35acf0dae)),
+    ];
+
+    #[test]
+    fn lengths128() {
+        let data = sanity_buffer(2367);
+        let secret = &data[7..7 + SECRET_SIZE_MIN + 11];
+        for &(len, h0, h1, hs) in SANITY128.iter() {
+            assert_eq!(xxh3_128_with_seed(&data[..len], 0), h0);
+            assert_eq!(xxh3_128_with_seed(&data[..len], PRIME64), h1);
+            assert_eq!(xxh3_128_with_secret(&data[..len], secret), hs);
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let data = sanity_buffer(2367);
+        for &len in &[0, 1, 16, 17, 128, 129, 240, 241, 1024, 1025, 2367] {
+            for &fragment in &[1, 7, 63, 64, 65, 256, 257] {
+                for &seed in &[0, PRIME64] {
+                    let hasher = streamed(&data[..len], seed, fragment);
+                    assert_eq!(hasher.finish(), xxh3_64_with_seed(&data[..len], seed));
+                    assert_eq!(hasher.finish128(), xxh3_128_with_seed(&data[..len], seed));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn seedable() {
+        check_seedable::<Xxh3Hasher>(true);
+    }
+}